@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS review_launchers (
+  name TEXT NOT NULL PRIMARY KEY,
+  command TEXT NOT NULL,
+  args TEXT NOT NULL DEFAULT '[]'
+);
+
+CREATE TABLE IF NOT EXISTS repository_local_paths (
+  repository TEXT NOT NULL PRIMARY KEY,
+  local_path TEXT NOT NULL
+);
+
+-- The review command Ctrl+R ran before launchers were configurable.
+INSERT OR IGNORE INTO review_launchers (name, command, args) VALUES (
+  'review',
+  'ghostty',
+  '["+new-window","-e","fish","-c","cd ~/code/{repo} && pr_review ~/code/{repo} {number}"]'
+);
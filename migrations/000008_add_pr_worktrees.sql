@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS pr_worktrees (
+  repository TEXT NOT NULL,
+  pr_number INTEGER NOT NULL,
+  path TEXT NOT NULL,
+  head_sha TEXT NOT NULL DEFAULT '',
+  PRIMARY KEY (repository, pr_number)
+);
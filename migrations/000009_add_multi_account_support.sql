@@ -0,0 +1,8 @@
+ALTER TABLE users ADD COLUMN is_active BOOLEAN NOT NULL DEFAULT 0;
+
+UPDATE users SET is_active = 1 WHERE id = (SELECT MIN(id) FROM users);
+
+CREATE UNIQUE INDEX IF NOT EXISTS users_username_unique ON users (username COLLATE NOCASE);
+
+-- NULL means the repository is synced with the active account.
+ALTER TABLE tracked_repositories ADD COLUMN account TEXT;
@@ -0,0 +1,18 @@
+-- Tokens may now live outside the database. `credential_source` holds a
+-- reference such as `env:GITHUB_TOKEN`; `access_token` is only set for `stored`.
+CREATE TABLE users_new (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  username TEXT NOT NULL,
+  access_token TEXT UNIQUE,
+  credential_source TEXT NOT NULL DEFAULT 'stored',
+  is_active BOOLEAN NOT NULL DEFAULT 0
+);
+
+INSERT INTO users_new (id, username, access_token, credential_source, is_active)
+SELECT id, username, access_token, 'stored', is_active FROM users;
+
+DROP TABLE users;
+
+ALTER TABLE users_new RENAME TO users;
+
+CREATE UNIQUE INDEX IF NOT EXISTS users_username_unique ON users (username COLLATE NOCASE);
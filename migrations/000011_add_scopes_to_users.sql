@@ -0,0 +1,2 @@
+-- JSON array of OAuth scopes granted at login; NULL when unknown.
+ALTER TABLE users ADD COLUMN scopes TEXT;
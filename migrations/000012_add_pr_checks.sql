@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS pr_checks (
+  repository TEXT NOT NULL,
+  pr_number INTEGER NOT NULL,
+  name TEXT NOT NULL,
+  status TEXT NOT NULL,
+  conclusion TEXT,
+  ci_status INTEGER NOT NULL DEFAULT 0,
+  is_required BOOLEAN NOT NULL DEFAULT 0,
+  details_url TEXT,
+  started_at_unix INTEGER,
+  completed_at_unix INTEGER,
+  PRIMARY KEY (repository, pr_number, name),
+  FOREIGN KEY (repository, pr_number) REFERENCES pull_requests(repository, number) ON DELETE CASCADE
+);
@@ -0,0 +1 @@
+ALTER TABLE tracked_repositories ADD COLUMN ci_policy TEXT NOT NULL DEFAULT 'required';
@@ -0,0 +1,19 @@
+ALTER TABLE pull_requests ADD COLUMN ci_status_label TEXT NOT NULL DEFAULT 'pending';
+UPDATE pull_requests SET ci_status_label = CASE ci_status
+  WHEN 1 THEN 'success'
+  WHEN 2 THEN 'failure'
+  WHEN 3 THEN 'no_checks'
+  WHEN 4 THEN 'optional_failing'
+  ELSE 'pending'
+END;
+ALTER TABLE pull_requests DROP COLUMN ci_status;
+ALTER TABLE pull_requests RENAME COLUMN ci_status_label TO ci_status;
+
+ALTER TABLE pr_checks ADD COLUMN ci_status_label TEXT NOT NULL DEFAULT 'pending';
+UPDATE pr_checks SET ci_status_label = CASE ci_status
+  WHEN 1 THEN 'success'
+  WHEN 2 THEN 'failure'
+  ELSE 'pending'
+END;
+ALTER TABLE pr_checks DROP COLUMN ci_status;
+ALTER TABLE pr_checks RENAME COLUMN ci_status_label TO ci_status;
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS pr_review_threads (
+  repository TEXT NOT NULL,
+  pr_number INTEGER NOT NULL,
+  id TEXT NOT NULL,
+  path TEXT NOT NULL,
+  line INTEGER,
+  is_resolved BOOLEAN NOT NULL DEFAULT 0,
+  is_outdated BOOLEAN NOT NULL DEFAULT 0,
+  author TEXT NOT NULL,
+  body TEXT NOT NULL,
+  comment_count INTEGER NOT NULL DEFAULT 0,
+  PRIMARY KEY (repository, pr_number, id),
+  FOREIGN KEY (repository, pr_number) REFERENCES pull_requests(repository, number) ON DELETE CASCADE
+);
+
+ALTER TABLE pull_requests ADD COLUMN last_thread_update_unix INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,4 @@
+ALTER TABLE pr_comments ADD COLUMN path TEXT;
+ALTER TABLE pr_comments ADD COLUMN line INTEGER;
+ALTER TABLE pr_comments ADD COLUMN diff_hunk TEXT;
+ALTER TABLE pr_comments ADD COLUMN is_outdated BOOLEAN NOT NULL DEFAULT 0;
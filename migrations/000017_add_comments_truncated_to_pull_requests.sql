@@ -0,0 +1 @@
+ALTER TABLE pull_requests ADD COLUMN comments_truncated BOOLEAN NOT NULL DEFAULT 0;
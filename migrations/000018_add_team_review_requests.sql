@@ -0,0 +1,8 @@
+ALTER TABLE pull_requests ADD COLUMN requested_teams TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE pull_requests ADD COLUMN team_review_requested BOOLEAN NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS user_teams (
+  username TEXT PRIMARY KEY COLLATE NOCASE,
+  teams TEXT NOT NULL DEFAULT '[]',
+  fetched_at_unix INTEGER NOT NULL
+);
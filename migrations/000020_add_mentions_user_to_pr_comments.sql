@@ -0,0 +1 @@
+ALTER TABLE pr_comments ADD COLUMN mentions_user BOOLEAN NOT NULL DEFAULT 0;
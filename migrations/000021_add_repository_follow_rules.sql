@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS repository_follow_rules (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  org TEXT NOT NULL,
+  topic TEXT,
+  team TEXT,
+  pattern TEXT,
+  exclude_archived BOOLEAN NOT NULL DEFAULT 0,
+  account TEXT,
+  created_at_unix INTEGER NOT NULL
+);
@@ -0,0 +1 @@
+ALTER TABLE tracked_repositories ADD COLUMN node_id TEXT;
@@ -0,0 +1 @@
+ALTER TABLE tracked_repositories ADD COLUMN last_sync_error TEXT;
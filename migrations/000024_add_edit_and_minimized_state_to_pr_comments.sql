@@ -0,0 +1,2 @@
+ALTER TABLE pr_comments ADD COLUMN edited_at_unix INTEGER;
+ALTER TABLE pr_comments ADD COLUMN is_minimized BOOLEAN NOT NULL DEFAULT 0;
@@ -0,0 +1,28 @@
+CREATE TABLE IF NOT EXISTS sync_runs (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  trigger TEXT NOT NULL,
+  scope TEXT NOT NULL,
+  dry_run BOOLEAN NOT NULL DEFAULT 0,
+  started_at_unix INTEGER NOT NULL,
+  finished_at_unix INTEGER,
+  new_prs INTEGER NOT NULL DEFAULT 0,
+  updated_data_prs INTEGER NOT NULL DEFAULT 0,
+  updated_attention_prs INTEGER NOT NULL DEFAULT 0,
+  deleted_prs INTEGER NOT NULL DEFAULT 0,
+  graphql_requests INTEGER NOT NULL DEFAULT 0,
+  graphql_cost INTEGER NOT NULL DEFAULT 0,
+  error TEXT,
+  log TEXT NOT NULL DEFAULT ''
+);
+
+CREATE TABLE IF NOT EXISTS sync_run_repositories (
+  run_id INTEGER NOT NULL,
+  repository TEXT NOT NULL,
+  duration_ms INTEGER NOT NULL,
+  new_prs INTEGER NOT NULL DEFAULT 0,
+  updated_prs INTEGER NOT NULL DEFAULT 0,
+  deleted_prs INTEGER NOT NULL DEFAULT 0,
+  error TEXT,
+  PRIMARY KEY (run_id, repository),
+  FOREIGN KEY (run_id) REFERENCES sync_runs(id) ON DELETE CASCADE
+);
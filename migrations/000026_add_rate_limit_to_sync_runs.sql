@@ -0,0 +1,2 @@
+ALTER TABLE sync_runs ADD COLUMN rate_limit_remaining INTEGER;
+ALTER TABLE sync_runs ADD COLUMN rate_limit_reset_at_unix INTEGER;
@@ -0,0 +1,31 @@
+-- Check runs from different workflows may share a name (e.g. two `build`
+-- jobs), so the workflow is part of the key. Commit statuses and checks
+-- created outside a workflow keep an empty workflow.
+CREATE TABLE pr_checks_new (
+  repository TEXT NOT NULL,
+  pr_number INTEGER NOT NULL,
+  workflow TEXT NOT NULL DEFAULT '',
+  name TEXT NOT NULL,
+  status TEXT NOT NULL,
+  conclusion TEXT,
+  ci_status TEXT NOT NULL DEFAULT 'pending',
+  is_required BOOLEAN NOT NULL DEFAULT 0,
+  details_url TEXT,
+  started_at_unix INTEGER,
+  completed_at_unix INTEGER,
+  PRIMARY KEY (repository, pr_number, workflow, name),
+  FOREIGN KEY (repository, pr_number) REFERENCES pull_requests(repository, number) ON DELETE CASCADE
+);
+
+INSERT INTO pr_checks_new (
+  repository, pr_number, workflow, name, status, conclusion, ci_status,
+  is_required, details_url, started_at_unix, completed_at_unix
+)
+SELECT
+  repository, pr_number, '', name, status, conclusion, ci_status,
+  is_required, details_url, started_at_unix, completed_at_unix
+FROM pr_checks;
+
+DROP TABLE pr_checks;
+
+ALTER TABLE pr_checks_new RENAME TO pr_checks;
@@ -0,0 +1,5 @@
+-- Whether the repository's CI policy counted the check when it was synced.
+-- Existing rows get what the default policy would have decided; the next sync
+-- applies the repository's own policy.
+ALTER TABLE pr_checks ADD COLUMN counts_towards_ci BOOLEAN NOT NULL DEFAULT 0;
+UPDATE pr_checks SET counts_towards_ci = is_required;
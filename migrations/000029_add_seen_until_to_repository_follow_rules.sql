@@ -0,0 +1,5 @@
+-- Creation time of the newest repository a follow rule has listed. Only
+-- repositories created later get tracked, so one the user untracked is not
+-- added back by the next sync.
+ALTER TABLE repository_follow_rules ADD COLUMN seen_until_unix INTEGER NOT NULL DEFAULT 0;
+UPDATE repository_follow_rules SET seen_until_unix = created_at_unix;
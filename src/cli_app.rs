@@ -1,14 +1,35 @@
 use std::io::IsTerminal;
 
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 
+use crate::credentials::{
+    github_client_for_user, is_managed_token, remove_token_file, resolve_token,
+    store_token_in_file, DEFAULT_GH_HOST,
+};
 use crate::db::DatabaseRepository;
-use crate::github::GitHubClient;
-use crate::models::User;
+use crate::github::device_flow::{
+    DeviceFlowClient, DEFAULT_DEVICE_FLOW_SCOPES, DEFAULT_OAUTH_BASE_URL,
+};
+use crate::github::{
+    missing_recommended_scopes, normalize_repository_reference, GitHubAuth, GitHubClient,
+};
+use crate::models::{
+    CiPolicy, CredentialSource, RepositoryDiscoveryRule, ReviewLauncher, SyncTrigger, User,
+    UserTeams,
+};
 use crate::pr_repository::partition_team_authors;
+use crate::service::{discover_repositories, resolve_repository_to_track};
 use crate::sync::{
-    format_sync_progress, format_sync_summary, sync_all_tracked_with_progress, SyncProgress,
+    format_pr_change_preview, format_sync_progress, format_sync_run, format_sync_run_repository,
+    format_sync_summary, load_sync_accounts, record_sync_run, SyncAccount, SyncAccounts,
+    SyncOptions, SyncProgress,
 };
+use crate::worktree::{
+    checkout_pull_request, format_kept_worktree, format_worktree_checkout, parse_pr_target,
+    worktree_status, WorktreeStatus,
+};
+
+const OAUTH_CLIENT_ID_ENV: &str = "PR_TRACKER_OAUTH_CLIENT_ID";
 
 #[derive(Debug, Parser)]
 #[command(about = "Track pull requests across repositories")]
@@ -19,8 +40,12 @@ struct Cli {
 
 #[derive(Debug, Subcommand)]
 enum Command {
+    /// Add an account with `prt auth <token>`, or manage existing accounts.
+    #[command(args_conflicts_with_subcommands = true)]
     Auth {
-        token: String,
+        #[command(subcommand)]
+        command: Option<AuthCommand>,
+        token: Option<String>,
     },
     Authors {
         #[command(subcommand)]
@@ -30,8 +55,126 @@ enum Command {
         #[command(subcommand)]
         command: RepositoryCommand,
     },
-    Sync,
+    Launchers {
+        #[command(subcommand)]
+        command: LauncherCommand,
+    },
+    /// Sync tracked repositories, by default all of them.
+    #[command(args_conflicts_with_subcommands = true)]
+    Sync {
+        #[command(subcommand)]
+        command: Option<SyncCommand>,
+        /// Only sync this repository (`owner/repo` or URL); repeatable.
+        #[arg(long = "repo")]
+        repositories: Vec<String>,
+        /// Only sync PRs by this author.
+        #[arg(long)]
+        author: Option<String>,
+        /// Print what the sync would change without writing anything.
+        #[arg(long)]
+        dry_run: bool,
+    },
     Prs,
+    Pr {
+        #[command(subcommand)]
+        command: PrCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum AuthCommand {
+    /// Add an account whose token is read from the given source.
+    Add(CredentialArgs),
+    /// Log in through GitHub's OAuth device flow and store the issued token.
+    Login {
+        /// OAuth app client id; defaults to $PR_TRACKER_OAUTH_CLIENT_ID.
+        #[arg(long)]
+        client_id: Option<String>,
+        /// Base URL of the GitHub web host, for GitHub Enterprise Server.
+        #[arg(long, default_value = DEFAULT_OAUTH_BASE_URL)]
+        base_url: String,
+        #[arg(long, default_value = DEFAULT_DEVICE_FLOW_SCOPES)]
+        scopes: String,
+    },
+    List,
+    /// Make another account the active one.
+    Switch {
+        login: String,
+    },
+    Remove {
+        login: String,
+    },
+    /// Validate each stored token and report its login, expiry and scopes.
+    Status,
+    /// Move tokens kept in the database by older versions into private files.
+    MoveTokens,
+    /// Remove the stored credentials of an account (the active one by default).
+    Logout {
+        login: Option<String>,
+    },
+    /// Add a GitHub App installation as an account; sync then uses
+    /// short-lived installation tokens minted from the app's private key.
+    App {
+        #[arg(long)]
+        app_id: String,
+        #[arg(long)]
+        installation_id: i64,
+        /// PEM private key downloaded from the app settings (chmod 600).
+        #[arg(long, value_name = "PATH")]
+        private_key: String,
+        /// Account name used with `--account` and `prt auth switch`.
+        #[arg(long)]
+        name: Option<String>,
+    },
+    /// Replace an account's token after checking it belongs to the same login.
+    Rotate {
+        #[command(flatten)]
+        credential: CredentialArgs,
+        #[arg(long)]
+        account: Option<String>,
+    },
+}
+
+/// Where to read a GitHub token from. A positional token is written to a
+/// private file in the data directory; the database only ever stores a
+/// reference.
+#[derive(Debug, Args)]
+#[group(required = true, multiple = false)]
+struct CredentialArgs {
+    token: Option<String>,
+    /// Read the token from an environment variable.
+    #[arg(long, value_name = "VAR")]
+    env: Option<String>,
+    /// Run a shell command that prints the token, e.g. "pass show github".
+    #[arg(long, value_name = "COMMAND")]
+    token_command: Option<String>,
+    /// Use the token of the gh CLI for a host.
+    #[arg(long = "gh", value_name = "HOST", num_args = 0..=1, default_missing_value = DEFAULT_GH_HOST)]
+    gh_host: Option<String>,
+    /// Read the token from a file that only its owner can read (0600).
+    #[arg(long, value_name = "PATH")]
+    file: Option<String>,
+}
+
+impl CredentialArgs {
+    fn into_source(self) -> anyhow::Result<CredentialSource> {
+        if let Some(token) = self.token {
+            return Ok(CredentialSource::Stored(token));
+        }
+        if let Some(name) = self.env {
+            return Ok(CredentialSource::Env(name));
+        }
+        if let Some(command) = self.token_command {
+            return Ok(CredentialSource::Command(command));
+        }
+        if let Some(host) = self.gh_host {
+            return Ok(CredentialSource::GhCli(host));
+        }
+        if let Some(path) = self.file {
+            return Ok(CredentialSource::File(absolute_path(&path)?));
+        }
+        anyhow::bail!("a token or credential source is required")
+    }
 }
 
 #[derive(Debug, Subcommand)]
@@ -45,9 +188,98 @@ enum AuthorCommand {
 #[derive(Debug, Subcommand)]
 enum RepositoryCommand {
     List,
-    Add { repository: String },
-    Remove { repository: String },
+    /// Track a repository, given as `owner/repo` or a GitHub URL.
+    Add {
+        repository: String,
+        /// Sync this repository with the given account instead of the active one.
+        #[arg(long)]
+        account: Option<String>,
+    },
+    Remove {
+        repository: String,
+    },
+    /// Assign the account used to sync a repository; omit it to use the active account.
+    SetAccount {
+        repository: String,
+        account: Option<String>,
+    },
+    /// Choose which checks decide CI status: `required` (default), `all`,
+    /// or `allow`/`deny` followed by check name globs, e.g. `deny 'codecov/*'`.
+    SetCiPolicy {
+        repository: String,
+        policy: String,
+        patterns: Vec<String>,
+    },
     ResetSync,
+    SetPath {
+        repository: String,
+        path: String,
+    },
+    UnsetPath {
+        repository: String,
+    },
+    /// Pick repositories of an organization to track.
+    Discover {
+        #[arg(long)]
+        org: String,
+        /// Only repositories with this topic.
+        #[arg(long)]
+        topic: Option<String>,
+        /// Only repositories this team (its slug) has access to.
+        #[arg(long)]
+        team: Option<String>,
+        /// Glob matched against the repository name, e.g. `api-*`.
+        #[arg(long)]
+        pattern: Option<String>,
+        #[arg(long)]
+        exclude_archived: bool,
+        /// Also track matching repositories created from now on, at sync time.
+        #[arg(long)]
+        follow: bool,
+        /// List with, and sync the chosen repositories as, this account.
+        #[arg(long)]
+        account: Option<String>,
+    },
+    /// List the discovery rules followed at sync time.
+    Follows,
+    Unfollow {
+        id: i64,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum LauncherCommand {
+    List,
+    /// Add or replace a launcher. Command and args may use {repo}, {owner},
+    /// {number}, {head_sha}, {url} and {local_path} placeholders.
+    Add {
+        name: String,
+        command: String,
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    Remove {
+        name: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum SyncCommand {
+    /// List recorded sync runs, or show one run's repositories and log.
+    History {
+        id: Option<i64>,
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum PrCommand {
+    /// Create or update a git worktree for the PR head, e.g. `octo/widgets#42`.
+    Checkout {
+        target: String,
+    },
+    Worktrees,
 }
 
 pub async fn run_from_args<I, T>(args: I) -> anyhow::Result<()>
@@ -62,11 +294,40 @@ where
 
 async fn run_command(command: Command, repo: &DatabaseRepository) -> anyhow::Result<()> {
     match command {
-        Command::Auth { token } => handle_auth(repo, &token).await?,
+        Command::Auth { command, token } => match (command, token) {
+            (Some(command), _) => handle_auth_command(repo, command).await?,
+            (None, Some(token)) => handle_auth(repo, CredentialSource::Stored(token)).await?,
+            (None, None) => handle_auth_command(repo, AuthCommand::Status).await?,
+        },
         Command::Authors { command } => handle_authors(repo, command).await?,
         Command::Repositories { command } => handle_repositories(repo, command).await?,
-        Command::Sync => handle_sync(repo).await?,
+        Command::Launchers { command } => handle_launchers(repo, command).await?,
+        Command::Sync {
+            command: Some(command),
+            ..
+        } => handle_sync_command(repo, command).await?,
+        Command::Sync {
+            command: None,
+            repositories,
+            author,
+            dry_run,
+        } => {
+            let repositories = repositories
+                .iter()
+                .map(|reference| normalize_repository_reference(reference))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            handle_sync(
+                repo,
+                SyncOptions {
+                    repositories,
+                    author,
+                    dry_run,
+                },
+            )
+            .await?
+        }
         Command::Prs => handle_prs(repo).await?,
+        Command::Pr { command } => handle_pr(repo, command).await?,
     }
 
     Ok(())
@@ -79,27 +340,362 @@ async fn open_repository() -> anyhow::Result<DatabaseRepository> {
     Ok(repo)
 }
 
-async fn handle_auth(repo: &DatabaseRepository, token: &str) -> anyhow::Result<()> {
-    if let Some(user) = repo.get_user().await? {
-        anyhow::bail!(
-            "a user is already authenticated as '{}', remove existing user first",
-            user.username
-        );
+/// Resolve relative paths against the current directory so stored references
+/// keep working from anywhere; `~` paths are expanded when read.
+fn absolute_path(path: &str) -> anyhow::Result<String> {
+    let path = std::path::Path::new(path);
+    if path.is_relative() && !path.starts_with("~") {
+        return Ok(std::env::current_dir()?.join(path).display().to_string());
     }
+    Ok(path.display().to_string())
+}
 
-    let github = GitHubClient::new(token.to_string())?;
+async fn handle_auth(
+    repo: &DatabaseRepository,
+    credential: CredentialSource,
+) -> anyhow::Result<()> {
+    let github = GitHubClient::new(resolve_token(&credential)?)?;
     let user = github.fetch_authenticated_user().await?;
 
+    if repo.get_user_by_username(&user.login).await?.is_some() {
+        anyhow::bail!(
+            "account '{}' is already authenticated, run 'prt auth rotate <token>' to replace its token",
+            user.login
+        );
+    }
+
+    let credential = store_token_in_file(&user.login, credential)?;
     let internal_user = User {
         username: user.login,
-        access_token: token.to_string(),
+        credential,
+        is_active: false,
+        scopes: None,
     };
     repo.save_user(&internal_user).await?;
-    println!("Authenticated as: {}", internal_user.username);
+    println!(
+        "Authenticated as: {} (credentials: {})",
+        internal_user.username,
+        internal_user.credential.reference()
+    );
+
+    Ok(())
+}
+
+async fn handle_auth_login(
+    repo: &DatabaseRepository,
+    client_id: Option<String>,
+    base_url: &str,
+    scopes: &str,
+) -> anyhow::Result<()> {
+    let client_id = client_id
+        .or_else(|| std::env::var(OAUTH_CLIENT_ID_ENV).ok())
+        .filter(|id| !id.trim().is_empty())
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "an OAuth app client id is required, pass --client-id or set {OAUTH_CLIENT_ID_ENV}"
+            )
+        })?;
+
+    let flow = DeviceFlowClient::new(client_id, base_url)?;
+    let code = flow.request_device_code(scopes).await?;
+    println!(
+        "Open {} and enter the code: {}",
+        code.verification_uri, code.user_code
+    );
+    let _ = open::that(&code.verification_uri);
+    eprintln!("Waiting for authorization...");
+
+    let token = flow.poll_for_token(&code).await?;
+    let github = GitHubClient::new(token.access_token.clone())?;
+    let login = github.fetch_authenticated_user().await?.login;
+    let existing = repo.get_user_by_username(&login).await?;
+    if let Some(existing) = &existing {
+        if !is_managed_token(&existing.credential) {
+            anyhow::bail!(
+                "account '{}' reads its token from {}, run 'prt auth rotate <token> --account {}' to replace that source",
+                existing.username,
+                existing.credential.reference(),
+                existing.username
+            );
+        }
+    }
+    let credential = store_token_in_file(&login, CredentialSource::Stored(token.access_token))?;
+
+    match existing {
+        Some(existing) => {
+            repo.update_user_credential(&existing.username, &credential, Some(&token.scopes))
+                .await?;
+            if existing.credential != credential {
+                remove_token_file(&existing.credential)?;
+            }
+            println!(
+                "Replaced the token of '{}' from {}",
+                existing.username,
+                existing.credential.reference()
+            );
+        }
+        None => {
+            repo.save_user(&User {
+                username: login.clone(),
+                credential,
+                is_active: false,
+                scopes: Some(token.scopes.clone()),
+            })
+            .await?;
+        }
+    }
+
+    println!(
+        "Authenticated as: {} (scopes: {})",
+        login,
+        token.scopes.join(", ")
+    );
+    let missing = missing_recommended_scopes(&token.scopes);
+    if !missing.is_empty() {
+        println!("Warning: token is missing scope(s) {}", missing.join(", "));
+    }
+
+    Ok(())
+}
+
+async fn handle_auth_command(
+    repo: &DatabaseRepository,
+    command: AuthCommand,
+) -> anyhow::Result<()> {
+    match command {
+        AuthCommand::Add(credential) => handle_auth(repo, credential.into_source()?).await?,
+        AuthCommand::Login {
+            client_id,
+            base_url,
+            scopes,
+        } => handle_auth_login(repo, client_id, &base_url, &scopes).await?,
+        AuthCommand::App {
+            app_id,
+            installation_id,
+            private_key,
+            name,
+        } => {
+            let name = name.unwrap_or_else(|| format!("app-{app_id}"));
+            if repo.get_user_by_username(&name).await?.is_some() {
+                anyhow::bail!("account '{name}' already exists, pass a different --name");
+            }
+
+            let user = User {
+                username: name,
+                credential: CredentialSource::GitHubApp {
+                    app_id,
+                    installation_id,
+                    private_key_path: absolute_path(&private_key)?,
+                },
+                is_active: false,
+                scopes: None,
+            };
+            let github = github_client_for_user(&user)?;
+            if let GitHubAuth::App(provider) = github.auth() {
+                provider.installation_token().await?;
+            }
+
+            repo.save_user(&user).await?;
+            println!("GitHub App installation added as '{}'", user.username);
+        }
+        AuthCommand::List => {
+            let users = repo.get_users().await?;
+            println!("Accounts:");
+            for user in users {
+                let marker = if user.is_active { " (active)" } else { "" };
+                println!(
+                    "- {}{} [{}]",
+                    user.username,
+                    marker,
+                    user.credential.reference()
+                );
+            }
+        }
+        AuthCommand::Switch { login } => {
+            let login = require_account(repo, &login).await?;
+            repo.set_active_user(&login).await?;
+            println!("Active account is now '{}'", login);
+        }
+        AuthCommand::Remove { login } => {
+            let user = repo.get_user_by_username(&login).await?;
+            if !repo.delete_user(&login).await? {
+                anyhow::bail!("no account named '{login}', run 'prt auth list' to see accounts");
+            }
+            if let Some(user) = user {
+                remove_token_file(&user.credential)?;
+            }
+            println!("Account '{}' removed successfully", login);
+        }
+        AuthCommand::Logout { login } => {
+            let user = match login {
+                Some(login) => repo.get_user_by_username(&login).await?,
+                None => repo.get_user().await?,
+            }
+            .ok_or_else(|| anyhow::anyhow!("no authenticated user found"))?;
+            repo.delete_user(&user.username).await?;
+            remove_token_file(&user.credential)?;
+            println!("Logged out of '{}'", user.username);
+        }
+        AuthCommand::Rotate {
+            credential,
+            account,
+        } => {
+            let user = match account {
+                Some(login) => repo.get_user_by_username(&login).await?,
+                None => repo.get_user().await?,
+            }
+            .ok_or_else(|| {
+                anyhow::anyhow!("no authenticated user found, run 'prt auth <token>' first")
+            })?;
+
+            let credential = credential.into_source()?;
+            let github = GitHubClient::new(resolve_token(&credential)?)?;
+            let new_login = github.fetch_authenticated_user().await?.login;
+            if !new_login.eq_ignore_ascii_case(&user.username) {
+                anyhow::bail!(
+                    "new token belongs to '{}', not '{}'; use 'prt auth <token>' to add another account",
+                    new_login,
+                    user.username
+                );
+            }
+
+            let credential = store_token_in_file(&user.username, credential)?;
+            repo.update_user_credential(&user.username, &credential, None)
+                .await?;
+            if credential != user.credential {
+                remove_token_file(&user.credential)?;
+            }
+            println!(
+                "Token for '{}' rotated successfully (credentials: {})",
+                user.username,
+                credential.reference()
+            );
+        }
+        AuthCommand::MoveTokens => {
+            let mut moved = 0;
+            for user in repo.get_users().await? {
+                if !matches!(user.credential, CredentialSource::Stored(_)) {
+                    continue;
+                }
+                let credential = store_token_in_file(&user.username, user.credential)?;
+                repo.update_user_credential(&user.username, &credential, user.scopes.as_deref())
+                    .await?;
+                println!(
+                    "Moved the token of '{}' to {}",
+                    user.username,
+                    credential.reference()
+                );
+                moved += 1;
+            }
+            if moved == 0 {
+                println!("No tokens are stored in the database");
+            }
+        }
+        AuthCommand::Status => {
+            let users = repo.get_users().await?;
+            if users.is_empty() {
+                println!("Not authenticated, run 'prt auth <token>' first");
+                return Ok(());
+            }
+            let repositories = repo.get_tracked_repositories().await?;
+            for user in users {
+                let count = repositories
+                    .iter()
+                    .filter(|tracked_repo| {
+                        tracked_repo
+                            .account
+                            .as_deref()
+                            .map_or(user.is_active, |login| {
+                                login.eq_ignore_ascii_case(&user.username)
+                            })
+                    })
+                    .count();
+                let marker = if user.is_active { " (active)" } else { "" };
+                println!("{}{}", user.username, marker);
+                println!(
+                    "  Repositories: {} repositor{}",
+                    count,
+                    if count == 1 { "y" } else { "ies" }
+                );
+                print_token_status(&user).await;
+            }
+        }
+    }
 
     Ok(())
 }
 
+async fn print_token_status(user: &User) {
+    println!("  Credentials: {}", user.credential.reference());
+    if matches!(user.credential, CredentialSource::Stored(_)) {
+        println!(
+            "  Warning: the token is stored in plaintext in the database, run 'prt auth move-tokens'"
+        );
+    }
+    let github = match github_client_for_user(user) {
+        Ok(github) => github,
+        Err(err) => {
+            println!("  Token: invalid ({err})");
+            return;
+        }
+    };
+
+    if let GitHubAuth::App(provider) = github.auth() {
+        match provider.installation_token().await {
+            Ok(token) => println!(
+                "  Installation token: valid until {}",
+                token.expires_at.to_rfc3339()
+            ),
+            Err(err) => println!("  Installation token: invalid ({err})"),
+        }
+        return;
+    }
+
+    let info = github.fetch_token_info().await;
+    let info = match info {
+        Ok(info) => info,
+        Err(err) => {
+            println!("  Token: invalid ({err})");
+            return;
+        }
+    };
+
+    if info.user.login.eq_ignore_ascii_case(&user.username) {
+        println!("  Login: {}", info.user.login);
+    } else {
+        println!(
+            "  Login: {} (warning: stored as '{}')",
+            info.user.login, user.username
+        );
+    }
+    println!(
+        "  Expires: {}",
+        info.expires_at.as_deref().unwrap_or("never")
+    );
+    match info.scopes {
+        Some(scopes) => {
+            println!("  Scopes: {}", scopes.join(", "));
+            let missing = missing_recommended_scopes(&scopes);
+            if !missing.is_empty() {
+                println!(
+                    "  Warning: token is missing scope(s) {}; private repositories or team features may not work",
+                    missing.join(", ")
+                );
+            }
+        }
+        None => println!("  Scopes: not reported (fine-grained token)"),
+    }
+}
+
+async fn require_account(repo: &DatabaseRepository, login: &str) -> anyhow::Result<String> {
+    repo.get_user_by_username(login)
+        .await?
+        .map(|user| user.username)
+        .ok_or_else(|| {
+            anyhow::anyhow!("no account named '{login}', run 'prt auth list' to see accounts")
+        })
+}
+
 async fn handle_authors(repo: &DatabaseRepository, command: AuthorCommand) -> anyhow::Result<()> {
     match command {
         AuthorCommand::List => {
@@ -130,11 +726,20 @@ async fn handle_authors_from_teams(repo: &DatabaseRepository) -> anyhow::Result<
         anyhow::anyhow!("no authenticated user found, run 'prt auth <token>' first")
     })?;
 
-    let github = GitHubClient::new(user.access_token.clone())?;
+    let github = github_client_for_user(&user)?;
 
     eprintln!("Fetching team members...");
 
     let teams = github.fetch_user_teams().await?;
+    repo.save_user_teams(&UserTeams {
+        username: user.username.clone(),
+        teams: teams
+            .iter()
+            .map(|team| format!("{}/{}", team.organization.login, team.slug))
+            .collect(),
+        fetched_at: chrono::Utc::now(),
+    })
+    .await?;
     if teams.is_empty() {
         println!("You are not a member of any GitHub teams.");
         return Ok(());
@@ -202,32 +807,301 @@ async fn handle_repositories(
     match command {
         RepositoryCommand::List => {
             let repositories = repo.get_tracked_repositories().await?;
+            let local_paths = repo.get_repository_local_paths().await?;
             println!("Repositories:");
             for tracked_repo in repositories {
-                println!("- {}", tracked_repo.repository);
+                let mut line = format!("- {}", tracked_repo.repository);
+                if let Some(account) = &tracked_repo.account {
+                    line.push_str(&format!(" [{account}]"));
+                }
+                if tracked_repo.ci_policy != CiPolicy::RequiredOnly {
+                    line.push_str(&format!(" {{ci: {}}}", tracked_repo.ci_policy.reference()));
+                }
+                if let Some(path) = local_paths.iter().find(|path| {
+                    path.repository
+                        .eq_ignore_ascii_case(&tracked_repo.repository)
+                }) {
+                    line.push_str(&format!(" ({})", path.local_path));
+                }
+                if let Some(error) = &tracked_repo.last_sync_error {
+                    line.push_str(&format!(" [last sync failed: {error}]"));
+                }
+                println!("{line}");
             }
         }
-        RepositoryCommand::Add { repository } => {
-            repo.save_tracked_repository(&repository).await?;
-            println!("Repository '{}' added successfully", repository);
+        RepositoryCommand::Add {
+            repository,
+            account,
+        } => {
+            handle_repositories_add(repo, &repository, account).await?;
+        }
+        RepositoryCommand::SetAccount {
+            repository,
+            account,
+        } => {
+            let account = match account {
+                Some(login) => Some(require_account(repo, &login).await?),
+                None => None,
+            };
+            if !repo
+                .set_tracked_repository_account(&repository, account.as_deref())
+                .await?
+            {
+                anyhow::bail!("repository '{repository}' is not tracked");
+            }
+            match account {
+                Some(account) => println!("Repository '{}' now syncs as '{}'", repository, account),
+                None => println!(
+                    "Repository '{}' now syncs with the active account",
+                    repository
+                ),
+            }
         }
         RepositoryCommand::Remove { repository } => {
-            repo.delete_tracked_repository(&repository).await?;
-            println!("Repository '{}' removed successfully", repository);
+            let deleted_prs = repo.delete_tracked_repository(&repository).await?;
+            println!(
+                "Repository '{}' removed successfully, along with {} stored PR(s)",
+                repository, deleted_prs
+            );
+        }
+        RepositoryCommand::SetCiPolicy {
+            repository,
+            policy,
+            patterns,
+        } => {
+            let reference = if patterns.is_empty() {
+                policy
+            } else {
+                format!("{policy}:{}", patterns.join(","))
+            };
+            let policy = CiPolicy::from_reference(&reference)?;
+            if !repo
+                .set_tracked_repository_ci_policy(&repository, &policy)
+                .await?
+            {
+                anyhow::bail!("repository '{repository}' is not tracked");
+            }
+            println!(
+                "CI policy for '{}' set to '{}'; it applies from the next sync",
+                repository,
+                policy.reference()
+            );
         }
         RepositoryCommand::ResetSync => {
             let count = repo.reset_all_tracked_repositories_last_synced_at().await?;
             println!("Reset sync timestamps for {} repository(ies)", count);
         }
+        RepositoryCommand::SetPath { repository, path } => {
+            repo.save_repository_local_path(&repository, &path).await?;
+            println!("Local path for '{}' set to '{}'", repository, path);
+        }
+        RepositoryCommand::UnsetPath { repository } => {
+            repo.delete_repository_local_path(&repository).await?;
+            println!("Local path for '{}' removed", repository);
+        }
+        RepositoryCommand::Discover {
+            org,
+            topic,
+            team,
+            pattern,
+            exclude_archived,
+            follow,
+            account,
+        } => {
+            let rule = RepositoryDiscoveryRule {
+                org,
+                topic,
+                team,
+                pattern,
+                exclude_archived,
+            };
+            handle_repositories_discover(repo, rule, follow, account).await?;
+        }
+        RepositoryCommand::Follows => {
+            let rules = repo.get_repository_follow_rules().await?;
+            println!("Followed discovery rules:");
+            for followed in rules {
+                let mut line = format!("- {}: {}", followed.id, followed.rule.describe());
+                if let Some(account) = &followed.account {
+                    line.push_str(&format!(" [{account}]"));
+                }
+                println!("{line}");
+            }
+        }
+        RepositoryCommand::Unfollow { id } => {
+            if !repo.delete_repository_follow_rule(id).await? {
+                anyhow::bail!("no followed discovery rule with id {id}");
+            }
+            println!("Stopped following discovery rule {}", id);
+        }
     }
 
     Ok(())
 }
 
-async fn handle_sync(repo: &DatabaseRepository) -> anyhow::Result<()> {
-    let user = repo.get_user().await?.ok_or_else(|| {
-        anyhow::anyhow!("no authenticated user found, run 'cli auth <token>' first")
-    })?;
+/// The given account, or the active one when `account` is `None`.
+async fn user_for_account(
+    repo: &DatabaseRepository,
+    account: Option<&str>,
+) -> anyhow::Result<User> {
+    match account {
+        Some(login) => repo.get_user_by_username(login).await?,
+        None => repo.get_user().await?,
+    }
+    .ok_or_else(|| match account {
+        Some(login) => {
+            anyhow::anyhow!("no account named '{login}', run 'prt auth list' to see accounts")
+        }
+        None => anyhow::anyhow!("no authenticated user found, run 'prt auth <token>' first"),
+    })
+}
+
+/// Track a repository under its canonical name after checking that the
+/// account syncing it can see it.
+async fn handle_repositories_add(
+    repo: &DatabaseRepository,
+    reference: &str,
+    account: Option<String>,
+) -> anyhow::Result<()> {
+    let requested = normalize_repository_reference(reference)?;
+    let user = user_for_account(repo, account.as_deref()).await?;
+    let account = account.map(|_| user.username.clone());
+    let github = github_client_for_user(&user)?;
+
+    let tracked = repo.get_tracked_repositories().await?;
+    let found = resolve_repository_to_track(&github, &requested, &tracked).await?;
+    repo.save_tracked_repository(
+        &found.full_name,
+        account.as_deref(),
+        found.node_id.as_deref(),
+    )
+    .await?;
+    if found.full_name != requested {
+        println!("'{}' resolved to '{}'", requested, found.full_name);
+    }
+    println!("Repository '{}' added successfully", found.full_name);
+    Ok(())
+}
+
+async fn handle_repositories_discover(
+    repo: &DatabaseRepository,
+    rule: RepositoryDiscoveryRule,
+    follow: bool,
+    account: Option<String>,
+) -> anyhow::Result<()> {
+    let user = user_for_account(repo, account.as_deref()).await?;
+    let account = account.map(|_| user.username.clone());
+    let github = github_client_for_user(&user)?;
+
+    eprintln!("Listing repositories of {}...", rule.describe());
+    let discovered = discover_repositories(&github, &rule).await?;
+    let tracked = repo.get_tracked_repositories().await?;
+    let (already_tracked, candidates): (Vec<String>, Vec<String>) = discovered
+        .iter()
+        .map(|repository| repository.full_name.clone())
+        .partition(|name| {
+            tracked
+                .iter()
+                .any(|tracked_repo| tracked_repo.repository.eq_ignore_ascii_case(name))
+        });
+
+    if !already_tracked.is_empty() {
+        println!("Already tracking:");
+        for name in &already_tracked {
+            println!("  ✓ {}", name);
+        }
+        println!();
+    }
+
+    if candidates.is_empty() {
+        println!("No untracked repositories match.");
+    } else {
+        if !std::io::stderr().is_terminal() {
+            anyhow::bail!(
+                "interactive selection requires a TTY; run this command in an interactive terminal"
+            );
+        }
+
+        match inquire::MultiSelect::new("Select repositories to track:", candidates)
+            .with_help_message(
+                "↑↓ navigate  space select  type to filter  enter confirm  esc cancel",
+            )
+            .with_page_size(15)
+            .prompt_skippable()
+            .map_err(|e| anyhow::anyhow!("selection prompt failed: {e}"))?
+        {
+            Some(names) if !names.is_empty() => {
+                for name in &names {
+                    let node_id = discovered
+                        .iter()
+                        .find(|repository| &repository.full_name == name)
+                        .and_then(|repository| repository.node_id.as_deref());
+                    repo.save_tracked_repository(name, account.as_deref(), node_id)
+                        .await?;
+                }
+                println!("Added {} repository(ies).", names.len());
+            }
+            _ => println!("No repositories selected."),
+        }
+    }
+
+    if follow {
+        let id = repo
+            .save_repository_follow_rule(&rule, account.as_deref())
+            .await?;
+        println!(
+            "Following rule {}: new repositories matching '{}' are tracked at sync time",
+            id,
+            rule.describe()
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_launchers(
+    repo: &DatabaseRepository,
+    command: LauncherCommand,
+) -> anyhow::Result<()> {
+    match command {
+        LauncherCommand::List => {
+            let launchers = repo.get_review_launchers().await?;
+            println!("Launchers:");
+            for launcher in launchers {
+                println!(
+                    "- {}: {} {}",
+                    launcher.name,
+                    launcher.command,
+                    launcher.args.join(" ")
+                );
+            }
+        }
+        LauncherCommand::Add {
+            name,
+            command,
+            args,
+        } => {
+            let launcher = ReviewLauncher {
+                name,
+                command,
+                args,
+            };
+            repo.save_review_launcher(&launcher).await?;
+            println!("Launcher '{}' saved successfully", launcher.name);
+        }
+        LauncherCommand::Remove { name } => {
+            repo.delete_review_launcher(&name).await?;
+            println!("Launcher '{}' removed successfully", name);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_sync(repo: &DatabaseRepository, options: SyncOptions) -> anyhow::Result<()> {
+    if repo.get_user().await?.is_none() {
+        anyhow::bail!("no authenticated user found, run 'cli auth <token>' first");
+    }
 
     let tracked_repositories = repo.get_tracked_repositories().await?;
     if tracked_repositories.is_empty() {
@@ -241,15 +1115,80 @@ async fn handle_sync(repo: &DatabaseRepository) -> anyhow::Result<()> {
         return Ok(());
     }
 
-    let username = user.username.clone();
-    let github = GitHubClient::new(user.access_token)?.with_request_logging(true);
-    let summary =
-        sync_all_tracked_with_progress(repo, &github, &username, log_sync_progress).await?;
+    let loaded = load_sync_accounts(repo).await?;
+    let accounts = SyncAccounts {
+        accounts: loaded
+            .accounts
+            .into_iter()
+            .map(|account| SyncAccount {
+                github: account.github.with_request_logging(true),
+                ..account
+            })
+            .collect(),
+        skipped: loaded.skipped,
+    };
+    let summary = record_sync_run(
+        repo,
+        &accounts,
+        &options,
+        SyncTrigger::Cli,
+        log_sync_progress,
+    )
+    .await?;
 
+    if options.dry_run {
+        println!("Dry run, nothing was written:");
+        for preview in &summary.previews {
+            println!("  {}", format_pr_change_preview(preview));
+        }
+    }
+    for kept in &summary.kept_worktrees {
+        println!("{}", format_kept_worktree(kept));
+    }
     println!("{}", format_sync_summary(&summary));
     Ok(())
 }
 
+async fn handle_sync_command(
+    repo: &DatabaseRepository,
+    command: SyncCommand,
+) -> anyhow::Result<()> {
+    match command {
+        SyncCommand::History { id: None, limit } => {
+            let runs = repo.get_sync_runs(limit).await?;
+            if runs.is_empty() {
+                println!("No sync runs recorded");
+            }
+            for run in &runs {
+                println!("{}", format_sync_run(run));
+            }
+        }
+        SyncCommand::History { id: Some(id), .. } => {
+            let run = repo
+                .get_sync_run(id)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("no sync run #{id}"))?;
+            println!("{}", format_sync_run(&run));
+            if let Some(error) = &run.error {
+                println!("Error: {error}");
+            }
+            if !run.repositories.is_empty() {
+                println!("Repositories:");
+                for repository in &run.repositories {
+                    println!("  {}", format_sync_run_repository(repository));
+                }
+            }
+            if !run.log.is_empty() {
+                println!("Log:");
+                for line in &run.log {
+                    println!("  {line}");
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 async fn handle_prs(repo: &DatabaseRepository) -> anyhow::Result<()> {
     let username = repo
         .get_user()
@@ -271,6 +1210,51 @@ async fn handle_prs(repo: &DatabaseRepository) -> anyhow::Result<()> {
         );
     }
 
+    if !dashboard.active_inbox.is_empty() {
+        println!("Review inbox:");
+        for index in &dashboard.active_inbox {
+            let pr = &dashboard.prs[*index];
+            println!(
+                "- #{}: {} (Repository: {}, Author: {})",
+                pr.number, pr.title, pr.repository, pr.author
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_pr(repo: &DatabaseRepository, command: PrCommand) -> anyhow::Result<()> {
+    match command {
+        PrCommand::Checkout { target } => {
+            let (repository, number) = parse_pr_target(&target)?;
+            let pr = repo.get_pr(&repository, number).await?.ok_or_else(|| {
+                anyhow::anyhow!("pull request '{target}' is not tracked, run 'prt sync' first")
+            })?;
+            let checkout = checkout_pull_request(repo, &pr).await?;
+            println!("{}", format_worktree_checkout(&checkout));
+        }
+        PrCommand::Worktrees => {
+            let worktrees = repo.get_pr_worktrees().await?;
+            println!("Worktrees:");
+            for worktree in worktrees {
+                let head_sha = repo
+                    .get_pr(&worktree.repository, worktree.pr_number)
+                    .await?
+                    .map(|pr| pr.head_sha)
+                    .unwrap_or(worktree.head_sha.clone());
+                let state = match worktree_status(&worktree, &head_sha).await {
+                    WorktreeStatus::Behind { .. } => "behind",
+                    _ => "up to date",
+                };
+                println!(
+                    "- {}#{}: {} ({})",
+                    worktree.repository, worktree.pr_number, worktree.path, state
+                );
+            }
+        }
+    }
+
     Ok(())
 }
 
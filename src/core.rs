@@ -2,7 +2,7 @@ use std::collections::{BTreeMap, HashMap, HashSet};
 
 use chrono::{DateTime, Utc};
 
-use crate::models::PullRequest;
+use crate::models::{ApprovalStatus, PullRequest};
 
 #[derive(Debug, Default)]
 pub struct SyncDiff {
@@ -21,7 +21,11 @@ pub struct UpdatedPullRequest {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UpdateReason {
     CiStatusChanged,
+    ChecksChanged,
+    ReviewThreadsChanged,
+    ThreadResolutionChanged,
     LastCommentChanged,
+    CommentsTruncatedChanged,
     HeadShaChanged,
     ApprovalStatusChanged,
     RequestedReviewersChanged,
@@ -29,13 +33,18 @@ pub enum UpdateReason {
     DraftChanged,
     TitleChanged,
     UpdatedAtChanged,
+    SourceChanged,
 }
 
 impl UpdateReason {
     pub fn code(self) -> &'static str {
         match self {
             Self::CiStatusChanged => "ci",
+            Self::ChecksChanged => "checks",
+            Self::ReviewThreadsChanged => "threads",
+            Self::ThreadResolutionChanged => "thread_resolution",
             Self::LastCommentChanged => "comment",
+            Self::CommentsTruncatedChanged => "comments_truncated",
             Self::HeadShaChanged => "head_sha",
             Self::ApprovalStatusChanged => "approval",
             Self::RequestedReviewersChanged => "reviewers",
@@ -43,8 +52,88 @@ impl UpdateReason {
             Self::DraftChanged => "draft",
             Self::TitleChanged => "title",
             Self::UpdatedAtChanged => "updated_at",
+            Self::SourceChanged => "source",
         }
     }
+
+    /// The reason's code with the values it changed between `old` and `new`,
+    /// e.g. `ci: pending -> success`.
+    pub fn describe(self, old: &PullRequest, new: &PullRequest) -> String {
+        let (before, after) = match self {
+            Self::CiStatusChanged => (
+                old.ci_status.as_str().to_string(),
+                new.ci_status.as_str().to_string(),
+            ),
+            Self::ChecksChanged => (old.checks.len().to_string(), new.checks.len().to_string()),
+            Self::ReviewThreadsChanged => (
+                old.review_threads.len().to_string(),
+                new.review_threads.len().to_string(),
+            ),
+            Self::ThreadResolutionChanged => (
+                unresolved_thread_count(old).to_string(),
+                unresolved_thread_count(new).to_string(),
+            ),
+            Self::LastCommentChanged => (
+                old.last_comment_at.to_rfc3339(),
+                new.last_comment_at.to_rfc3339(),
+            ),
+            Self::CommentsTruncatedChanged => (
+                old.comments_truncated.to_string(),
+                new.comments_truncated.to_string(),
+            ),
+            Self::HeadShaChanged => (short_sha(&old.head_sha), short_sha(&new.head_sha)),
+            Self::ApprovalStatusChanged => (
+                approval_label(old.approval_status).to_string(),
+                approval_label(new.approval_status).to_string(),
+            ),
+            Self::RequestedReviewersChanged => (
+                requested_reviewers_label(old),
+                requested_reviewers_label(new),
+            ),
+            Self::UserReviewedChanged => (
+                old.user_has_reviewed.to_string(),
+                new.user_has_reviewed.to_string(),
+            ),
+            Self::DraftChanged => (old.draft.to_string(), new.draft.to_string()),
+            Self::TitleChanged => (format!("{:?}", old.title), format!("{:?}", new.title)),
+            Self::UpdatedAtChanged => (old.updated_at.to_rfc3339(), new.updated_at.to_rfc3339()),
+            Self::SourceChanged => (
+                old.source.as_str().to_string(),
+                new.source.as_str().to_string(),
+            ),
+        };
+        format!("{}: {before} -> {after}", self.code())
+    }
+}
+
+fn unresolved_thread_count(pr: &PullRequest) -> usize {
+    pr.review_threads
+        .iter()
+        .filter(|thread| !thread.is_resolved)
+        .count()
+}
+
+fn short_sha(sha: &str) -> String {
+    sha.chars().take(7).collect()
+}
+
+fn approval_label(status: ApprovalStatus) -> &'static str {
+    match status {
+        ApprovalStatus::None => "none",
+        ApprovalStatus::Approved => "approved",
+        ApprovalStatus::ChangesRequested => "changes_requested",
+    }
+}
+
+/// Requested users and teams, e.g. `[alice, @acme/core]`.
+fn requested_reviewers_label(pr: &PullRequest) -> String {
+    let names: Vec<String> = pr
+        .requested_reviewers
+        .iter()
+        .cloned()
+        .chain(pr.requested_teams.iter().map(|team| format!("@{team}")))
+        .collect();
+    format!("[{}]", names.join(", "))
 }
 
 pub fn count_update_reasons(updated_prs: &[UpdatedPullRequest]) -> BTreeMap<String, usize> {
@@ -104,6 +193,7 @@ pub fn process_pull_request_sync_results(
             &mut updated,
             update_analysis.ci_status_changed,
             update_analysis.approval_status_changed,
+            update_analysis.thread_resolution_changed,
             now,
         );
         diff.updated_prs.push(UpdatedPullRequest {
@@ -130,6 +220,7 @@ fn index_pull_requests_by_key(prs: &[PullRequest]) -> HashMap<String, PullReques
 struct PullRequestUpdateAnalysis {
     ci_status_changed: bool,
     approval_status_changed: bool,
+    thread_resolution_changed: bool,
     has_attention_changes: bool,
     has_data_changes: bool,
     reasons: Vec<UpdateReason>,
@@ -140,33 +231,60 @@ fn analyze_pull_request_changes(
     incoming_pr: &PullRequest,
 ) -> PullRequestUpdateAnalysis {
     let ci_status_changed = existing_pr.ci_status != incoming_pr.ci_status;
+    let checks_changed = existing_pr.checks != incoming_pr.checks;
+    let review_threads_changed = existing_pr.review_threads != incoming_pr.review_threads;
+    let thread_resolution_changed = thread_resolution_changed(existing_pr, incoming_pr);
     let last_comment_changed = existing_pr.last_comment_at != incoming_pr.last_comment_at;
+    let comments_truncated_changed =
+        existing_pr.comments_truncated != incoming_pr.comments_truncated;
     let head_sha_changed = existing_pr.head_sha != incoming_pr.head_sha;
     let approval_status_changed = existing_pr.approval_status != incoming_pr.approval_status;
-    let requested_reviewers_changed =
-        existing_pr.requested_reviewers != incoming_pr.requested_reviewers;
+    let requested_reviewers_changed = existing_pr.requested_reviewers
+        != incoming_pr.requested_reviewers
+        || existing_pr.requested_teams != incoming_pr.requested_teams
+        || existing_pr.team_review_requested != incoming_pr.team_review_requested;
     let user_reviewed_changed = existing_pr.user_has_reviewed != incoming_pr.user_has_reviewed;
     let draft_changed = existing_pr.draft != incoming_pr.draft;
     let title_changed = existing_pr.title != incoming_pr.title;
     let updated_at_changed = existing_pr.updated_at != incoming_pr.updated_at;
+    let source_changed = existing_pr.source != incoming_pr.source;
 
     let has_attention_changes = ci_status_changed
         || last_comment_changed
         || head_sha_changed
         || approval_status_changed
         || requested_reviewers_changed
-        || user_reviewed_changed;
-
-    let has_data_changes =
-        has_attention_changes || draft_changed || title_changed || updated_at_changed;
+        || user_reviewed_changed
+        || thread_resolution_changed;
+
+    let has_data_changes = has_attention_changes
+        || checks_changed
+        || review_threads_changed
+        || comments_truncated_changed
+        || draft_changed
+        || title_changed
+        || updated_at_changed
+        || source_changed;
 
     let mut reasons = Vec::new();
     if ci_status_changed {
         reasons.push(UpdateReason::CiStatusChanged);
     }
+    if checks_changed {
+        reasons.push(UpdateReason::ChecksChanged);
+    }
+    if review_threads_changed {
+        reasons.push(UpdateReason::ReviewThreadsChanged);
+    }
+    if thread_resolution_changed {
+        reasons.push(UpdateReason::ThreadResolutionChanged);
+    }
     if last_comment_changed {
         reasons.push(UpdateReason::LastCommentChanged);
     }
+    if comments_truncated_changed {
+        reasons.push(UpdateReason::CommentsTruncatedChanged);
+    }
     if head_sha_changed {
         reasons.push(UpdateReason::HeadShaChanged);
     }
@@ -188,21 +306,36 @@ fn analyze_pull_request_changes(
     if updated_at_changed {
         reasons.push(UpdateReason::UpdatedAtChanged);
     }
+    if source_changed {
+        reasons.push(UpdateReason::SourceChanged);
+    }
 
     PullRequestUpdateAnalysis {
         ci_status_changed,
         approval_status_changed,
+        thread_resolution_changed,
         has_attention_changes,
         has_data_changes,
         reasons,
     }
 }
 
+/// Whether a thread both copies know about was resolved or reopened. New
+/// threads arrive with review activity, which is tracked separately.
+fn thread_resolution_changed(existing_pr: &PullRequest, incoming_pr: &PullRequest) -> bool {
+    incoming_pr.review_threads.iter().any(|incoming| {
+        existing_pr.review_threads.iter().any(|existing| {
+            existing.id == incoming.id && existing.is_resolved != incoming.is_resolved
+        })
+    })
+}
+
 fn apply_sync_metadata(
     existing_pr: &PullRequest,
     incoming_pr: &mut PullRequest,
     ci_status_changed: bool,
     approval_status_changed: bool,
+    thread_resolution_changed: bool,
     now: DateTime<Utc>,
 ) {
     incoming_pr.last_acknowledged_at = existing_pr.last_acknowledged_at;
@@ -221,6 +354,11 @@ fn apply_sync_metadata(
     } else {
         existing_pr.last_review_status_update_at
     };
+    incoming_pr.last_thread_update_at = if thread_resolution_changed {
+        now
+    } else {
+        existing_pr.last_thread_update_at
+    };
 }
 
 fn collect_removed_pull_requests(
@@ -244,7 +382,7 @@ mod tests {
     use chrono::{DateTime, TimeZone, Utc};
 
     use super::process_pull_request_sync_results;
-    use crate::models::{ApprovalStatus, CiStatus, PullRequest};
+    use crate::models::{ApprovalStatus, CiStatus, PrCheck, PrReviewThread, PrSource, PullRequest};
 
     fn dt(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
         Utc.with_ymd_and_hms(year, month, day, hour, 0, 0)
@@ -270,15 +408,22 @@ mod tests {
             last_review_status_update_at: DateTime::UNIX_EPOCH,
             last_acknowledged_at: None,
             requested_reviewers: Vec::new(),
+            requested_teams: Vec::new(),
             user_has_reviewed: false,
+            team_review_requested: false,
             comments: Vec::new(),
+            checks: Vec::new(),
+            review_threads: Vec::new(),
+            last_thread_update_at: DateTime::UNIX_EPOCH,
+            comments_truncated: false,
+            source: PrSource::Tracked,
         }
     }
 
     #[test]
     fn classifies_new_pr() {
         let pr = empty_pr("acme/repo", 1);
-        let result = process_pull_request_sync_results(&[], &[pr.clone()], Utc::now());
+        let result = process_pull_request_sync_results(&[], std::slice::from_ref(&pr), Utc::now());
 
         assert_eq!(result.new_prs, vec![pr]);
         assert!(result.updated_prs.is_empty());
@@ -301,7 +446,11 @@ mod tests {
             ..empty_pr("acme/repo", 1)
         };
 
-        let result = process_pull_request_sync_results(&[db_pr], &[fresh_pr.clone()], Utc::now());
+        let result = process_pull_request_sync_results(
+            &[db_pr],
+            std::slice::from_ref(&fresh_pr),
+            Utc::now(),
+        );
         assert!(result.new_prs.is_empty());
         assert_eq!(result.updated_prs.len(), 1);
         assert_eq!(result.updated_prs[0].pr.number, fresh_pr.number);
@@ -311,7 +460,7 @@ mod tests {
     #[test]
     fn classifies_removed_pr() {
         let pr = empty_pr("acme/repo", 1);
-        let result = process_pull_request_sync_results(&[pr.clone()], &[], Utc::now());
+        let result = process_pull_request_sync_results(std::slice::from_ref(&pr), &[], Utc::now());
         assert!(result.new_prs.is_empty());
         assert!(result.updated_prs.is_empty());
         assert_eq!(result.removed_prs, vec![pr]);
@@ -455,6 +604,81 @@ mod tests {
             .contains(&super::UpdateReason::CiStatusChanged));
     }
 
+    #[test]
+    fn check_only_changes_are_saved_without_needing_attention() {
+        let check = |conclusion: &str| PrCheck {
+            workflow: String::new(),
+            name: "lint".to_string(),
+            status: "COMPLETED".to_string(),
+            conclusion: Some(conclusion.to_string()),
+            ci_status: CiStatus::Success,
+            is_required: false,
+            counts_towards_ci: false,
+            details_url: None,
+            started_at: None,
+            completed_at: None,
+        };
+        let db_pr = PullRequest {
+            checks: vec![check("SUCCESS")],
+            ..empty_pr("acme/repo", 1)
+        };
+        let fresh_pr = PullRequest {
+            checks: vec![check("NEUTRAL")],
+            ..empty_pr("acme/repo", 1)
+        };
+
+        let result = process_pull_request_sync_results(&[db_pr], &[fresh_pr], Utc::now());
+
+        assert_eq!(result.updated_prs.len(), 1);
+        assert!(!result.updated_prs[0].attention_changed);
+        assert_eq!(
+            result.updated_prs[0].reasons,
+            vec![super::UpdateReason::ChecksChanged]
+        );
+    }
+
+    #[test]
+    fn thread_resolution_needs_attention_but_new_threads_do_not() {
+        let now = dt(2025, 1, 1, 2);
+        let thread = |id: &str, is_resolved| PrReviewThread {
+            id: id.to_string(),
+            path: "src/lib.rs".to_string(),
+            line: Some(3),
+            is_resolved,
+            is_outdated: false,
+            author: "bob".to_string(),
+            body: "nit".to_string(),
+            comment_count: 1,
+        };
+        let db_pr = PullRequest {
+            review_threads: vec![thread("a", false)],
+            ..empty_pr("acme/repo", 1)
+        };
+        let resolved = PullRequest {
+            review_threads: vec![thread("a", true)],
+            ..empty_pr("acme/repo", 1)
+        };
+        let added = PullRequest {
+            review_threads: vec![thread("a", false), thread("b", false)],
+            ..empty_pr("acme/repo", 1)
+        };
+
+        let result =
+            process_pull_request_sync_results(std::slice::from_ref(&db_pr), &[resolved], now);
+        assert!(result.updated_prs[0].attention_changed);
+        assert_eq!(result.updated_prs[0].pr.last_thread_update_at, now);
+        assert!(result.updated_prs[0]
+            .reasons
+            .contains(&super::UpdateReason::ThreadResolutionChanged));
+
+        let result = process_pull_request_sync_results(&[db_pr], &[added], now);
+        assert!(!result.updated_prs[0].attention_changed);
+        assert_eq!(
+            result.updated_prs[0].reasons,
+            vec![super::UpdateReason::ReviewThreadsChanged]
+        );
+    }
+
     #[test]
     fn updates_review_status_from_api_timestamp() {
         let before = dt(2025, 1, 1, 0);
@@ -523,6 +747,22 @@ mod tests {
         assert!(update.reasons.contains(&super::UpdateReason::TitleChanged));
     }
 
+    #[test]
+    fn source_only_change_is_data_update_without_attention_update() {
+        let db_pr = PullRequest {
+            source: PrSource::ReviewInbox,
+            ..empty_pr("acme/repo", 1)
+        };
+        let fresh_pr = empty_pr("acme/repo", 1);
+
+        let result = process_pull_request_sync_results(&[db_pr], &[fresh_pr], Utc::now());
+
+        assert_eq!(result.updated_prs.len(), 1);
+        let update = &result.updated_prs[0];
+        assert!(!update.attention_changed);
+        assert!(update.reasons.contains(&super::UpdateReason::SourceChanged));
+    }
+
     #[test]
     fn update_reason_codes_are_aggregated() {
         let db_pr = PullRequest {
@@ -542,4 +782,44 @@ mod tests {
         assert_eq!(counts.get("draft"), Some(&1));
         assert_eq!(counts.get("title"), Some(&1));
     }
+
+    #[test]
+    fn update_reasons_describe_old_and_new_values() {
+        let old = PullRequest {
+            ci_status: CiStatus::Pending,
+            head_sha: "0123456789abcdef".to_string(),
+            title: "old".to_string(),
+            requested_reviewers: vec!["alice".to_string()],
+            ..empty_pr("acme/repo", 1)
+        };
+        let new = PullRequest {
+            ci_status: CiStatus::Success,
+            head_sha: "fedcba9876543210".to_string(),
+            title: "new".to_string(),
+            approval_status: ApprovalStatus::Approved,
+            requested_teams: vec!["acme/core".to_string()],
+            ..empty_pr("acme/repo", 1)
+        };
+
+        assert_eq!(
+            super::UpdateReason::CiStatusChanged.describe(&old, &new),
+            "ci: pending -> success"
+        );
+        assert_eq!(
+            super::UpdateReason::HeadShaChanged.describe(&old, &new),
+            "head_sha: 0123456 -> fedcba9"
+        );
+        assert_eq!(
+            super::UpdateReason::TitleChanged.describe(&old, &new),
+            "title: \"old\" -> \"new\""
+        );
+        assert_eq!(
+            super::UpdateReason::ApprovalStatusChanged.describe(&old, &new),
+            "approval: none -> approved"
+        );
+        assert_eq!(
+            super::UpdateReason::RequestedReviewersChanged.describe(&old, &new),
+            "reviewers: [alice] -> [@acme/core]"
+        );
+    }
 }
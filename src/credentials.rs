@@ -0,0 +1,393 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::github::app_auth::InstallationTokenProvider;
+use crate::github::{GitHubClient, BASE_URL};
+use crate::launcher::expand_home;
+use crate::models::{CredentialSource, User};
+
+/// Host used for `gh` CLI credentials when none is given.
+pub const DEFAULT_GH_HOST: &str = "github.com";
+
+/// Build a GitHub client authenticated with the user's token, wherever it lives.
+pub fn github_client_for_user(user: &User) -> anyhow::Result<GitHubClient> {
+    if let CredentialSource::GitHubApp {
+        app_id,
+        installation_id,
+        private_key_path,
+    } = &user.credential
+    {
+        let private_key = read_private_file(Path::new(&expand_home(private_key_path)))?;
+        let provider =
+            InstallationTokenProvider::new(app_id, *installation_id, &private_key, BASE_URL)?;
+        return GitHubClient::new(provider);
+    }
+
+    let token = resolve_token(&user.credential)
+        .map_err(|err| anyhow::anyhow!("failed to load token for '{}': {err}", user.username))?;
+    GitHubClient::new(token)
+}
+
+pub fn resolve_token(source: &CredentialSource) -> anyhow::Result<String> {
+    let token = match source {
+        CredentialSource::Stored(token) => token.clone(),
+        CredentialSource::Env(name) => std::env::var(name)
+            .map_err(|_| anyhow::anyhow!("environment variable '{name}' is not set"))?,
+        CredentialSource::Command(command) => run_token_command(command)?,
+        CredentialSource::GhCli(host) => read_gh_cli_token(host)?,
+        CredentialSource::File(path) => read_private_file(Path::new(&expand_home(path)))?,
+        CredentialSource::GitHubApp { .. } => {
+            anyhow::bail!("GitHub App credentials mint tokens per request and cannot be resolved to a single token")
+        }
+    };
+
+    let token = token.trim().to_string();
+    if token.is_empty() {
+        anyhow::bail!(
+            "credential source '{}' returned an empty token",
+            source.reference()
+        );
+    }
+    Ok(token)
+}
+
+fn run_token_command(command: &str) -> anyhow::Result<String> {
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .output()
+        .map_err(|err| anyhow::anyhow!("failed to run token command: {err}"))?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "token command '{command}' failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Read the token `gh` stores for `host`. Recent `gh` versions keep it in the
+/// system keyring instead of `hosts.yml`, so fall back to `gh auth token`.
+fn read_gh_cli_token(host: &str) -> anyhow::Result<String> {
+    let from_hosts_file = gh_hosts_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| parse_gh_hosts_token(&contents, host));
+    if let Some(token) = from_hosts_file {
+        return Ok(token);
+    }
+
+    let output = Command::new("gh")
+        .args(["auth", "token", "--hostname", host])
+        .output()
+        .map_err(|err| anyhow::anyhow!("no gh token for '{host}' and failed to run gh: {err}"))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "no gh token for '{host}': {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn gh_hosts_path() -> Option<PathBuf> {
+    let non_empty = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+    if let Some(dir) = non_empty("GH_CONFIG_DIR") {
+        return Some(PathBuf::from(dir).join("hosts.yml"));
+    }
+    if let Some(dir) = non_empty("XDG_CONFIG_HOME") {
+        return Some(PathBuf::from(dir).join("gh").join("hosts.yml"));
+    }
+    non_empty("HOME").map(|home| PathBuf::from(home).join(".config/gh/hosts.yml"))
+}
+
+/// Extract `oauth_token` for `host` from a `gh` `hosts.yml` file.
+///
+/// The file is a flat two-level mapping, so a line scan is enough and avoids
+/// pulling in a YAML parser.
+pub fn parse_gh_hosts_token(contents: &str, host: &str) -> Option<String> {
+    let mut in_host = false;
+
+    for line in contents.lines() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        if !line.starts_with(char::is_whitespace) {
+            let key = line.trim_end().trim_end_matches(':');
+            in_host = unquote(key) == host;
+            continue;
+        }
+
+        if !in_host {
+            continue;
+        }
+
+        if let Some((key, value)) = line.trim().split_once(':') {
+            if key.trim() == "oauth_token" {
+                let value = unquote(value.trim());
+                if !value.is_empty() {
+                    return Some(value.to_string());
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .or_else(|| {
+            value
+                .strip_prefix('\'')
+                .and_then(|value| value.strip_suffix('\''))
+        })
+        .unwrap_or(value)
+}
+
+/// Directory for the token files `prt` writes itself, next to the default
+/// database.
+pub fn token_dir() -> anyhow::Result<PathBuf> {
+    crate::data_dir()
+        .map(|dir| dir.join("tokens"))
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "no data directory for token files (set XDG_DATA_HOME or HOME), \
+                 or add the account with --env, --token-command, --gh or --file"
+            )
+        })
+}
+
+/// Keep a token given on the command line out of the database: write it to a
+/// file in `token_dir` that only its owner can read and reference that file.
+/// Other sources are returned unchanged.
+pub fn store_token_in_file(
+    login: &str,
+    credential: CredentialSource,
+) -> anyhow::Result<CredentialSource> {
+    match credential {
+        CredentialSource::Stored(token) => {
+            let path = write_token_file(&token_dir()?, login, &token)?;
+            Ok(CredentialSource::File(path.display().to_string()))
+        }
+        other => Ok(other),
+    }
+}
+
+/// Write `token` to `dir/<login>` with mode 0600, replacing any earlier file.
+pub fn write_token_file(dir: &Path, login: &str, token: &str) -> anyhow::Result<PathBuf> {
+    create_private_dir(dir)?;
+    let path = dir.join(login.to_ascii_lowercase());
+    let partial = path.with_extension("partial");
+    write_private_file(&partial, token)?;
+    std::fs::rename(&partial, &path)
+        .map_err(|err| anyhow::anyhow!("failed to write {}: {err}", path.display()))?;
+    Ok(path)
+}
+
+/// Whether `prt` itself holds `credential`'s token, either in the database or
+/// in a file it wrote; other sources belong to the user and are only read.
+pub fn is_managed_token(credential: &CredentialSource) -> bool {
+    matches!(credential, CredentialSource::Stored(_)) || managed_token_file(credential).is_some()
+}
+
+fn managed_token_file(credential: &CredentialSource) -> Option<&Path> {
+    let CredentialSource::File(path) = credential else {
+        return None;
+    };
+    let path = Path::new(path);
+    token_dir()
+        .is_ok_and(|dir| path.parent() == Some(dir.as_path()))
+        .then_some(path)
+}
+
+/// Delete the token file of `credential` if `prt` wrote it into `token_dir`;
+/// files the user pointed an account at are left alone.
+pub fn remove_token_file(credential: &CredentialSource) -> anyhow::Result<()> {
+    if let Some(path) = managed_token_file(credential) {
+        match std::fs::remove_file(path) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                anyhow::bail!("failed to remove {}: {err}", path.display())
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn create_private_dir(dir: &Path) -> anyhow::Result<()> {
+    use std::os::unix::fs::DirBuilderExt;
+
+    std::fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(dir)
+        .map_err(|err| anyhow::anyhow!("failed to create {}: {err}", dir.display()))
+}
+
+#[cfg(not(unix))]
+fn create_private_dir(dir: &Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir)
+        .map_err(|err| anyhow::anyhow!("failed to create {}: {err}", dir.display()))
+}
+
+#[cfg(unix)]
+fn write_private_file(path: &Path, contents: &str) -> anyhow::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    let write = || -> std::io::Result<()> {
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(path)?;
+        // The mode only applies to new files.
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        file.write_all(contents.as_bytes())
+    };
+    write().map_err(|err| anyhow::anyhow!("failed to write {}: {err}", path.display()))
+}
+
+#[cfg(not(unix))]
+fn write_private_file(path: &Path, contents: &str) -> anyhow::Result<()> {
+    std::fs::write(path, contents)
+        .map_err(|err| anyhow::anyhow!("failed to write {}: {err}", path.display()))
+}
+
+/// Read a secret file, refusing it if group or others can access it.
+pub fn read_private_file(path: &Path) -> anyhow::Result<String> {
+    let metadata = std::fs::metadata(path)
+        .map_err(|err| anyhow::anyhow!("failed to read {}: {err}", path.display()))?;
+    ensure_private_permissions(path, &metadata)?;
+
+    std::fs::read_to_string(path)
+        .map_err(|err| anyhow::anyhow!("failed to read {}: {err}", path.display()))
+}
+
+#[cfg(unix)]
+fn ensure_private_permissions(path: &Path, metadata: &std::fs::Metadata) -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = metadata.permissions().mode() & 0o777;
+    if mode & 0o077 != 0 {
+        anyhow::bail!(
+            "{} has permissions {:04o}, expected 0600 (run 'chmod 600 {}')",
+            path.display(),
+            mode,
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn ensure_private_permissions(_path: &Path, _metadata: &std::fs::Metadata) -> anyhow::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_gh_hosts_token_finds_token_for_host() {
+        let contents = "\
+github.com:
+    user: octocat
+    oauth_token: gho_public
+    git_protocol: https
+github.example.com:
+    oauth_token: \"gho_enterprise\"
+";
+
+        assert_eq!(
+            parse_gh_hosts_token(contents, "github.com").as_deref(),
+            Some("gho_public")
+        );
+        assert_eq!(
+            parse_gh_hosts_token(contents, "github.example.com").as_deref(),
+            Some("gho_enterprise")
+        );
+        assert_eq!(parse_gh_hosts_token(contents, "gitlab.com"), None);
+    }
+
+    #[test]
+    fn parse_gh_hosts_token_ignores_hosts_without_token() {
+        let contents = "github.com:\n    user: octocat\n    git_protocol: ssh\n";
+
+        assert_eq!(parse_gh_hosts_token(contents, "github.com"), None);
+    }
+
+    #[test]
+    fn resolve_token_trims_command_output() {
+        let source = CredentialSource::Command("printf '  tok123\\n'".to_string());
+
+        assert_eq!(resolve_token(&source).expect("token resolves"), "tok123");
+    }
+
+    #[test]
+    fn resolve_token_reports_missing_env_var() {
+        let source = CredentialSource::Env("PR_TRACKER_TEST_UNSET_TOKEN_VAR".to_string());
+
+        let err = resolve_token(&source).unwrap_err();
+        assert!(err.to_string().contains("PR_TRACKER_TEST_UNSET_TOKEN_VAR"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn read_private_file_rejects_group_readable_files() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join(format!("prt-token-{}", std::process::id()));
+        std::fs::write(&path, "tok123\n").expect("write token file");
+
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).expect("chmod 644");
+        assert!(read_private_file(&path).is_err());
+
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).expect("chmod 600");
+        assert_eq!(read_private_file(&path).expect("readable").trim(), "tok123");
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn write_token_file_creates_a_private_file_per_login() {
+        let dir = std::env::temp_dir().join(format!("prt-tokens-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let path = write_token_file(&dir, "OctoCat", "tok123").expect("token written");
+        write_token_file(&dir, "octocat", "tok456").expect("token replaced");
+
+        assert_eq!(path, dir.join("octocat"));
+        assert_eq!(read_private_file(&path).expect("readable"), "tok456");
+        assert_eq!(std::fs::read_dir(&dir).expect("listable").count(), 1);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn only_tokens_prt_wrote_are_managed() {
+        assert!(is_managed_token(&CredentialSource::Stored(
+            "tok".to_string()
+        )));
+        assert!(!is_managed_token(&CredentialSource::Env(
+            "GITHUB_TOKEN".to_string()
+        )));
+        assert!(!is_managed_token(&CredentialSource::File(
+            "/home/octocat/.config/prt/token".to_string()
+        )));
+        if let Ok(dir) = token_dir() {
+            let written = dir.join("octocat").display().to_string();
+            assert!(is_managed_token(&CredentialSource::File(written)));
+        }
+    }
+}
@@ -1,16 +1,25 @@
 use chrono::{DateTime, Utc};
 use sqlx::migrate::Migrator;
 use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
-use sqlx::{FromRow, Row, SqlitePool};
+use sqlx::{FromRow, QueryBuilder, Row, SqliteConnection, SqlitePool};
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use std::str::FromStr;
 
-use crate::models::{ApprovalStatus, CiStatus, PrComment, PullRequest, TrackedRepository, User};
+use crate::models::{
+    ApprovalStatus, CiPolicy, CiStatus, CredentialSource, FollowedDiscoveryRule, PrCheck,
+    PrComment, PrReviewThread, PrSource, PrWorktree, PullRequest, RepositoryDiscoveryRule,
+    RepositoryLocalPath, ReviewLauncher, SyncRun, SyncRunRepository, SyncTrigger,
+    TrackedRepository, User, UserTeams,
+};
 use crate::pr_repository::{build_pr_dashboard, PrDashboard};
 
 pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
 
+/// Sync runs kept in `sync_runs`; older ones are dropped as new runs start.
+const MAX_SYNC_RUNS: i64 = 500;
+
 #[derive(Clone)]
 pub struct DatabaseRepository {
     pool: SqlitePool,
@@ -32,66 +41,77 @@ impl DatabaseRepository {
         Ok(())
     }
 
+    /// Upsert a PR and replace its checks and review threads with the ones on `pr`.
     pub async fn save_pr(&self, pr: &PullRequest) -> anyhow::Result<()> {
-        let reviewers_json = serde_json::to_string(&pr.requested_reviewers)?;
-        sqlx::query(
-            r#"
-            INSERT INTO pull_requests (
-              number, title, repository, author, head_sha, draft, created_at_unix,
-              updated_at_unix, ci_status, last_comment_unix, last_commit_unix,
-              last_ci_status_update_unix, last_acknowledged_unix, requested_reviewers,
-              approval_status, last_review_status_update_unix, user_has_reviewed
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
-            ON CONFLICT(repository, number) DO UPDATE SET
-              title = excluded.title,
-              repository = excluded.repository,
-              author = excluded.author,
-              head_sha = excluded.head_sha,
-              draft = excluded.draft,
-              updated_at_unix = excluded.updated_at_unix,
-              ci_status = excluded.ci_status,
-              last_comment_unix = excluded.last_comment_unix,
-              last_commit_unix = excluded.last_commit_unix,
-              last_ci_status_update_unix = excluded.last_ci_status_update_unix,
-              last_acknowledged_unix = excluded.last_acknowledged_unix,
-              requested_reviewers = excluded.requested_reviewers,
-              approval_status = excluded.approval_status,
-              last_review_status_update_unix = excluded.last_review_status_update_unix,
-              user_has_reviewed = excluded.user_has_reviewed
-            "#,
-        )
-        .bind(pr.number)
-        .bind(&pr.title)
-        .bind(&pr.repository)
-        .bind(&pr.author)
-        .bind(&pr.head_sha)
-        .bind(pr.draft)
-        .bind(pr.created_at.timestamp())
-        .bind(pr.updated_at.timestamp())
-        .bind(pr.ci_status.as_i64())
-        .bind(pr.last_comment_at.timestamp())
-        .bind(pr.last_commit_at.timestamp())
-        .bind(pr.last_ci_status_update_at.timestamp())
-        .bind(pr.last_acknowledged_at.map(|t| t.timestamp()))
-        .bind(reviewers_json)
-        .bind(pr.approval_status.as_i64())
-        .bind(pr.last_review_status_update_at.timestamp())
-        .bind(pr.user_has_reviewed)
-        .execute(&self.pool)
-        .await?;
-
+        let mut tx = self.pool.begin().await?;
+        upsert_pull_requests(&mut tx, &[pr]).await?;
+        replace_checks_and_review_threads(&mut tx, &[pr]).await?;
+        tx.commit().await?;
         Ok(())
     }
 
-    pub async fn delete_pr(&self, repo_name: &str, pr_number: i64) -> anyhow::Result<()> {
-        sqlx::query("DELETE FROM pull_requests WHERE repository = ?1 AND number = ?2")
+    /// Persist everything one repository's sync produced in a single
+    /// transaction: the saved PRs (with their checks and review threads), the
+    /// closed PRs and deleted comments to drop, the fetched comments and the
+    /// new watermark. Either
+    /// all of it lands or none of it does, so an interrupted sync never leaves
+    /// a repository half written.
+    pub async fn save_repository_sync(
+        &self,
+        repo_name: &str,
+        prs: &[&PullRequest],
+        closed_pr_numbers: &[i64],
+        stale_comment_ids: &[String],
+        comments: &[PrComment],
+        last_synced_at: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        upsert_pull_requests(&mut tx, prs).await?;
+        replace_checks_and_review_threads(&mut tx, prs).await?;
+        for chunk in closed_pr_numbers.chunks(SQLITE_MAX_BINDS - 1) {
+            let mut query = QueryBuilder::new("DELETE FROM pull_requests WHERE repository = ");
+            query.push_bind(repo_name).push(" AND number IN (");
+            let mut numbers = query.separated(", ");
+            for number in chunk {
+                numbers.push_bind(*number);
+            }
+            query.push(")");
+            query.build().execute(&mut *tx).await?;
+        }
+        for chunk in stale_comment_ids.chunks(SQLITE_MAX_BINDS) {
+            let mut query = QueryBuilder::new("DELETE FROM pr_comments WHERE id IN (");
+            let mut ids = query.separated(", ");
+            for id in chunk {
+                ids.push_bind(id);
+            }
+            query.push(")");
+            query.build().execute(&mut *tx).await?;
+        }
+        upsert_comments(&mut tx, comments).await?;
+        if let Some(last_synced_at) = last_synced_at {
+            sqlx::query(
+                "UPDATE tracked_repositories SET last_synced_at_unix = ?1 WHERE repository = ?2",
+            )
+            .bind(last_synced_at.timestamp())
             .bind(repo_name)
-            .bind(pr_number)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
+        }
+        tx.commit().await?;
         Ok(())
     }
 
+    /// Repositories that hold at least one PR found by the review inbox.
+    pub async fn get_review_inbox_repositories(&self) -> anyhow::Result<Vec<String>> {
+        let rows = sqlx::query(
+            "SELECT DISTINCT repository FROM pull_requests WHERE source = ?1 ORDER BY repository",
+        )
+        .bind(PrSource::ReviewInbox.as_str())
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|row| row.get("repository")).collect())
+    }
+
     pub async fn get_prs_by_repository(&self, repo_name: &str) -> anyhow::Result<Vec<PullRequest>> {
         let rows = sqlx::query_as::<_, PullRequestWithCommentsRow>(
             r#"
@@ -113,6 +133,11 @@ impl DatabaseRepository {
                 pr.approval_status,
                 pr.last_review_status_update_unix,
                 pr.user_has_reviewed,
+                pr.last_thread_update_unix,
+                pr.comments_truncated,
+                pr.requested_teams,
+                pr.team_review_requested,
+                pr.source,
                 COALESCE(
                     json_group_array(
                         json_object(
@@ -124,12 +149,55 @@ impl DatabaseRepository {
                             'created_at_unix', c.created_at_unix,
                             'updated_at_unix', c.updated_at_unix,
                             'is_review_comment', c.is_review_comment,
-                            'review_state', c.review_state
+                            'review_state', c.review_state,
+                            'path', c.path,
+                            'line', c.line,
+                            'diff_hunk', c.diff_hunk,
+                            'is_outdated', c.is_outdated,
+                            'mentions_user', c.mentions_user,
+                            'edited_at_unix', c.edited_at_unix,
+                            'is_minimized', c.is_minimized
                         )
                         ORDER BY c.created_at_unix ASC
                     ) FILTER (WHERE c.id IS NOT NULL),
                     '[]'
-                ) as comments_json
+                ) as comments_json,
+                (
+                    SELECT json_group_array(
+                        json_object(
+                            'workflow', k.workflow,
+                            'name', k.name,
+                            'status', k.status,
+                            'conclusion', k.conclusion,
+                            'ci_status', k.ci_status,
+                            'is_required', k.is_required,
+                            'counts_towards_ci', k.counts_towards_ci,
+                            'details_url', k.details_url,
+                            'started_at_unix', k.started_at_unix,
+                            'completed_at_unix', k.completed_at_unix
+                        )
+                        ORDER BY k.workflow ASC, k.name ASC
+                    )
+                    FROM pr_checks k
+                    WHERE k.repository = pr.repository AND k.pr_number = pr.number
+                ) as checks_json,
+                (
+                    SELECT json_group_array(
+                        json_object(
+                            'id', t.id,
+                            'path', t.path,
+                            'line', t.line,
+                            'is_resolved', t.is_resolved,
+                            'is_outdated', t.is_outdated,
+                            'author', t.author,
+                            'body', t.body,
+                            'comment_count', t.comment_count
+                        )
+                        ORDER BY t.path ASC, t.line ASC
+                    )
+                    FROM pr_review_threads t
+                    WHERE t.repository = pr.repository AND t.pr_number = pr.number
+                ) as threads_json
             FROM pull_requests pr
             LEFT JOIN pr_comments c 
                 ON pr.repository = c.repository AND pr.number = c.pr_number
@@ -176,6 +244,11 @@ impl DatabaseRepository {
                 pr.approval_status,
                 pr.last_review_status_update_unix,
                 pr.user_has_reviewed,
+                pr.last_thread_update_unix,
+                pr.comments_truncated,
+                pr.requested_teams,
+                pr.team_review_requested,
+                pr.source,
                 COALESCE(
                     json_group_array(
                         json_object(
@@ -187,12 +260,55 @@ impl DatabaseRepository {
                             'created_at_unix', c.created_at_unix,
                             'updated_at_unix', c.updated_at_unix,
                             'is_review_comment', c.is_review_comment,
-                            'review_state', c.review_state
+                            'review_state', c.review_state,
+                            'path', c.path,
+                            'line', c.line,
+                            'diff_hunk', c.diff_hunk,
+                            'is_outdated', c.is_outdated,
+                            'mentions_user', c.mentions_user,
+                            'edited_at_unix', c.edited_at_unix,
+                            'is_minimized', c.is_minimized
                         )
                         ORDER BY c.created_at_unix ASC
                     ) FILTER (WHERE c.id IS NOT NULL),
                     '[]'
-                ) as comments_json
+                ) as comments_json,
+                (
+                    SELECT json_group_array(
+                        json_object(
+                            'workflow', k.workflow,
+                            'name', k.name,
+                            'status', k.status,
+                            'conclusion', k.conclusion,
+                            'ci_status', k.ci_status,
+                            'is_required', k.is_required,
+                            'counts_towards_ci', k.counts_towards_ci,
+                            'details_url', k.details_url,
+                            'started_at_unix', k.started_at_unix,
+                            'completed_at_unix', k.completed_at_unix
+                        )
+                        ORDER BY k.workflow ASC, k.name ASC
+                    )
+                    FROM pr_checks k
+                    WHERE k.repository = pr.repository AND k.pr_number = pr.number
+                ) as checks_json,
+                (
+                    SELECT json_group_array(
+                        json_object(
+                            'id', t.id,
+                            'path', t.path,
+                            'line', t.line,
+                            'is_resolved', t.is_resolved,
+                            'is_outdated', t.is_outdated,
+                            'author', t.author,
+                            'body', t.body,
+                            'comment_count', t.comment_count
+                        )
+                        ORDER BY t.path ASC, t.line ASC
+                    )
+                    FROM pr_review_threads t
+                    WHERE t.repository = pr.repository AND t.pr_number = pr.number
+                ) as threads_json
             FROM pull_requests pr
             LEFT JOIN pr_comments c 
                 ON pr.repository = c.repository AND pr.number = c.pr_number
@@ -206,30 +322,135 @@ impl DatabaseRepository {
         rows.into_iter().map(|row| row.into_model()).collect()
     }
 
+    /// Returns the active account, or the oldest one if none is marked active.
     pub async fn get_user(&self) -> anyhow::Result<Option<User>> {
-        let rows = sqlx::query("SELECT id, username, access_token FROM users")
-            .fetch_all(&self.pool)
-            .await?;
+        let row = sqlx::query(
+            r#"
+            SELECT username, access_token, credential_source, is_active, scopes FROM users
+            ORDER BY is_active DESC, id ASC
+            LIMIT 1
+            "#,
+        )
+        .fetch_optional(&self.pool)
+        .await?;
 
-        if rows.len() > 1 {
-            anyhow::bail!("fatal error: expected at most 1 user, got {}", rows.len());
-        }
+        row.map(user_from_row).transpose()
+    }
 
-        Ok(rows.into_iter().next().map(|row| User {
-            username: row.get::<String, _>("username"),
-            access_token: row.get::<String, _>("access_token"),
-        }))
+    pub async fn get_users(&self) -> anyhow::Result<Vec<User>> {
+        let rows = sqlx::query(
+            "SELECT username, access_token, credential_source, is_active, scopes FROM users ORDER BY id",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(user_from_row).collect()
     }
 
+    pub async fn get_user_by_username(&self, username: &str) -> anyhow::Result<Option<User>> {
+        let row = sqlx::query(
+            "SELECT username, access_token, credential_source, is_active, scopes FROM users WHERE username = ?1 COLLATE NOCASE",
+        )
+        .bind(username)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(user_from_row).transpose()
+    }
+
+    /// Insert a new account. The first account becomes the active one.
     pub async fn save_user(&self, user: &User) -> anyhow::Result<()> {
-        sqlx::query("INSERT INTO users (username, access_token) VALUES (?1, ?2)")
-            .bind(&user.username)
-            .bind(&user.access_token)
-            .execute(&self.pool)
-            .await?;
+        sqlx::query(
+            r#"
+            INSERT INTO users (username, access_token, credential_source, is_active, scopes)
+            VALUES (?1, ?2, ?3, ?4 OR NOT EXISTS (SELECT 1 FROM users WHERE is_active = 1), ?5)
+            "#,
+        )
+        .bind(&user.username)
+        .bind(user.credential.stored_token())
+        .bind(user.credential.reference())
+        .bind(user.is_active)
+        .bind(
+            user.scopes
+                .as_ref()
+                .map(serde_json::to_string)
+                .transpose()?,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn update_user_credential(
+        &self,
+        username: &str,
+        credential: &CredentialSource,
+        scopes: Option<&[String]>,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE users SET access_token = ?1, credential_source = ?2, scopes = ?3
+            WHERE username = ?4 COLLATE NOCASE
+            "#,
+        )
+        .bind(credential.stored_token())
+        .bind(credential.reference())
+        .bind(scopes.map(serde_json::to_string).transpose()?)
+        .bind(username)
+        .execute(&self.pool)
+        .await?;
         Ok(())
     }
 
+    pub async fn set_active_user(&self, username: &str) -> anyhow::Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        let exists = sqlx::query("SELECT 1 FROM users WHERE username = ?1 COLLATE NOCASE")
+            .bind(username)
+            .fetch_optional(&mut *tx)
+            .await?
+            .is_some();
+        if !exists {
+            return Ok(false);
+        }
+
+        sqlx::query("UPDATE users SET is_active = (username = ?1 COLLATE NOCASE)")
+            .bind(username)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    /// Remove an account. Repositories assigned to it fall back to the active
+    /// account, and if it was active the oldest remaining account takes over.
+    pub async fn delete_user(&self, username: &str) -> anyhow::Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        let deleted = sqlx::query("DELETE FROM users WHERE username = ?1 COLLATE NOCASE")
+            .bind(username)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected()
+            > 0;
+
+        sqlx::query(
+            "UPDATE tracked_repositories SET account = NULL WHERE account = ?1 COLLATE NOCASE",
+        )
+        .bind(username)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query(
+            r#"
+            UPDATE users SET is_active = 1
+            WHERE id = (SELECT MIN(id) FROM users)
+              AND NOT EXISTS (SELECT 1 FROM users WHERE is_active = 1)
+            "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(deleted)
+    }
+
     pub async fn get_pr(
         &self,
         repo_name: &str,
@@ -255,6 +476,11 @@ impl DatabaseRepository {
                 pr.approval_status,
                 pr.last_review_status_update_unix,
                 pr.user_has_reviewed,
+                pr.last_thread_update_unix,
+                pr.comments_truncated,
+                pr.requested_teams,
+                pr.team_review_requested,
+                pr.source,
                 COALESCE(
                     json_group_array(
                         json_object(
@@ -266,12 +492,55 @@ impl DatabaseRepository {
                             'created_at_unix', c.created_at_unix,
                             'updated_at_unix', c.updated_at_unix,
                             'is_review_comment', c.is_review_comment,
-                            'review_state', c.review_state
+                            'review_state', c.review_state,
+                            'path', c.path,
+                            'line', c.line,
+                            'diff_hunk', c.diff_hunk,
+                            'is_outdated', c.is_outdated,
+                            'mentions_user', c.mentions_user,
+                            'edited_at_unix', c.edited_at_unix,
+                            'is_minimized', c.is_minimized
                         )
                         ORDER BY c.created_at_unix ASC
                     ) FILTER (WHERE c.id IS NOT NULL),
                     '[]'
-                ) as comments_json
+                ) as comments_json,
+                (
+                    SELECT json_group_array(
+                        json_object(
+                            'workflow', k.workflow,
+                            'name', k.name,
+                            'status', k.status,
+                            'conclusion', k.conclusion,
+                            'ci_status', k.ci_status,
+                            'is_required', k.is_required,
+                            'counts_towards_ci', k.counts_towards_ci,
+                            'details_url', k.details_url,
+                            'started_at_unix', k.started_at_unix,
+                            'completed_at_unix', k.completed_at_unix
+                        )
+                        ORDER BY k.workflow ASC, k.name ASC
+                    )
+                    FROM pr_checks k
+                    WHERE k.repository = pr.repository AND k.pr_number = pr.number
+                ) as checks_json,
+                (
+                    SELECT json_group_array(
+                        json_object(
+                            'id', t.id,
+                            'path', t.path,
+                            'line', t.line,
+                            'is_resolved', t.is_resolved,
+                            'is_outdated', t.is_outdated,
+                            'author', t.author,
+                            'body', t.body,
+                            'comment_count', t.comment_count
+                        )
+                        ORDER BY t.path ASC, t.line ASC
+                    )
+                    FROM pr_review_threads t
+                    WHERE t.repository = pr.repository AND t.pr_number = pr.number
+                ) as threads_json
             FROM pull_requests pr
             LEFT JOIN pr_comments c 
                 ON pr.repository = c.repository AND pr.number = c.pr_number
@@ -287,6 +556,43 @@ impl DatabaseRepository {
         row.map(|r| r.into_model()).transpose()
     }
 
+    pub async fn get_user_teams(&self, username: &str) -> anyhow::Result<Option<UserTeams>> {
+        let row = sqlx::query(
+            "SELECT username, teams, fetched_at_unix FROM user_teams WHERE username = ?1",
+        )
+        .bind(username)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| {
+            let teams: String = row.get("teams");
+            Ok(UserTeams {
+                username: row.get("username"),
+                teams: serde_json::from_str(&teams)
+                    .map_err(|err| anyhow::anyhow!("unmarshal user teams: {err}"))?,
+                fetched_at: unix_to_datetime(row.get("fetched_at_unix"))?,
+            })
+        })
+        .transpose()
+    }
+
+    pub async fn save_user_teams(&self, user_teams: &UserTeams) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO user_teams (username, teams, fetched_at_unix) VALUES (?1, ?2, ?3)
+            ON CONFLICT(username) DO UPDATE SET
+                teams = excluded.teams,
+                fetched_at_unix = excluded.fetched_at_unix
+            "#,
+        )
+        .bind(&user_teams.username)
+        .bind(serde_json::to_string(&user_teams.teams)?)
+        .bind(user_teams.fetched_at.timestamp())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
     pub async fn get_tracked_authors(&self) -> anyhow::Result<Vec<String>> {
         let rows = sqlx::query("SELECT author FROM tracked_authors")
             .fetch_all(&self.pool)
@@ -326,39 +632,207 @@ impl DatabaseRepository {
     }
 
     pub async fn get_tracked_repositories(&self) -> anyhow::Result<Vec<TrackedRepository>> {
-        let rows = sqlx::query("SELECT repository, last_synced_at_unix FROM tracked_repositories")
-            .fetch_all(&self.pool)
-            .await?;
-        Ok(rows
-            .into_iter()
+        let rows = sqlx::query(
+            "SELECT repository, last_synced_at_unix, account, ci_policy, node_id, last_sync_error FROM tracked_repositories",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        rows.into_iter()
             .map(|row| {
                 let repository: String = row.get("repository");
                 let last_synced_at_unix: Option<i64> = row.get("last_synced_at_unix");
-                TrackedRepository {
+                let ci_policy: String = row.get("ci_policy");
+                Ok(TrackedRepository {
+                    ci_policy: CiPolicy::from_reference(&ci_policy)
+                        .map_err(|err| anyhow::anyhow!("repository '{repository}': {err}"))?,
                     repository,
                     last_synced_at: last_synced_at_unix
                         .and_then(|ts| DateTime::from_timestamp(ts, 0)),
-                }
+                    account: row.get("account"),
+                    node_id: row.get("node_id"),
+                    last_sync_error: row.get("last_sync_error"),
+                })
             })
-            .collect())
+            .collect()
+    }
+
+    pub async fn save_tracked_repository(
+        &self,
+        repo: &str,
+        account: Option<&str>,
+        node_id: Option<&str>,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT INTO tracked_repositories (repository, account, node_id) VALUES (?1, ?2, ?3)",
+        )
+        .bind(repo)
+        .bind(account)
+        .bind(node_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
     }
 
-    pub async fn save_tracked_repository(&self, repo: &str) -> anyhow::Result<()> {
-        sqlx::query("INSERT INTO tracked_repositories (repository) VALUES (?1)")
+    pub async fn set_tracked_repository_node_id(
+        &self,
+        repo: &str,
+        node_id: &str,
+    ) -> anyhow::Result<()> {
+        sqlx::query("UPDATE tracked_repositories SET node_id = ?1 WHERE repository = ?2")
+            .bind(node_id)
             .bind(repo)
             .execute(&self.pool)
             .await?;
         Ok(())
     }
 
-    pub async fn delete_tracked_repository(&self, repo: &str) -> anyhow::Result<()> {
-        sqlx::query("DELETE FROM tracked_repositories WHERE repository = ?1")
+    /// Move everything stored under `old_name` to `new_name` after GitHub
+    /// renamed or transferred the repository. When `new_name` is already
+    /// tracked the stale entry and its PRs are dropped instead; the next sync
+    /// fetches them under the new name.
+    pub async fn rename_tracked_repository(
+        &self,
+        old_name: &str,
+        new_name: &str,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        let target_tracked =
+            sqlx::query("SELECT 1 FROM tracked_repositories WHERE repository = ?1")
+                .bind(new_name)
+                .fetch_optional(&mut *tx)
+                .await?
+                .is_some();
+
+        if target_tracked {
+            for table in ["pull_requests", "pr_worktrees", "tracked_repositories"] {
+                sqlx::query(&format!("DELETE FROM {table} WHERE repository = ?1"))
+                    .bind(old_name)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+            tx.commit().await?;
+            return Ok(());
+        }
+
+        // Review inbox PRs already stored under the new name are the same
+        // PRs as the renamed ones and would collide with them.
+        for table in ["pull_requests", "pr_worktrees"] {
+            sqlx::query(&format!("DELETE FROM {table} WHERE repository = ?1"))
+                .bind(new_name)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        // Comments, checks and review threads reference their PR by
+        // (repository, number); deferring the check lets parents and
+        // children be renamed one table at a time.
+        sqlx::query("PRAGMA defer_foreign_keys = ON")
+            .execute(&mut *tx)
+            .await?;
+        for table in [
+            "tracked_repositories",
+            "pull_requests",
+            "pr_comments",
+            "pr_checks",
+            "pr_review_threads",
+            "pr_worktrees",
+        ] {
+            sqlx::query(&format!(
+                "UPDATE {table} SET repository = ?1 WHERE repository = ?2"
+            ))
+            .bind(new_name)
+            .bind(old_name)
+            .execute(&mut *tx)
+            .await?;
+        }
+        // A local path set for the new name wins over the old one.
+        sqlx::query(
+            "UPDATE OR IGNORE repository_local_paths SET repository = ?1 WHERE repository = ?2",
+        )
+        .bind(new_name)
+        .bind(old_name)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("DELETE FROM repository_local_paths WHERE repository = ?1")
+            .bind(old_name)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn set_tracked_repository_account(
+        &self,
+        repo: &str,
+        account: Option<&str>,
+    ) -> anyhow::Result<bool> {
+        let result =
+            sqlx::query("UPDATE tracked_repositories SET account = ?1 WHERE repository = ?2")
+                .bind(account)
+                .bind(repo)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn set_tracked_repository_ci_policy(
+        &self,
+        repo: &str,
+        policy: &CiPolicy,
+    ) -> anyhow::Result<bool> {
+        let result =
+            sqlx::query("UPDATE tracked_repositories SET ci_policy = ?1 WHERE repository = ?2")
+                .bind(policy.reference())
+                .bind(repo)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Stop tracking `repo` and delete its stored PRs, whose comments, checks
+    /// and review threads cascade with them. Returns how many PRs were deleted.
+    pub async fn delete_tracked_repository(&self, repo: &str) -> anyhow::Result<u64> {
+        let mut tx = self.pool.begin().await?;
+        let deleted_prs =
+            sqlx::query("DELETE FROM pull_requests WHERE repository = ?1 COLLATE NOCASE")
+                .bind(repo)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+        sqlx::query("DELETE FROM tracked_repositories WHERE repository = ?1 COLLATE NOCASE")
+            .bind(repo)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(deleted_prs)
+    }
+
+    pub async fn set_tracked_repository_sync_error(
+        &self,
+        repo: &str,
+        error: Option<&str>,
+    ) -> anyhow::Result<()> {
+        sqlx::query("UPDATE tracked_repositories SET last_sync_error = ?1 WHERE repository = ?2")
+            .bind(error)
             .bind(repo)
             .execute(&self.pool)
             .await?;
         Ok(())
     }
 
+    pub async fn reset_tracked_repository_last_synced_at(
+        &self,
+        repo: &str,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query(
+            "UPDATE tracked_repositories SET last_synced_at_unix = NULL WHERE repository = ?1",
+        )
+        .bind(repo)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
     pub async fn reset_all_tracked_repositories_last_synced_at(&self) -> anyhow::Result<usize> {
         let result = sqlx::query("UPDATE tracked_repositories SET last_synced_at_unix = NULL")
             .execute(&self.pool)
@@ -366,55 +840,230 @@ impl DatabaseRepository {
         Ok(result.rows_affected() as usize)
     }
 
-    pub async fn update_tracked_repository_last_synced_at(
+    pub async fn get_review_launchers(&self) -> anyhow::Result<Vec<ReviewLauncher>> {
+        let rows = sqlx::query("SELECT name, command, args FROM review_launchers ORDER BY name")
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let args: String = row.get("args");
+                Ok(ReviewLauncher {
+                    name: row.get("name"),
+                    command: row.get("command"),
+                    args: serde_json::from_str(&args)
+                        .map_err(|err| anyhow::anyhow!("unmarshal launcher args: {err}"))?,
+                })
+            })
+            .collect()
+    }
+
+    pub async fn save_review_launcher(&self, launcher: &ReviewLauncher) -> anyhow::Result<()> {
+        let args_json = serde_json::to_string(&launcher.args)?;
+        sqlx::query(
+            r#"
+            INSERT INTO review_launchers (name, command, args) VALUES (?1, ?2, ?3)
+            ON CONFLICT(name) DO UPDATE SET
+              command = excluded.command,
+              args = excluded.args
+            "#,
+        )
+        .bind(&launcher.name)
+        .bind(&launcher.command)
+        .bind(args_json)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete_review_launcher(&self, name: &str) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM review_launchers WHERE name = ?1")
+            .bind(name)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_repository_follow_rules(&self) -> anyhow::Result<Vec<FollowedDiscoveryRule>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, org, topic, team, pattern, exclude_archived, account, created_at_unix,
+                   seen_until_unix
+            FROM repository_follow_rules
+            ORDER BY id
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(FollowedDiscoveryRule {
+                    id: row.get("id"),
+                    rule: RepositoryDiscoveryRule {
+                        org: row.get("org"),
+                        topic: row.get("topic"),
+                        team: row.get("team"),
+                        pattern: row.get("pattern"),
+                        exclude_archived: row.get("exclude_archived"),
+                    },
+                    account: row.get("account"),
+                    created_at: unix_to_datetime(row.get("created_at_unix"))?,
+                    seen_until: unix_to_datetime(row.get("seen_until_unix"))?,
+                })
+            })
+            .collect()
+    }
+
+    /// Follow `rule`: repositories matching it that are created from now on
+    /// get tracked at sync time. Returns the new rule's id.
+    pub async fn save_repository_follow_rule(
+        &self,
+        rule: &RepositoryDiscoveryRule,
+        account: Option<&str>,
+    ) -> anyhow::Result<i64> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO repository_follow_rules (
+              org, topic, team, pattern, exclude_archived, account, created_at_unix,
+              seen_until_unix
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)
+            "#,
+        )
+        .bind(&rule.org)
+        .bind(&rule.topic)
+        .bind(&rule.team)
+        .bind(&rule.pattern)
+        .bind(rule.exclude_archived)
+        .bind(account)
+        .bind(Utc::now().timestamp())
+        .execute(&self.pool)
+        .await?;
+        Ok(result.last_insert_rowid())
+    }
+
+    pub async fn set_repository_follow_rule_seen_until(
+        &self,
+        id: i64,
+        seen_until: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        sqlx::query("UPDATE repository_follow_rules SET seen_until_unix = ?1 WHERE id = ?2")
+            .bind(seen_until.timestamp())
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete_repository_follow_rule(&self, id: i64) -> anyhow::Result<bool> {
+        let result = sqlx::query("DELETE FROM repository_follow_rules WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn get_repository_local_paths(&self) -> anyhow::Result<Vec<RepositoryLocalPath>> {
+        let rows = sqlx::query(
+            "SELECT repository, local_path FROM repository_local_paths ORDER BY repository",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| RepositoryLocalPath {
+                repository: row.get("repository"),
+                local_path: row.get("local_path"),
+            })
+            .collect())
+    }
+
+    pub async fn get_repository_local_path(&self, repo: &str) -> anyhow::Result<Option<String>> {
+        let row = sqlx::query(
+            "SELECT local_path FROM repository_local_paths WHERE repository = ?1 COLLATE NOCASE",
+        )
+        .bind(repo)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(|row| row.get("local_path")))
+    }
+
+    pub async fn save_repository_local_path(
         &self,
         repo: &str,
-        last_synced_at: DateTime<Utc>,
+        local_path: &str,
     ) -> anyhow::Result<()> {
         sqlx::query(
-            "UPDATE tracked_repositories SET last_synced_at_unix = ?1 WHERE repository = ?2",
+            r#"
+            INSERT INTO repository_local_paths (repository, local_path) VALUES (?1, ?2)
+            ON CONFLICT(repository) DO UPDATE SET local_path = excluded.local_path
+            "#,
         )
-        .bind(last_synced_at.timestamp())
         .bind(repo)
+        .bind(local_path)
         .execute(&self.pool)
         .await?;
         Ok(())
     }
 
-    pub async fn save_comment(&self, comment: &PrComment) -> anyhow::Result<()> {
+    pub async fn delete_repository_local_path(&self, repo: &str) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM repository_local_paths WHERE repository = ?1 COLLATE NOCASE")
+            .bind(repo)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_pr_worktrees(&self) -> anyhow::Result<Vec<PrWorktree>> {
+        let rows = sqlx::query(
+            "SELECT repository, pr_number, path, head_sha FROM pr_worktrees ORDER BY repository, pr_number",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(pr_worktree_from_row).collect())
+    }
+
+    pub async fn get_pr_worktrees_for_repository(
+        &self,
+        repo: &str,
+    ) -> anyhow::Result<Vec<PrWorktree>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT repository, pr_number, path, head_sha FROM pr_worktrees
+            WHERE repository = ?1 COLLATE NOCASE
+            ORDER BY pr_number
+            "#,
+        )
+        .bind(repo)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(pr_worktree_from_row).collect())
+    }
+
+    pub async fn save_pr_worktree(&self, worktree: &PrWorktree) -> anyhow::Result<()> {
         sqlx::query(
             r#"
-            INSERT INTO pr_comments (
-                id, repository, pr_number, author, body, created_at_unix,
-                updated_at_unix, is_review_comment, review_state
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
-            ON CONFLICT(id) DO UPDATE SET
-                repository = excluded.repository,
-                pr_number = excluded.pr_number,
-                author = excluded.author,
-                body = excluded.body,
-                created_at_unix = excluded.created_at_unix,
-                updated_at_unix = excluded.updated_at_unix,
-                is_review_comment = excluded.is_review_comment,
-                review_state = excluded.review_state
+            INSERT INTO pr_worktrees (repository, pr_number, path, head_sha) VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT(repository, pr_number) DO UPDATE SET
+              path = excluded.path,
+              head_sha = excluded.head_sha
             "#,
         )
-        .bind(&comment.id)
-        .bind(&comment.repository)
-        .bind(comment.pr_number)
-        .bind(&comment.author)
-        .bind(&comment.body)
-        .bind(comment.created_at.timestamp())
-        .bind(comment.updated_at.timestamp())
-        .bind(if comment.is_review_comment {
-            1i64
-        } else {
-            0i64
-        })
-        .bind(&comment.review_state)
+        .bind(&worktree.repository)
+        .bind(worktree.pr_number)
+        .bind(&worktree.path)
+        .bind(&worktree.head_sha)
         .execute(&self.pool)
         .await?;
+        Ok(())
+    }
 
+    pub async fn delete_pr_worktree(&self, repo: &str, pr_number: i64) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM pr_worktrees WHERE repository = ?1 AND pr_number = ?2")
+            .bind(repo)
+            .bind(pr_number)
+            .execute(&self.pool)
+            .await?;
         Ok(())
     }
 
@@ -426,7 +1075,8 @@ impl DatabaseRepository {
         let rows = sqlx::query_as::<_, PrCommentRow>(
             r#"
             SELECT id, repository, pr_number, author, body, created_at_unix,
-                   updated_at_unix, is_review_comment, review_state
+                   updated_at_unix, is_review_comment, review_state, path, line,
+                   diff_hunk, is_outdated, mentions_user, edited_at_unix, is_minimized
             FROM pr_comments
             WHERE repository = ?1 AND pr_number = ?2
             ORDER BY created_at_unix ASC
@@ -452,6 +1102,455 @@ impl DatabaseRepository {
             .await?;
         Ok(())
     }
+
+    /// Record that a sync run started and return its id. Only the newest
+    /// `MAX_SYNC_RUNS` runs are kept.
+    pub async fn start_sync_run(
+        &self,
+        trigger: SyncTrigger,
+        scope: &str,
+        dry_run: bool,
+        started_at: DateTime<Utc>,
+    ) -> anyhow::Result<i64> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO sync_runs (trigger, scope, dry_run, started_at_unix)
+            VALUES (?1, ?2, ?3, ?4)
+            "#,
+        )
+        .bind(trigger.as_str())
+        .bind(scope)
+        .bind(dry_run)
+        .bind(started_at.timestamp())
+        .execute(&self.pool)
+        .await?;
+        let id = result.last_insert_rowid();
+
+        sqlx::query("DELETE FROM sync_runs WHERE id <= ?1")
+            .bind(id - MAX_SYNC_RUNS)
+            .execute(&self.pool)
+            .await?;
+        Ok(id)
+    }
+
+    /// Store how the run `run.id` ended, along with its repositories.
+    pub async fn finish_sync_run(&self, run: &SyncRun) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"
+            UPDATE sync_runs SET
+              finished_at_unix = ?1,
+              new_prs = ?2,
+              updated_data_prs = ?3,
+              updated_attention_prs = ?4,
+              deleted_prs = ?5,
+              graphql_requests = ?6,
+              graphql_cost = ?7,
+              error = ?8,
+              log = ?9,
+              rate_limit_remaining = ?10,
+              rate_limit_reset_at_unix = ?11
+            WHERE id = ?12
+            "#,
+        )
+        .bind(run.finished_at.map(|t| t.timestamp()))
+        .bind(run.new_prs)
+        .bind(run.updated_data_prs)
+        .bind(run.updated_attention_prs)
+        .bind(run.deleted_prs)
+        .bind(run.graphql_requests)
+        .bind(run.graphql_cost)
+        .bind(&run.error)
+        .bind(run.log.join("\n"))
+        .bind(run.rate_limit_remaining)
+        .bind(run.rate_limit_reset_at.map(|t| t.timestamp()))
+        .bind(run.id)
+        .execute(&mut *tx)
+        .await?;
+
+        for repository in &run.repositories {
+            sqlx::query(
+                r#"
+                INSERT OR REPLACE INTO sync_run_repositories (
+                  run_id, repository, duration_ms, new_prs, updated_prs, deleted_prs, error
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                "#,
+            )
+            .bind(run.id)
+            .bind(&repository.repository)
+            .bind(repository.duration_ms)
+            .bind(repository.new_prs)
+            .bind(repository.updated_prs)
+            .bind(repository.deleted_prs)
+            .bind(&repository.error)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// The newest `limit` sync runs, newest first.
+    pub async fn get_sync_runs(&self, limit: usize) -> anyhow::Result<Vec<SyncRun>> {
+        self.query_sync_runs(None, limit).await
+    }
+
+    /// The sync run `id`, if it is still kept.
+    pub async fn get_sync_run(&self, id: i64) -> anyhow::Result<Option<SyncRun>> {
+        Ok(self.query_sync_runs(Some(id), 1).await?.into_iter().next())
+    }
+
+    async fn query_sync_runs(&self, id: Option<i64>, limit: usize) -> anyhow::Result<Vec<SyncRun>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, trigger, scope, dry_run, started_at_unix, finished_at_unix, new_prs,
+                   updated_data_prs, updated_attention_prs, deleted_prs, graphql_requests,
+                   graphql_cost, rate_limit_remaining, rate_limit_reset_at_unix, error, log
+            FROM sync_runs
+            WHERE ?1 IS NULL OR id = ?1
+            ORDER BY id DESC
+            LIMIT ?2
+            "#,
+        )
+        .bind(id)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let repository_rows = sqlx::query(
+            r#"
+            SELECT run_id, repository, duration_ms, new_prs, updated_prs, deleted_prs, error
+            FROM sync_run_repositories
+            WHERE run_id IN (
+              SELECT id FROM sync_runs WHERE ?1 IS NULL OR id = ?1 ORDER BY id DESC LIMIT ?2
+            )
+            ORDER BY repository
+            "#,
+        )
+        .bind(id)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?;
+        let mut repositories_by_run: HashMap<i64, Vec<SyncRunRepository>> = HashMap::new();
+        for row in repository_rows {
+            repositories_by_run
+                .entry(row.get("run_id"))
+                .or_default()
+                .push(SyncRunRepository {
+                    repository: row.get("repository"),
+                    duration_ms: row.get("duration_ms"),
+                    new_prs: row.get("new_prs"),
+                    updated_prs: row.get("updated_prs"),
+                    deleted_prs: row.get("deleted_prs"),
+                    error: row.get("error"),
+                });
+        }
+
+        rows.into_iter()
+            .map(|row| {
+                let id: i64 = row.get("id");
+                let log: String = row.get("log");
+                Ok(SyncRun {
+                    id,
+                    trigger: SyncTrigger::parse(row.get("trigger")),
+                    scope: row.get("scope"),
+                    dry_run: row.get("dry_run"),
+                    started_at: unix_to_datetime(row.get("started_at_unix"))?,
+                    finished_at: row
+                        .get::<Option<i64>, _>("finished_at_unix")
+                        .map(unix_to_datetime)
+                        .transpose()?,
+                    new_prs: row.get("new_prs"),
+                    updated_data_prs: row.get("updated_data_prs"),
+                    updated_attention_prs: row.get("updated_attention_prs"),
+                    deleted_prs: row.get("deleted_prs"),
+                    graphql_requests: row.get("graphql_requests"),
+                    graphql_cost: row.get("graphql_cost"),
+                    rate_limit_remaining: row.get("rate_limit_remaining"),
+                    rate_limit_reset_at: row
+                        .get::<Option<i64>, _>("rate_limit_reset_at_unix")
+                        .map(unix_to_datetime)
+                        .transpose()?,
+                    error: row.get("error"),
+                    log: log.lines().map(str::to_string).collect(),
+                    repositories: repositories_by_run.remove(&id).unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// SQLite's default limit on bound parameters per statement; multi-row
+/// inserts are chunked to stay below it.
+const SQLITE_MAX_BINDS: usize = 32_766;
+
+async fn upsert_pull_requests(
+    conn: &mut SqliteConnection,
+    prs: &[&PullRequest],
+) -> anyhow::Result<()> {
+    const COLUMNS: usize = 22;
+    for chunk in prs.chunks(SQLITE_MAX_BINDS / COLUMNS) {
+        let mut encoded = Vec::with_capacity(chunk.len());
+        for pr in chunk {
+            encoded.push((
+                *pr,
+                serde_json::to_string(&pr.requested_reviewers)?,
+                serde_json::to_string(&pr.requested_teams)?,
+            ));
+        }
+        let mut query = QueryBuilder::new(
+            r#"
+            INSERT INTO pull_requests (
+              number, title, repository, author, head_sha, draft, created_at_unix,
+              updated_at_unix, ci_status, last_comment_unix, last_commit_unix,
+              last_ci_status_update_unix, last_acknowledged_unix, requested_reviewers,
+              approval_status, last_review_status_update_unix, user_has_reviewed,
+              last_thread_update_unix, comments_truncated, requested_teams,
+              team_review_requested, source
+            ) "#,
+        );
+        query.push_values(encoded, |mut row, (pr, reviewers_json, teams_json)| {
+            row.push_bind(pr.number)
+                .push_bind(&pr.title)
+                .push_bind(&pr.repository)
+                .push_bind(&pr.author)
+                .push_bind(&pr.head_sha)
+                .push_bind(pr.draft)
+                .push_bind(pr.created_at.timestamp())
+                .push_bind(pr.updated_at.timestamp())
+                .push_bind(pr.ci_status.as_str())
+                .push_bind(pr.last_comment_at.timestamp())
+                .push_bind(pr.last_commit_at.timestamp())
+                .push_bind(pr.last_ci_status_update_at.timestamp())
+                .push_bind(pr.last_acknowledged_at.map(|t| t.timestamp()))
+                .push_bind(reviewers_json)
+                .push_bind(pr.approval_status.as_i64())
+                .push_bind(pr.last_review_status_update_at.timestamp())
+                .push_bind(pr.user_has_reviewed)
+                .push_bind(pr.last_thread_update_at.timestamp())
+                .push_bind(pr.comments_truncated)
+                .push_bind(teams_json)
+                .push_bind(pr.team_review_requested)
+                .push_bind(pr.source.as_str());
+        });
+        query.push(
+            r#"
+            ON CONFLICT(repository, number) DO UPDATE SET
+              title = excluded.title,
+              repository = excluded.repository,
+              author = excluded.author,
+              head_sha = excluded.head_sha,
+              draft = excluded.draft,
+              updated_at_unix = excluded.updated_at_unix,
+              ci_status = excluded.ci_status,
+              last_comment_unix = excluded.last_comment_unix,
+              last_commit_unix = excluded.last_commit_unix,
+              last_ci_status_update_unix = excluded.last_ci_status_update_unix,
+              last_acknowledged_unix = excluded.last_acknowledged_unix,
+              requested_reviewers = excluded.requested_reviewers,
+              approval_status = excluded.approval_status,
+              last_review_status_update_unix = excluded.last_review_status_update_unix,
+              user_has_reviewed = excluded.user_has_reviewed,
+              last_thread_update_unix = excluded.last_thread_update_unix,
+              comments_truncated = excluded.comments_truncated,
+              requested_teams = excluded.requested_teams,
+              team_review_requested = excluded.team_review_requested,
+              source = excluded.source
+            "#,
+        );
+        query.build().execute(&mut *conn).await?;
+    }
+    Ok(())
+}
+
+/// Replace the stored checks and review threads of `prs` with the ones they
+/// carry.
+async fn replace_checks_and_review_threads(
+    conn: &mut SqliteConnection,
+    prs: &[&PullRequest],
+) -> anyhow::Result<()> {
+    for pr in prs {
+        sqlx::query("DELETE FROM pr_checks WHERE repository = ?1 AND pr_number = ?2")
+            .bind(&pr.repository)
+            .bind(pr.number)
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query("DELETE FROM pr_review_threads WHERE repository = ?1 AND pr_number = ?2")
+            .bind(&pr.repository)
+            .bind(pr.number)
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    const CHECK_COLUMNS: usize = 12;
+    let checks: Vec<_> = prs
+        .iter()
+        .flat_map(|pr| pr.checks.iter().map(move |check| (*pr, check)))
+        .collect();
+    for chunk in checks.chunks(SQLITE_MAX_BINDS / CHECK_COLUMNS) {
+        let mut query = QueryBuilder::new(
+            r#"
+            INSERT INTO pr_checks (
+                repository, pr_number, workflow, name, status, conclusion,
+                ci_status, is_required, counts_towards_ci, details_url, started_at_unix,
+                completed_at_unix
+            ) "#,
+        );
+        query.push_values(chunk, |mut row, (pr, check)| {
+            row.push_bind(&pr.repository)
+                .push_bind(pr.number)
+                .push_bind(&check.workflow)
+                .push_bind(&check.name)
+                .push_bind(&check.status)
+                .push_bind(&check.conclusion)
+                .push_bind(check.ci_status.as_str())
+                .push_bind(check.is_required)
+                .push_bind(check.counts_towards_ci)
+                .push_bind(&check.details_url)
+                .push_bind(check.started_at.map(|t| t.timestamp()))
+                .push_bind(check.completed_at.map(|t| t.timestamp()));
+        });
+        query.push(
+            r#"
+            ON CONFLICT(repository, pr_number, workflow, name) DO UPDATE SET
+                status = excluded.status,
+                conclusion = excluded.conclusion,
+                ci_status = excluded.ci_status,
+                is_required = excluded.is_required,
+                counts_towards_ci = excluded.counts_towards_ci,
+                details_url = excluded.details_url,
+                started_at_unix = excluded.started_at_unix,
+                completed_at_unix = excluded.completed_at_unix
+            "#,
+        );
+        query.build().execute(&mut *conn).await?;
+    }
+
+    const THREAD_COLUMNS: usize = 10;
+    let threads: Vec<_> = prs
+        .iter()
+        .flat_map(|pr| pr.review_threads.iter().map(move |thread| (*pr, thread)))
+        .collect();
+    for chunk in threads.chunks(SQLITE_MAX_BINDS / THREAD_COLUMNS) {
+        let mut query = QueryBuilder::new(
+            r#"
+            INSERT INTO pr_review_threads (
+                repository, pr_number, id, path, line, is_resolved, is_outdated,
+                author, body, comment_count
+            ) "#,
+        );
+        query.push_values(chunk, |mut row, (pr, thread)| {
+            row.push_bind(&pr.repository)
+                .push_bind(pr.number)
+                .push_bind(&thread.id)
+                .push_bind(&thread.path)
+                .push_bind(thread.line)
+                .push_bind(thread.is_resolved)
+                .push_bind(thread.is_outdated)
+                .push_bind(&thread.author)
+                .push_bind(&thread.body)
+                .push_bind(thread.comment_count);
+        });
+        query.push(
+            r#"
+            ON CONFLICT(repository, pr_number, id) DO UPDATE SET
+                path = excluded.path,
+                line = excluded.line,
+                is_resolved = excluded.is_resolved,
+                is_outdated = excluded.is_outdated,
+                author = excluded.author,
+                body = excluded.body,
+                comment_count = excluded.comment_count
+            "#,
+        );
+        query.build().execute(&mut *conn).await?;
+    }
+    Ok(())
+}
+
+async fn upsert_comments(
+    conn: &mut SqliteConnection,
+    comments: &[PrComment],
+) -> anyhow::Result<()> {
+    const COLUMNS: usize = 16;
+    for chunk in comments.chunks(SQLITE_MAX_BINDS / COLUMNS) {
+        let mut query = QueryBuilder::new(
+            r#"
+            INSERT INTO pr_comments (
+                id, repository, pr_number, author, body, created_at_unix,
+                updated_at_unix, is_review_comment, review_state, path, line,
+                diff_hunk, is_outdated, mentions_user, edited_at_unix, is_minimized
+            ) "#,
+        );
+        query.push_values(chunk, |mut row, comment| {
+            row.push_bind(&comment.id)
+                .push_bind(&comment.repository)
+                .push_bind(comment.pr_number)
+                .push_bind(&comment.author)
+                .push_bind(&comment.body)
+                .push_bind(comment.created_at.timestamp())
+                .push_bind(comment.updated_at.timestamp())
+                .push_bind(if comment.is_review_comment {
+                    1i64
+                } else {
+                    0i64
+                })
+                .push_bind(&comment.review_state)
+                .push_bind(&comment.path)
+                .push_bind(comment.line)
+                .push_bind(&comment.diff_hunk)
+                .push_bind(comment.is_outdated)
+                .push_bind(comment.mentions_user)
+                .push_bind(comment.edited_at.map(|t| t.timestamp()))
+                .push_bind(comment.is_minimized);
+        });
+        query.push(
+            r#"
+            ON CONFLICT(id) DO UPDATE SET
+                repository = excluded.repository,
+                pr_number = excluded.pr_number,
+                author = excluded.author,
+                body = excluded.body,
+                created_at_unix = excluded.created_at_unix,
+                updated_at_unix = excluded.updated_at_unix,
+                is_review_comment = excluded.is_review_comment,
+                review_state = excluded.review_state,
+                path = excluded.path,
+                line = excluded.line,
+                diff_hunk = excluded.diff_hunk,
+                is_outdated = excluded.is_outdated,
+                mentions_user = excluded.mentions_user,
+                edited_at_unix = excluded.edited_at_unix,
+                is_minimized = excluded.is_minimized
+            "#,
+        );
+        query.build().execute(&mut *conn).await?;
+    }
+    Ok(())
+}
+
+fn user_from_row(row: sqlx::sqlite::SqliteRow) -> anyhow::Result<User> {
+    let reference: String = row.get("credential_source");
+    Ok(User {
+        username: row.get("username"),
+        credential: CredentialSource::from_reference(&reference, row.get("access_token"))?,
+        is_active: row.get("is_active"),
+        scopes: row
+            .get::<Option<String>, _>("scopes")
+            .map(|scopes| serde_json::from_str(&scopes))
+            .transpose()
+            .map_err(|err| anyhow::anyhow!("unmarshal user scopes: {err}"))?,
+    })
+}
+
+fn pr_worktree_from_row(row: sqlx::sqlite::SqliteRow) -> PrWorktree {
+    PrWorktree {
+        repository: row.get("repository"),
+        pr_number: row.get("pr_number"),
+        path: row.get("path"),
+        head_sha: row.get("head_sha"),
+    }
 }
 
 fn ensure_database_parent_dir(database_path: &str) -> anyhow::Result<()> {
@@ -499,6 +1598,13 @@ struct PrCommentRow {
     updated_at_unix: i64,
     is_review_comment: i64,
     review_state: Option<String>,
+    path: Option<String>,
+    line: Option<i64>,
+    diff_hunk: Option<String>,
+    is_outdated: bool,
+    mentions_user: bool,
+    edited_at_unix: Option<i64>,
+    is_minimized: bool,
 }
 
 impl PrCommentRow {
@@ -513,6 +1619,13 @@ impl PrCommentRow {
             updated_at: unix_to_datetime(self.updated_at_unix)?,
             is_review_comment: self.is_review_comment != 0,
             review_state: self.review_state,
+            path: self.path,
+            line: self.line,
+            diff_hunk: self.diff_hunk,
+            is_outdated: self.is_outdated,
+            mentions_user: self.mentions_user,
+            edited_at: self.edited_at_unix.map(unix_to_datetime).transpose()?,
+            is_minimized: self.is_minimized,
         })
     }
 }
@@ -528,6 +1641,13 @@ struct CommentJson {
     updated_at_unix: Option<i64>,
     is_review_comment: Option<i64>, // stored as 0/1 in JSON
     review_state: Option<String>,
+    path: Option<String>,
+    line: Option<i64>,
+    diff_hunk: Option<String>,
+    is_outdated: Option<i64>,   // stored as 0/1 in JSON
+    mentions_user: Option<i64>, // stored as 0/1 in JSON
+    edited_at_unix: Option<i64>,
+    is_minimized: Option<i64>, // stored as 0/1 in JSON
 }
 
 impl CommentJson {
@@ -571,10 +1691,75 @@ impl CommentJson {
             updated_at: unix_to_datetime(updated_at_unix)?,
             is_review_comment: is_review_comment != 0,
             review_state: self.review_state,
+            path: self.path,
+            line: self.line,
+            diff_hunk: self.diff_hunk,
+            is_outdated: self.is_outdated.unwrap_or(0) != 0,
+            mentions_user: self.mentions_user.unwrap_or(0) != 0,
+            edited_at: self.edited_at_unix.map(unix_to_datetime).transpose()?,
+            is_minimized: self.is_minimized.unwrap_or(0) != 0,
         }))
     }
 }
 
+#[derive(Debug, serde::Deserialize)]
+struct CheckJson {
+    workflow: String,
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+    ci_status: String,
+    is_required: i64, // stored as 0/1 in JSON
+    counts_towards_ci: i64,
+    details_url: Option<String>,
+    started_at_unix: Option<i64>,
+    completed_at_unix: Option<i64>,
+}
+
+impl CheckJson {
+    fn into_model(self) -> anyhow::Result<PrCheck> {
+        Ok(PrCheck {
+            workflow: self.workflow,
+            name: self.name,
+            status: self.status,
+            conclusion: self.conclusion,
+            ci_status: CiStatus::parse(&self.ci_status),
+            is_required: self.is_required != 0,
+            counts_towards_ci: self.counts_towards_ci != 0,
+            details_url: self.details_url,
+            started_at: self.started_at_unix.map(unix_to_datetime).transpose()?,
+            completed_at: self.completed_at_unix.map(unix_to_datetime).transpose()?,
+        })
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ReviewThreadJson {
+    id: String,
+    path: String,
+    line: Option<i64>,
+    is_resolved: i64,
+    is_outdated: i64,
+    author: String,
+    body: String,
+    comment_count: i64,
+}
+
+impl ReviewThreadJson {
+    fn into_model(self) -> PrReviewThread {
+        PrReviewThread {
+            id: self.id,
+            path: self.path,
+            line: self.line,
+            is_resolved: self.is_resolved != 0,
+            is_outdated: self.is_outdated != 0,
+            author: self.author,
+            body: self.body,
+            comment_count: self.comment_count,
+        }
+    }
+}
+
 #[derive(Debug, sqlx::FromRow)]
 struct PullRequestWithCommentsRow {
     number: i64,
@@ -585,7 +1770,7 @@ struct PullRequestWithCommentsRow {
     draft: bool,
     created_at_unix: i64,
     updated_at_unix: i64,
-    ci_status: i64,
+    ci_status: String,
     last_comment_unix: i64,
     last_commit_unix: i64,
     last_ci_status_update_unix: i64,
@@ -594,7 +1779,14 @@ struct PullRequestWithCommentsRow {
     approval_status: i64,
     last_review_status_update_unix: i64,
     user_has_reviewed: bool,
+    last_thread_update_unix: i64,
+    comments_truncated: bool,
+    requested_teams: String,
+    team_review_requested: bool,
+    source: String,
     comments_json: String,
+    checks_json: String,
+    threads_json: String,
 }
 
 impl PullRequestWithCommentsRow {
@@ -602,6 +1794,8 @@ impl PullRequestWithCommentsRow {
         // First, deserialize requested_reviewers (same as PullRequestRow)
         let requested_reviewers: Vec<String> = serde_json::from_str(&self.requested_reviewers)
             .map_err(|err| anyhow::anyhow!("unmarshal requested_reviewers: {err}"))?;
+        let requested_teams: Vec<String> = serde_json::from_str(&self.requested_teams)
+            .map_err(|err| anyhow::anyhow!("unmarshal requested_teams: {err}"))?;
 
         // Deserialize the JSON array of comments
         let comments: Vec<CommentJson> = serde_json::from_str(&self.comments_json)
@@ -616,6 +1810,20 @@ impl PullRequestWithCommentsRow {
             .flatten()
             .collect();
 
+        let checks: Vec<CheckJson> = serde_json::from_str(&self.checks_json)
+            .map_err(|err| anyhow::anyhow!("unmarshal checks_json: {err}"))?;
+        let checks = checks
+            .into_iter()
+            .map(CheckJson::into_model)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let review_threads: Vec<ReviewThreadJson> = serde_json::from_str(&self.threads_json)
+            .map_err(|err| anyhow::anyhow!("unmarshal threads_json: {err}"))?;
+        let review_threads = review_threads
+            .into_iter()
+            .map(ReviewThreadJson::into_model)
+            .collect();
+
         // Build and return the PullRequest (copy the pattern from existing PullRequestRow::into_model)
         Ok(PullRequest {
             number: self.number,
@@ -626,7 +1834,7 @@ impl PullRequestWithCommentsRow {
             draft: self.draft,
             created_at: unix_to_datetime(self.created_at_unix)?,
             updated_at: unix_to_datetime(self.updated_at_unix)?,
-            ci_status: CiStatus::from_i64(self.ci_status),
+            ci_status: CiStatus::parse(&self.ci_status),
             last_comment_at: unix_to_datetime(self.last_comment_unix)?,
             last_commit_at: unix_to_datetime(self.last_commit_unix)?,
             last_ci_status_update_at: unix_to_datetime(self.last_ci_status_update_unix)?,
@@ -639,13 +1847,22 @@ impl PullRequestWithCommentsRow {
             requested_reviewers,
             user_has_reviewed: self.user_has_reviewed,
             comments, // NEW: populated from JSON
+            checks,
+            review_threads,
+            last_thread_update_at: unix_to_datetime(self.last_thread_update_unix)?,
+            comments_truncated: self.comments_truncated,
+            requested_teams,
+            team_review_requested: self.team_review_requested,
+            source: PrSource::parse(&self.source),
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::sqlite_file_path;
+    use super::{sqlite_file_path, DatabaseRepository};
+    use crate::models::{ApprovalStatus, CiStatus, PrCheck, PrSource, PullRequest};
+    use chrono::DateTime;
 
     #[test]
     fn extracts_relative_sqlite_file_path() {
@@ -667,4 +1884,134 @@ mod tests {
     fn ignores_in_memory_database() {
         assert_eq!(sqlite_file_path("sqlite::memory:"), None);
     }
+
+    fn check(workflow: &str, name: &str, ci_status: CiStatus) -> PrCheck {
+        PrCheck {
+            workflow: workflow.to_string(),
+            name: name.to_string(),
+            status: "COMPLETED".to_string(),
+            conclusion: None,
+            ci_status,
+            is_required: true,
+            counts_towards_ci: true,
+            details_url: None,
+            started_at: None,
+            completed_at: None,
+        }
+    }
+
+    async fn test_database(name: &str) -> (DatabaseRepository, std::path::PathBuf) {
+        let path = std::env::temp_dir().join(format!("prt-{name}-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let repository = DatabaseRepository::connect(&path.display().to_string())
+            .await
+            .unwrap();
+        repository.apply_migrations().await.unwrap();
+        repository
+            .save_tracked_repository("owner/repo", None, None)
+            .await
+            .unwrap();
+        (repository, path)
+    }
+
+    fn test_pr(checks: Vec<PrCheck>) -> PullRequest {
+        PullRequest {
+            number: 1,
+            title: "Test PR".to_string(),
+            repository: "owner/repo".to_string(),
+            author: "alice".to_string(),
+            head_sha: "abc123".to_string(),
+            draft: false,
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+            ci_status: CiStatus::Failure,
+            last_comment_at: DateTime::UNIX_EPOCH,
+            last_commit_at: DateTime::UNIX_EPOCH,
+            last_ci_status_update_at: DateTime::UNIX_EPOCH,
+            approval_status: ApprovalStatus::None,
+            last_review_status_update_at: DateTime::UNIX_EPOCH,
+            last_acknowledged_at: None,
+            requested_reviewers: Vec::new(),
+            requested_teams: Vec::new(),
+            user_has_reviewed: false,
+            team_review_requested: false,
+            comments: Vec::new(),
+            checks,
+            review_threads: Vec::new(),
+            last_thread_update_at: DateTime::UNIX_EPOCH,
+            comments_truncated: false,
+            source: PrSource::Tracked,
+        }
+    }
+
+    #[tokio::test]
+    async fn keeps_checks_with_the_same_name_from_different_workflows() {
+        let (repository, path) = test_database("checks").await;
+        let pr = test_pr(vec![
+            check("CI", "build", CiStatus::Failure),
+            check("Release", "build", CiStatus::Success),
+        ]);
+        repository
+            .save_repository_sync("owner/repo", &[&pr], &[], &[], &[], None)
+            .await
+            .unwrap();
+
+        let stored = repository
+            .get_prs_by_repository("owner/repo")
+            .await
+            .unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(stored[0].checks, pr.checks);
+    }
+
+    #[tokio::test]
+    async fn migrations_seed_the_former_review_command() {
+        let (repository, path) = test_database("seeded-launcher").await;
+
+        let launchers = repository.get_review_launchers().await.unwrap();
+        assert_eq!(launchers.len(), 1);
+        assert_eq!(launchers[0].name, "review");
+        assert_eq!(launchers[0].command, "ghostty");
+        assert_eq!(
+            launchers[0].args.last().map(String::as_str),
+            Some("cd ~/code/{repo} && pr_review ~/code/{repo} {number}")
+        );
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[tokio::test]
+    async fn deleting_a_tracked_repository_deletes_its_prs() {
+        let (repository, path) = test_database("delete-repository").await;
+        let pr = test_pr(vec![check("CI", "build", CiStatus::Failure)]);
+        repository
+            .save_repository_sync("owner/repo", &[&pr], &[], &[], &[], None)
+            .await
+            .unwrap();
+
+        let deleted_prs = repository
+            .delete_tracked_repository("Owner/Repo")
+            .await
+            .unwrap();
+
+        let count = |table: &'static str| {
+            let pool = repository.pool.clone();
+            async move {
+                sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) FROM {table}"))
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap()
+            }
+        };
+        let counts = (
+            count("tracked_repositories").await,
+            count("pull_requests").await,
+            count("pr_checks").await,
+        );
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(deleted_prs, 1);
+        assert_eq!(counts, (0, 0, 0));
+    }
 }
@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, USER_AGENT};
+use ring::rand::SystemRandom;
+use ring::signature::{RsaKeyPair, RSA_PKCS1_SHA256};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+/// GitHub rejects app JWTs valid for more than 10 minutes; backdate `iat` to
+/// tolerate clock drift.
+const JWT_BACKDATE_SECS: i64 = 60;
+const JWT_LIFETIME_SECS: i64 = 9 * 60;
+/// Refresh installation tokens this long before GitHub expires them.
+const TOKEN_REFRESH_MARGIN_SECS: i64 = 5 * 60;
+
+/// Mints and caches installation access tokens for a GitHub App.
+pub struct InstallationTokenProvider {
+    http: reqwest::Client,
+    api_base_url: String,
+    app_id: String,
+    installation_id: i64,
+    key: RsaKeyPair,
+    cached: Mutex<Option<InstallationToken>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct InstallationToken {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl InstallationTokenProvider {
+    pub fn new(
+        app_id: impl Into<String>,
+        installation_id: i64,
+        private_key_pem: &str,
+        api_base_url: impl Into<String>,
+    ) -> anyhow::Result<Self> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            ACCEPT,
+            HeaderValue::from_static("application/vnd.github+json"),
+        );
+        headers.insert(USER_AGENT, HeaderValue::from_static("pr-tracker-rust"));
+        headers.insert(
+            "X-GitHub-Api-Version",
+            HeaderValue::from_static("2022-11-28"),
+        );
+
+        Ok(Self {
+            http: reqwest::Client::builder()
+                .default_headers(headers)
+                .build()?,
+            api_base_url: api_base_url.into().trim_end_matches('/').to_string(),
+            app_id: app_id.into(),
+            installation_id,
+            key: parse_private_key(private_key_pem)?,
+            cached: Mutex::new(None),
+        })
+    }
+
+    /// Return a valid installation token, exchanging a fresh JWT when the
+    /// cached token is missing or about to expire.
+    pub async fn token(&self) -> anyhow::Result<String> {
+        Ok(self.installation_token().await?.token)
+    }
+
+    pub async fn installation_token(&self) -> anyhow::Result<InstallationToken> {
+        let mut cached = self.cached.lock().await;
+        if let Some(token) = cached.as_ref() {
+            if !needs_refresh(token.expires_at, Utc::now()) {
+                return Ok(token.clone());
+            }
+        }
+
+        let token = self.fetch_installation_token().await?;
+        *cached = Some(token.clone());
+        Ok(token)
+    }
+
+    async fn fetch_installation_token(&self) -> anyhow::Result<InstallationToken> {
+        let jwt = build_app_jwt(&self.app_id, &self.key, Utc::now())?;
+        let url = format!(
+            "{}/app/installations/{}/access_tokens",
+            self.api_base_url, self.installation_id
+        );
+        let response = self
+            .http
+            .post(&url)
+            .header(AUTHORIZATION, format!("Bearer {jwt}"))
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            anyhow::bail!(
+                "failed to create installation token: status={} body={}",
+                status.as_u16(),
+                body.trim()
+            );
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|err| anyhow::anyhow!("error decoding installation token: {err}"))
+    }
+}
+
+impl std::fmt::Debug for InstallationTokenProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InstallationTokenProvider")
+            .field("app_id", &self.app_id)
+            .field("installation_id", &self.installation_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// How a [`super::GitHubClient`] authenticates its requests.
+#[derive(Debug, Clone)]
+pub enum GitHubAuth {
+    Token(String),
+    App(Arc<InstallationTokenProvider>),
+}
+
+impl From<String> for GitHubAuth {
+    fn from(token: String) -> Self {
+        Self::Token(token)
+    }
+}
+
+impl From<&str> for GitHubAuth {
+    fn from(token: &str) -> Self {
+        Self::Token(token.to_string())
+    }
+}
+
+impl From<InstallationTokenProvider> for GitHubAuth {
+    fn from(provider: InstallationTokenProvider) -> Self {
+        Self::App(Arc::new(provider))
+    }
+}
+
+fn needs_refresh(expires_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    expires_at - Duration::seconds(TOKEN_REFRESH_MARGIN_SECS) <= now
+}
+
+/// Build the RS256-signed JWT GitHub expects when authenticating as an app.
+pub fn build_app_jwt(app_id: &str, key: &RsaKeyPair, now: DateTime<Utc>) -> anyhow::Result<String> {
+    let header = serde_json::json!({ "alg": "RS256", "typ": "JWT" });
+    let claims = serde_json::json!({
+        "iat": now.timestamp() - JWT_BACKDATE_SECS,
+        "exp": now.timestamp() + JWT_LIFETIME_SECS,
+        "iss": app_id,
+    });
+    let signing_input = format!(
+        "{}.{}",
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?),
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(&claims)?)
+    );
+
+    let mut signature = vec![0; key.public().modulus_len()];
+    key.sign(
+        &RSA_PKCS1_SHA256,
+        &SystemRandom::new(),
+        signing_input.as_bytes(),
+        &mut signature,
+    )
+    .map_err(|_| anyhow::anyhow!("failed to sign GitHub App JWT"))?;
+
+    Ok(format!(
+        "{signing_input}.{}",
+        URL_SAFE_NO_PAD.encode(signature)
+    ))
+}
+
+/// Parse a PEM private key as downloaded from the GitHub App settings
+/// (PKCS#1), or converted to PKCS#8.
+pub fn parse_private_key(pem: &str) -> anyhow::Result<RsaKeyPair> {
+    let (label, der) = decode_pem(pem)?;
+    match label.as_str() {
+        "RSA PRIVATE KEY" => RsaKeyPair::from_der(&der),
+        "PRIVATE KEY" => RsaKeyPair::from_pkcs8(&der),
+        other => anyhow::bail!("unsupported private key type '{other}'"),
+    }
+    .map_err(|err| anyhow::anyhow!("invalid GitHub App private key: {err}"))
+}
+
+fn decode_pem(pem: &str) -> anyhow::Result<(String, Vec<u8>)> {
+    let mut lines = pem
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty());
+    let label = lines
+        .next()
+        .and_then(|line| line.strip_prefix("-----BEGIN "))
+        .and_then(|line| line.strip_suffix("-----"))
+        .ok_or_else(|| anyhow::anyhow!("private key is not PEM encoded"))?
+        .to_string();
+
+    let end = format!("-----END {label}-----");
+    let body: String = lines.take_while(|line| *line != end).collect();
+    let der = STANDARD
+        .decode(body)
+        .map_err(|err| anyhow::anyhow!("invalid PEM body: {err}"))?;
+    Ok((label, der))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use ring::signature::{UnparsedPublicKey, RSA_PKCS1_2048_8192_SHA256};
+
+    const TEST_KEY: &str = include_str!("testdata/app_private_key.pem");
+
+    #[test]
+    fn build_app_jwt_signs_claims_with_app_key() {
+        let key = parse_private_key(TEST_KEY).expect("key parses");
+        let now = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+
+        let jwt = build_app_jwt("12345", &key, now).expect("jwt builds");
+
+        let parts: Vec<&str> = jwt.split('.').collect();
+        assert_eq!(parts.len(), 3);
+        let claims: serde_json::Value =
+            serde_json::from_slice(&URL_SAFE_NO_PAD.decode(parts[1]).unwrap()).unwrap();
+        assert_eq!(claims["iss"], "12345");
+        assert_eq!(claims["iat"], now.timestamp() - 60);
+        assert_eq!(claims["exp"], now.timestamp() + 540);
+
+        let public_key = UnparsedPublicKey::new(&RSA_PKCS1_2048_8192_SHA256, key.public().as_ref());
+        public_key
+            .verify(
+                format!("{}.{}", parts[0], parts[1]).as_bytes(),
+                &URL_SAFE_NO_PAD.decode(parts[2]).unwrap(),
+            )
+            .expect("signature verifies");
+    }
+
+    #[test]
+    fn parse_private_key_rejects_non_pem_input() {
+        assert!(parse_private_key("not a key").is_err());
+        assert!(
+            parse_private_key("-----BEGIN CERTIFICATE-----\nAAAA\n-----END CERTIFICATE-----")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn needs_refresh_before_expiry_margin() {
+        let now = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+
+        assert!(!needs_refresh(now + Duration::minutes(30), now));
+        assert!(needs_refresh(now + Duration::minutes(4), now));
+        assert!(needs_refresh(now - Duration::minutes(1), now));
+    }
+}
@@ -0,0 +1,274 @@
+use std::time::{Duration, Instant};
+
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, USER_AGENT};
+use serde::Deserialize;
+
+use super::parse_oauth_scopes;
+
+pub const DEFAULT_OAUTH_BASE_URL: &str = "https://github.com";
+pub const DEFAULT_DEVICE_FLOW_SCOPES: &str = "repo read:org";
+
+const DEVICE_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+/// GitHub asks clients to add 5 seconds to the interval on `slow_down`.
+const SLOW_DOWN_INCREMENT_SECS: u64 = 5;
+
+/// Client for GitHub's OAuth device authorization flow.
+pub struct DeviceFlowClient {
+    http: reqwest::Client,
+    base_url: String,
+    client_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeviceCode {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_uri: String,
+    pub expires_in: u64,
+    pub interval: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceFlowToken {
+    pub access_token: String,
+    pub scopes: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AccessTokenResponse {
+    access_token: Option<String>,
+    scope: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+    interval: Option<u64>,
+}
+
+impl DeviceFlowClient {
+    pub fn new(client_id: impl Into<String>, base_url: impl Into<String>) -> anyhow::Result<Self> {
+        let client_id = client_id.into();
+        if client_id.trim().is_empty() {
+            anyhow::bail!("OAuth client id is required");
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+        headers.insert(USER_AGENT, HeaderValue::from_static("pr-tracker-rust"));
+
+        Ok(Self {
+            http: reqwest::Client::builder()
+                .default_headers(headers)
+                .build()?,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            client_id,
+        })
+    }
+
+    pub async fn request_device_code(&self, scopes: &str) -> anyhow::Result<DeviceCode> {
+        let url = format!("{}/login/device/code", self.base_url);
+        let response = self
+            .http
+            .post(&url)
+            .form(&[("client_id", self.client_id.as_str()), ("scope", scopes)])
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            anyhow::bail!(
+                "device code request failed: status={} body={}",
+                status.as_u16(),
+                body.trim()
+            );
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|err| anyhow::anyhow!("error decoding device code response: {err}"))
+    }
+
+    /// Poll the token endpoint until the user approves the device, the code
+    /// expires, or the request is denied.
+    pub async fn poll_for_token(&self, code: &DeviceCode) -> anyhow::Result<DeviceFlowToken> {
+        let url = format!("{}/login/oauth/access_token", self.base_url);
+        let deadline = Instant::now() + Duration::from_secs(code.expires_in);
+        let mut interval = code.interval;
+
+        loop {
+            if Instant::now() >= deadline {
+                anyhow::bail!("device code expired before it was approved");
+            }
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+
+            let response: AccessTokenResponse = self
+                .http
+                .post(&url)
+                .form(&[
+                    ("client_id", self.client_id.as_str()),
+                    ("device_code", code.device_code.as_str()),
+                    ("grant_type", DEVICE_CODE_GRANT_TYPE),
+                ])
+                .send()
+                .await?
+                .json()
+                .await
+                .map_err(|err| anyhow::anyhow!("error decoding access token response: {err}"))?;
+
+            if let Some(access_token) = response.access_token {
+                return Ok(DeviceFlowToken {
+                    access_token,
+                    scopes: response
+                        .scope
+                        .as_deref()
+                        .map(parse_oauth_scopes)
+                        .unwrap_or_default(),
+                });
+            }
+
+            match response.error.as_deref() {
+                Some("authorization_pending") => {}
+                Some("slow_down") => {
+                    interval = response
+                        .interval
+                        .unwrap_or(interval + SLOW_DOWN_INCREMENT_SECS);
+                }
+                Some("expired_token") => {
+                    anyhow::bail!("device code expired before it was approved")
+                }
+                Some("access_denied") => anyhow::bail!("authorization was denied"),
+                Some(error) => anyhow::bail!(
+                    "device flow failed: {error}{}",
+                    response
+                        .error_description
+                        .map(|description| format!(" ({description})"))
+                        .unwrap_or_default()
+                ),
+                None => {
+                    anyhow::bail!("access token response contained neither a token nor an error")
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    /// Serve one canned JSON response per request, in order, and record the
+    /// request line and body of each.
+    fn stub_server(responses: Vec<&'static str>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind stub server");
+        let base_url = format!("http://{}", listener.local_addr().expect("local addr"));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+
+        std::thread::spawn(move || {
+            for body in responses {
+                let Ok((mut stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).expect("read request");
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).expect("read header");
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap_or(0);
+                        }
+                    }
+                }
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).expect("read body");
+                recorded.lock().expect("lock").push(format!(
+                    "{} {}",
+                    request_line.trim(),
+                    String::from_utf8_lossy(&request_body)
+                ));
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream
+                    .write_all(response.as_bytes())
+                    .expect("write response");
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    fn device_code(interval: u64) -> DeviceCode {
+        DeviceCode {
+            device_code: "dev-123".to_string(),
+            user_code: "ABCD-1234".to_string(),
+            verification_uri: "https://github.com/login/device".to_string(),
+            expires_in: 60,
+            interval,
+        }
+    }
+
+    #[tokio::test]
+    async fn request_device_code_posts_client_id_and_scopes() {
+        let (base_url, requests) = stub_server(vec![
+            r#"{"device_code":"dev-123","user_code":"ABCD-1234","verification_uri":"https://github.com/login/device","expires_in":900,"interval":5}"#,
+        ]);
+        let client = DeviceFlowClient::new("client-abc", base_url).expect("client builds");
+
+        let code = client
+            .request_device_code("repo read:org")
+            .await
+            .expect("device code");
+
+        assert_eq!(code.user_code, "ABCD-1234");
+        assert_eq!(code.interval, 5);
+        let requests = requests.lock().expect("lock");
+        assert!(requests[0].starts_with("POST /login/device/code "));
+        assert!(requests[0].contains("client_id=client-abc"));
+        assert!(requests[0].contains("scope=repo+read%3Aorg"));
+    }
+
+    #[tokio::test]
+    async fn poll_for_token_waits_while_authorization_is_pending() {
+        let (base_url, requests) = stub_server(vec![
+            r#"{"error":"authorization_pending"}"#,
+            r#"{"access_token":"gho_token","token_type":"bearer","scope":"repo,read:org"}"#,
+        ]);
+        let client = DeviceFlowClient::new("client-abc", base_url).expect("client builds");
+
+        let token = client.poll_for_token(&device_code(0)).await.expect("token");
+
+        assert_eq!(
+            token,
+            DeviceFlowToken {
+                access_token: "gho_token".to_string(),
+                scopes: vec!["repo".to_string(), "read:org".to_string()],
+            }
+        );
+        let requests = requests.lock().expect("lock");
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].contains("device_code=dev-123"));
+    }
+
+    #[tokio::test]
+    async fn poll_for_token_stops_when_access_is_denied() {
+        let (base_url, _) = stub_server(vec![r#"{"error":"access_denied"}"#]);
+        let client = DeviceFlowClient::new("client-abc", base_url).expect("client builds");
+
+        let err = client.poll_for_token(&device_code(0)).await.unwrap_err();
+
+        assert!(err.to_string().contains("denied"));
+    }
+}
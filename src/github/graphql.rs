@@ -1,58 +1,38 @@
 use serde::Deserialize;
 use serde_json::Value;
 
-const SEARCH_PULL_REQUEST_FIELDS: &str = r#"
-number
-title
-isDraft
-createdAt
-updatedAt
-state
-headRefOid
-author {
-  login
-}
-reviewRequests(first: 100) {
-  nodes {
-    requestedReviewer {
-      ... on User {
-        login
-      }
-    }
-  }
-}
-comments(last: 100) {
-  nodes {
-    id
-    author { __typename login }
-    body
-    createdAt
-    updatedAt
-  }
-}
-reviews(last: 100) {
-  nodes {
-    id
-    author { __typename login }
-    body
-    createdAt
-    updatedAt
-    state
-    submittedAt
-  }
-}
-latestReviews(first: 100) {
-  nodes {
-    state
-    submittedAt
-    author {
-      login
-    }
-  }
-}
-"#;
-
-fn pull_request_fields_with_required_ci(pr_number_expression: &str) -> String {
+/// Nodes GitHub may return for one query; larger queries are rejected
+/// before they run.
+pub const MAX_QUERY_NODES: usize = 500_000;
+/// Upper bound on PRs fetched by number in one query, however few nodes
+/// they need, so a single slow query doesn't time out.
+pub const MAX_PULL_REQUESTS_PER_QUERY: usize = 50;
+/// Size of the fixed nested connections, and the largest size of the
+/// comment and review connections, which can be paged and so shrunk.
+pub const MAX_CONNECTION_SIZE: usize = 100;
+
+/// Nodes GitHub counts for one PR of `build_pull_requests_by_number_query`:
+/// every connection's `first`/`last` times that of its parents.
+pub fn pull_request_query_nodes(connection_size: usize) -> usize {
+    let fixed = MAX_CONNECTION_SIZE;
+    // reviewRequests, commits(last: 1) and its contexts, latestReviews, and
+    // reviewThreads with their first comment.
+    let unpaged = fixed + 1 + fixed + fixed + fixed + fixed;
+    // comments, and reviews with their inline comments.
+    let paged = connection_size + connection_size + connection_size * fixed;
+    unpaged + paged
+}
+
+/// How many PRs fit in one query when their comment and review connections
+/// ask for `connection_size` nodes, capped at `limit`.
+pub fn pull_requests_per_query(connection_size: usize, limit: usize) -> usize {
+    (MAX_QUERY_NODES / pull_request_query_nodes(connection_size)).clamp(1, limit.max(1))
+}
+
+fn pull_request_fields_with_required_ci(
+    pr_number_expression: &str,
+    connection_size: usize,
+) -> String {
     format!(
         r#"
 number
@@ -71,6 +51,9 @@ reviewRequests(first: 100) {{
       ... on User {{
         login
       }}
+      ... on Team {{
+        combinedSlug
+      }}
     }}
   }}
 }}
@@ -84,13 +67,28 @@ commits(last: 1) {{
             __typename
             ... on CheckRun {{
               name
+              checkSuite {{
+                app {{
+                  slug
+                }}
+                workflowRun {{
+                  workflow {{
+                    name
+                  }}
+                }}
+              }}
               status
               conclusion
+              detailsUrl
+              startedAt
+              completedAt
               isRequired(pullRequestNumber: {pr_number_expression})
             }}
             ... on StatusContext {{
               context
               state
+              targetUrl
+              createdAt
               isRequired(pullRequestNumber: {pr_number_expression})
             }}
           }}
@@ -99,16 +97,26 @@ commits(last: 1) {{
     }}
   }}
 }}
-comments(last: 100) {{
+comments(last: {connection_size}) {{
+  pageInfo {{
+    hasPreviousPage
+    startCursor
+  }}
   nodes {{
     id
     author {{ __typename login }}
     body
     createdAt
     updatedAt
+    isMinimized
+    lastEditedAt
   }}
 }}
-reviews(last: 100) {{
+reviews(last: {connection_size}) {{
+  pageInfo {{
+    hasPreviousPage
+    startCursor
+  }}
   nodes {{
     id
     author {{ __typename login }}
@@ -117,6 +125,22 @@ reviews(last: 100) {{
     updatedAt
     state
     submittedAt
+    lastEditedAt
+    comments(first: 100) {{
+      nodes {{
+        id
+        author {{ __typename login }}
+        body
+        createdAt
+        updatedAt
+        path
+        line
+        diffHunk
+        outdated
+        isMinimized
+        lastEditedAt
+      }}
+    }}
   }}
 }}
 latestReviews(first: 100) {{
@@ -128,22 +152,95 @@ latestReviews(first: 100) {{
     }}
   }}
 }}
+reviewThreads(first: 100) {{
+  nodes {{
+    id
+    isResolved
+    isOutdated
+    path
+    line
+    comments(first: 1) {{
+      totalCount
+      nodes {{
+        author {{ __typename login }}
+        body
+        createdAt
+      }}
+    }}
+  }}
+}}
 "#
     )
 }
 
-pub fn tracked_pull_requests_search_query() -> String {
+/// Older issue comments of one PR, `connection_size` at a time, for paging
+/// past those fetched with it.
+pub fn build_pull_request_comments_page_query(connection_size: usize) -> String {
     format!(
         r#"
-query($query: String!, $cursor: String) {{
-  search(query: $query, type: ISSUE, first: 100, after: $cursor) {{
-    pageInfo {{
-      hasNextPage
-      endCursor
+query($owner: String!, $name: String!, $number: Int!, $cursor: String) {{
+  repository(owner: $owner, name: $name) {{
+    pullRequest(number: $number) {{
+      comments(last: {connection_size}, before: $cursor) {{
+        pageInfo {{
+          hasPreviousPage
+          startCursor
+        }}
+        nodes {{
+          id
+          author {{ __typename login }}
+          body
+          createdAt
+          updatedAt
+          isMinimized
+          lastEditedAt
+        }}
+      }}
     }}
-    nodes {{
-      ... on PullRequest {{
-        {SEARCH_PULL_REQUEST_FIELDS}
+  }}
+}}
+"#
+    )
+}
+
+/// Older reviews of one PR, `connection_size` at a time, for paging past
+/// those fetched with it.
+pub fn build_pull_request_reviews_page_query(connection_size: usize) -> String {
+    format!(
+        r#"
+query($owner: String!, $name: String!, $number: Int!, $cursor: String) {{
+  repository(owner: $owner, name: $name) {{
+    pullRequest(number: $number) {{
+      reviews(last: {connection_size}, before: $cursor) {{
+        pageInfo {{
+          hasPreviousPage
+          startCursor
+        }}
+        nodes {{
+          id
+          author {{ __typename login }}
+          body
+          createdAt
+          updatedAt
+          state
+          submittedAt
+          lastEditedAt
+          comments(first: 100) {{
+            nodes {{
+              id
+              author {{ __typename login }}
+              body
+              createdAt
+              updatedAt
+              path
+              line
+              diffHunk
+              outdated
+              isMinimized
+              lastEditedAt
+            }}
+          }}
+        }}
       }}
     }}
   }}
@@ -152,6 +249,39 @@ query($query: String!, $cursor: String) {{
     )
 }
 
+/// `query` with `rateLimit` added to its top-level selection, so the points
+/// each request costs and the points left can be recorded.
+pub fn with_rate_limit(query: &str) -> String {
+    match query.find('{') {
+        Some(index) => format!(
+            "{}{{\n  rateLimit {{ cost remaining resetAt }}{}",
+            &query[..index],
+            &query[index + 1..]
+        ),
+        None => query.to_string(),
+    }
+}
+
+/// Locates the tracked PRs updated since the last sync, open or not; the
+/// open ones are fetched by number afterwards.
+pub const TRACKED_PULL_REQUESTS_SEARCH_QUERY: &str = r#"
+query($query: String!, $cursor: String) {
+  search(query: $query, type: ISSUE, first: 100, after: $cursor) {
+    pageInfo {
+      hasNextPage
+      endCursor
+    }
+    nodes {
+      ... on PullRequest {
+        number
+        state
+        updatedAt
+      }
+    }
+  }
+}
+"#;
+
 #[derive(Debug, Deserialize)]
 pub struct TrackedPullRequestSearchResponse {
     pub search: TrackedPullRequestSearchResult,
@@ -162,7 +292,100 @@ pub struct TrackedPullRequestSearchResult {
     #[serde(rename = "pageInfo")]
     pub page_info: PageInfo,
     #[serde(default)]
-    pub nodes: Vec<PullRequestNode>,
+    pub nodes: Vec<SearchPullRequestNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchPullRequestNode {
+    pub number: i64,
+    pub state: String,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
+}
+
+/// Search qualifiers whose open PRs make up the review inbox.
+pub const REVIEW_INBOX_FILTERS: [&str; 3] =
+    ["review-requested:@me", "involves:@me", "mentions:@me"];
+
+/// Locates the PRs matching a review inbox search; their details are fetched
+/// by number afterwards.
+pub const REVIEW_INBOX_SEARCH_QUERY: &str = r#"
+query($query: String!, $cursor: String) {
+  search(query: $query, type: ISSUE, first: 100, after: $cursor) {
+    pageInfo {
+      hasNextPage
+      endCursor
+    }
+    nodes {
+      ... on PullRequest {
+        number
+        repository {
+          nameWithOwner
+        }
+      }
+    }
+  }
+}
+"#;
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewInboxSearchResponse {
+    pub search: ReviewInboxSearchResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewInboxSearchResult {
+    #[serde(rename = "pageInfo")]
+    pub page_info: PageInfo,
+    #[serde(default)]
+    pub nodes: Vec<ReviewInboxNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewInboxNode {
+    pub number: i64,
+    pub repository: RepositoryRef,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RepositoryRef {
+    #[serde(rename = "nameWithOwner")]
+    pub name_with_owner: String,
+}
+
+/// Current names of repositories looked up by node id, which keeps
+/// resolving after a rename or transfer.
+pub const REPOSITORY_NODES_QUERY: &str = r#"
+query($ids: [ID!]!) {
+  nodes(ids: $ids) {
+    ... on Repository {
+      id
+      nameWithOwner
+    }
+  }
+}
+"#;
+
+#[derive(Debug, Deserialize)]
+pub struct RepositoryNodesResponse {
+    /// `null` for ids that no longer resolve or are not accessible.
+    pub nodes: Vec<Option<RepositoryNode>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RepositoryNode {
+    pub id: String,
+    #[serde(rename = "nameWithOwner")]
+    pub name_with_owner: String,
+}
+
+/// What GitHub reports about the rate limit along with each response.
+#[derive(Debug, Deserialize)]
+pub struct RateLimit {
+    pub cost: u64,
+    pub remaining: u64,
+    #[serde(rename = "resetAt")]
+    pub reset_at: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -173,6 +396,15 @@ pub struct PageInfo {
     pub end_cursor: Option<String>,
 }
 
+/// Page info of a connection fetched with `last`/`before`, i.e. newest first.
+#[derive(Debug, Default, Deserialize)]
+pub struct BackwardPageInfo {
+    #[serde(rename = "hasPreviousPage")]
+    pub has_previous_page: bool,
+    #[serde(rename = "startCursor")]
+    pub start_cursor: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct PullRequestNode {
     pub number: i64,
@@ -195,6 +427,11 @@ pub struct PullRequestNode {
     pub reviews: ReviewConnection,
     #[serde(rename = "latestReviews")]
     pub latest_reviews: LatestReviewConnection,
+    #[serde(rename = "reviewThreads", default)]
+    pub review_threads: ReviewThreadConnection,
+    /// Set when older comments or reviews were left unfetched.
+    #[serde(skip)]
+    pub comments_truncated: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -219,6 +456,9 @@ pub struct ReviewRequestNode {
 #[derive(Debug, Deserialize)]
 pub struct RequestedReviewer {
     pub login: Option<String>,
+    /// `org/slug` when a team rather than a user was requested.
+    #[serde(rename = "combinedSlug", default)]
+    pub combined_slug: Option<String>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -256,21 +496,69 @@ pub struct StatusCheckRollupContextConnection {
 pub enum StatusCheckRollupContext {
     CheckRun {
         name: String,
+        #[serde(rename = "checkSuite", default)]
+        check_suite: Option<CheckSuiteNode>,
         status: String,
         conclusion: Option<String>,
         #[serde(rename = "isRequired")]
         is_required: bool,
+        #[serde(rename = "detailsUrl", default)]
+        details_url: Option<String>,
+        #[serde(rename = "startedAt", default)]
+        started_at: Option<String>,
+        #[serde(rename = "completedAt", default)]
+        completed_at: Option<String>,
     },
     StatusContext {
         context: String,
         state: String,
+        #[serde(rename = "targetUrl", default)]
+        target_url: Option<String>,
+        #[serde(rename = "createdAt", default)]
+        created_at: Option<String>,
         #[serde(rename = "isRequired")]
         is_required: bool,
     },
 }
 
 #[derive(Debug, Deserialize)]
+pub struct CheckSuiteNode {
+    #[serde(default)]
+    pub app: Option<CheckSuiteApp>,
+    #[serde(rename = "workflowRun", default)]
+    pub workflow_run: Option<CheckSuiteWorkflowRun>,
+}
+
+impl CheckSuiteNode {
+    /// The workflow that ran the suite, or the app's slug for suites created
+    /// outside GitHub Actions.
+    pub fn workflow_name(&self) -> Option<&str> {
+        self.workflow_run
+            .as_ref()
+            .map(|run| run.workflow.name.as_str())
+            .or_else(|| self.app.as_ref().map(|app| app.slug.as_str()))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckSuiteApp {
+    pub slug: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckSuiteWorkflowRun {
+    pub workflow: CheckSuiteWorkflow,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckSuiteWorkflow {
+    pub name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
 pub struct CommentConnection {
+    #[serde(rename = "pageInfo", default)]
+    pub page_info: BackwardPageInfo,
     #[serde(default)]
     pub nodes: Vec<CommentNode>,
 }
@@ -284,10 +572,16 @@ pub struct CommentNode {
     pub created_at: String,
     #[serde(rename = "updatedAt")]
     pub updated_at: String,
+    #[serde(rename = "isMinimized", default)]
+    pub is_minimized: bool,
+    #[serde(rename = "lastEditedAt", default)]
+    pub last_edited_at: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 pub struct ReviewConnection {
+    #[serde(rename = "pageInfo", default)]
+    pub page_info: BackwardPageInfo,
     #[serde(default)]
     pub nodes: Vec<ReviewNode>,
 }
@@ -304,6 +598,37 @@ pub struct ReviewNode {
     pub state: String,
     #[serde(rename = "submittedAt")]
     pub submitted_at: Option<String>,
+    #[serde(rename = "lastEditedAt", default)]
+    pub last_edited_at: Option<String>,
+    #[serde(default)]
+    pub comments: ReviewCommentConnection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ReviewCommentConnection {
+    #[serde(default)]
+    pub nodes: Vec<ReviewCommentNode>,
+}
+
+/// An inline comment left on the diff as part of a review.
+#[derive(Debug, Deserialize)]
+pub struct ReviewCommentNode {
+    pub id: String,
+    pub author: Option<Author>,
+    pub body: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: String,
+    pub path: String,
+    pub line: Option<i64>,
+    #[serde(rename = "diffHunk")]
+    pub diff_hunk: String,
+    pub outdated: bool,
+    #[serde(rename = "isMinimized", default)]
+    pub is_minimized: bool,
+    #[serde(rename = "lastEditedAt", default)]
+    pub last_edited_at: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -320,6 +645,40 @@ pub struct LatestReviewNode {
     pub author: Option<Author>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct ReviewThreadConnection {
+    #[serde(default)]
+    pub nodes: Vec<ReviewThreadNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewThreadNode {
+    pub id: String,
+    #[serde(rename = "isResolved")]
+    pub is_resolved: bool,
+    #[serde(rename = "isOutdated")]
+    pub is_outdated: bool,
+    pub path: String,
+    pub line: Option<i64>,
+    pub comments: ReviewThreadCommentConnection,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewThreadCommentConnection {
+    #[serde(rename = "totalCount")]
+    pub total_count: i64,
+    #[serde(default)]
+    pub nodes: Vec<ReviewThreadCommentNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewThreadCommentNode {
+    pub author: Option<Author>,
+    pub body: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct PullRequestsByNumberResponse {
     pub repository: PullRequestsByNumberRepository,
@@ -331,6 +690,27 @@ pub struct PullRequestsByNumberRepository {
     pub pull_requests: serde_json::Map<String, Value>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct PullRequestPageResponse<T> {
+    pub repository: PullRequestPageRepository<T>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PullRequestPageRepository<T> {
+    #[serde(rename = "pullRequest")]
+    pub pull_request: Option<T>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommentsPage {
+    pub comments: CommentConnection,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewsPage {
+    pub reviews: ReviewConnection,
+}
+
 pub fn build_tracked_pull_requests_search_query(
     repo_name: &str,
     authors: &[String],
@@ -348,11 +728,17 @@ pub fn build_tracked_pull_requests_search_query(
     terms.join(" ")
 }
 
-pub fn build_pull_requests_by_number_query(pr_numbers: &[i64]) -> String {
+pub fn build_review_inbox_search_query(filter: &str) -> String {
+    format!("is:pr is:open archived:false {filter} sort:updated-desc")
+}
+
+/// One query for `pr_numbers`, asking for the newest `connection_size`
+/// comments and reviews of each.
+pub fn build_pull_requests_by_number_query(pr_numbers: &[i64], connection_size: usize) -> String {
     let selections = pr_numbers
         .iter()
         .map(|number| {
-            let fields = pull_request_fields_with_required_ci(&number.to_string());
+            let fields = pull_request_fields_with_required_ci(&number.to_string(), connection_size);
             format!("    pr_{number}: pullRequest(number: {number}) {{\n      {fields}\n    }}")
         })
         .collect::<Vec<_>>()
@@ -405,16 +791,29 @@ mod tests {
     }
 
     #[test]
-    fn tracked_pull_requests_search_query_includes_ci_fields() {
-        let query = tracked_pull_requests_search_query();
+    fn build_review_inbox_search_query_only_matches_open_prs() {
+        let query = build_review_inbox_search_query("review-requested:@me");
 
+        assert!(query.contains("is:pr"));
+        assert!(query.contains("is:open"));
+        assert!(query.contains("review-requested:@me"));
+        assert!(!query.contains("repo:"));
+    }
+
+    #[test]
+    fn tracked_pull_requests_search_query_only_locates_prs() {
+        let query = TRACKED_PULL_REQUESTS_SEARCH_QUERY;
+
+        assert!(query.contains("updatedAt"));
+        assert!(query.contains("state"));
         assert!(!query.contains("statusCheckRollup"));
-        assert!(query.contains("latestReviews(first: 100)"));
+        assert!(!query.contains("comments"));
+        assert!(!query.contains("reviews"));
     }
 
     #[test]
     fn build_pull_requests_by_number_query_uses_aliases() {
-        let query = build_pull_requests_by_number_query(&[42, 99]);
+        let query = build_pull_requests_by_number_query(&[42, 99], 100);
 
         assert!(query.contains("query($owner: String!, $name: String!)"));
         assert!(query.contains("pr_42: pullRequest(number: 42)"));
@@ -422,5 +821,116 @@ mod tests {
         assert!(query.contains("statusCheckRollup"));
         assert!(query.contains("isRequired(pullRequestNumber: 42)"));
         assert!(query.contains("isRequired(pullRequestNumber: 99)"));
+        assert!(query.contains("isResolved"));
+        assert!(query.contains("hasPreviousPage"));
+    }
+
+    #[test]
+    fn build_pull_requests_by_number_query_sizes_comments_and_reviews() {
+        let query = build_pull_requests_by_number_query(&[42], 25);
+
+        assert!(query.contains("comments(last: 25)"));
+        assert!(query.contains("reviews(last: 25)"));
+        assert!(query.contains("reviewThreads(first: 100)"));
+    }
+
+    #[test]
+    fn history_page_queries_use_the_connection_size() {
+        assert!(build_pull_request_comments_page_query(25)
+            .contains("comments(last: 25, before: $cursor)"));
+        assert!(build_pull_request_reviews_page_query(25)
+            .contains("reviews(last: 25, before: $cursor)"));
+    }
+
+    #[test]
+    fn pull_requests_per_query_stays_under_the_node_limit() {
+        assert_eq!(pull_request_query_nodes(100), 10_701);
+        assert_eq!(
+            pull_requests_per_query(100, MAX_PULL_REQUESTS_PER_QUERY),
+            46
+        );
+        assert_eq!(
+            pull_requests_per_query(25, MAX_PULL_REQUESTS_PER_QUERY),
+            MAX_PULL_REQUESTS_PER_QUERY
+        );
+        assert_eq!(pull_requests_per_query(100, 3), 3);
+        assert_eq!(pull_requests_per_query(100, 0), 1);
+        for connection_size in [10, 25, 50, 100] {
+            let prs = pull_requests_per_query(connection_size, usize::MAX);
+            assert!(prs * pull_request_query_nodes(connection_size) <= MAX_QUERY_NODES);
+        }
+    }
+
+    #[test]
+    fn with_rate_limit_adds_the_cost_to_the_top_level_selection() {
+        let query = with_rate_limit(REPOSITORY_NODES_QUERY);
+
+        assert!(query.starts_with(REPOSITORY_NODES_QUERY.split('{').next().unwrap()));
+        assert!(query.contains("query($ids: [ID!]!) {\n  rateLimit { cost remaining resetAt }\n"));
+        assert!(query.contains("nodes(ids: $ids)"));
+    }
+
+    #[test]
+    fn comments_page_deserializes_backward_page_info() {
+        let page: PullRequestPageResponse<CommentsPage> = serde_json::from_str(
+            r#"{
+                "repository": {
+                    "pullRequest": {
+                        "comments": {
+                            "pageInfo": { "hasPreviousPage": true, "startCursor": "Y3Vy" },
+                            "nodes": []
+                        }
+                    }
+                }
+            }"#,
+        )
+        .expect("page deserializes");
+
+        let comments = page.repository.pull_request.expect("pr present").comments;
+        assert!(comments.page_info.has_previous_page);
+        assert_eq!(comments.page_info.start_cursor.as_deref(), Some("Y3Vy"));
+    }
+
+    #[test]
+    fn status_check_rollup_context_deserializes_check_details() {
+        let context: StatusCheckRollupContext = serde_json::from_str(
+            r#"{
+                "__typename": "CheckRun",
+                "name": "lint",
+                "checkSuite": {
+                    "app": { "slug": "github-actions" },
+                    "workflowRun": { "workflow": { "name": "CI" } }
+                },
+                "status": "COMPLETED",
+                "conclusion": "FAILURE",
+                "detailsUrl": "https://github.com/o/r/actions/runs/1",
+                "startedAt": "2025-06-15T00:00:00Z",
+                "completedAt": "2025-06-15T00:02:00Z",
+                "isRequired": true
+            }"#,
+        )
+        .expect("check run deserializes");
+
+        match context {
+            StatusCheckRollupContext::CheckRun {
+                name,
+                check_suite,
+                details_url,
+                completed_at,
+                ..
+            } => {
+                assert_eq!(name, "lint");
+                assert_eq!(
+                    check_suite.as_ref().and_then(CheckSuiteNode::workflow_name),
+                    Some("CI")
+                );
+                assert_eq!(
+                    details_url.as_deref(),
+                    Some("https://github.com/o/r/actions/runs/1")
+                );
+                assert_eq!(completed_at.as_deref(), Some("2025-06-15T00:02:00Z"));
+            }
+            _ => panic!("expected a check run"),
+        }
     }
 }
@@ -1,27 +1,190 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::Display;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 use chrono::{DateTime, Utc};
 use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, USER_AGENT};
 use serde::de::DeserializeOwned;
 
+pub use app_auth::GitHubAuth;
+
+pub mod app_auth;
+pub mod device_flow;
 pub mod graphql;
 pub mod schema;
 
-const BASE_URL: &str = "https://api.github.com";
+pub const BASE_URL: &str = "https://api.github.com";
 const PER_PAGE: i64 = 100;
+/// Older pages of comments or reviews fetched per PR before giving up and
+/// recording the PR's comment history as truncated.
+const MAX_COMMENT_HISTORY_PAGES: usize = 10;
+
+/// Smallest comment and review connection size a query is shrunk to before
+/// giving up on it.
+const MIN_CONNECTION_SIZE: usize = 10;
+/// GraphQL error types GitHub reports for queries that are too big to run.
+const QUERY_TOO_LARGE_ERROR_TYPES: [&str; 2] =
+    ["MAX_NODE_LIMIT_EXCEEDED", "RESOURCE_LIMITS_EXCEEDED"];
+
+/// Classic token scopes the tracker needs: `repo` for private repositories and
+/// `read:org` for team lookups.
+pub const RECOMMENDED_SCOPES: [&str; 2] = ["repo", "read:org"];
+
+/// What GitHub reports about the token used by a client.
+#[derive(Debug, Clone)]
+pub struct TokenInfo {
+    pub user: schema::User,
+    /// `None` for fine-grained tokens, which do not report OAuth scopes.
+    pub scopes: Option<Vec<String>>,
+    /// Raw `GitHub-Authentication-Token-Expiration` header, if the token expires.
+    pub expires_at: Option<String>,
+}
+
+/// GraphQL requests made through a client and the rate-limit points GitHub
+/// charged for them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GraphqlUsage {
+    pub requests: u64,
+    pub cost: u64,
+}
+
+impl GraphqlUsage {
+    /// The usage accumulated since `earlier` was taken.
+    pub fn since(self, earlier: GraphqlUsage) -> GraphqlUsage {
+        GraphqlUsage {
+            requests: self.requests.saturating_sub(earlier.requests),
+            cost: self.cost.saturating_sub(earlier.cost),
+        }
+    }
+}
+
+impl std::ops::Add for GraphqlUsage {
+    type Output = GraphqlUsage;
+
+    fn add(self, other: GraphqlUsage) -> GraphqlUsage {
+        GraphqlUsage {
+            requests: self.requests + other.requests,
+            cost: self.cost + other.cost,
+        }
+    }
+}
+
+/// Points left in a token's GraphQL rate limit as of its latest response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    pub remaining: u64,
+    pub reset_at: DateTime<Utc>,
+}
+
+impl RateLimitStatus {
+    /// The more recent of two statuses. Responses of parallel requests arrive
+    /// out of order, so within one window the lower `remaining` wins.
+    fn latest(self, other: RateLimitStatus) -> RateLimitStatus {
+        match self.reset_at.cmp(&other.reset_at) {
+            std::cmp::Ordering::Less => other,
+            std::cmp::Ordering::Greater => self,
+            std::cmp::Ordering::Equal if other.remaining < self.remaining => other,
+            std::cmp::Ordering::Equal => self,
+        }
+    }
+}
+
+/// GitHub refused a query, or timed out on it, because of its size; a
+/// smaller query may succeed.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct QueryTooLarge(String);
+
+#[derive(Debug, Default)]
+struct GraphqlUsageCounters {
+    requests: AtomicU64,
+    cost: AtomicU64,
+    rate_limit: Mutex<Option<RateLimitStatus>>,
+}
+
+/// Limits on the size of PR queries, lowered whenever GitHub rejects one as
+/// too large so the next queries don't repeat the failure, and raised again
+/// step by step as queries succeed so one oversized PR doesn't keep the rest
+/// of a sync at the reduced size.
+#[derive(Debug)]
+struct BatchLimits {
+    prs_per_query: AtomicUsize,
+    connection_size: AtomicUsize,
+}
+
+impl Default for BatchLimits {
+    fn default() -> Self {
+        Self {
+            prs_per_query: AtomicUsize::new(graphql::MAX_PULL_REQUESTS_PER_QUERY),
+            connection_size: AtomicUsize::new(graphql::MAX_CONNECTION_SIZE),
+        }
+    }
+}
+
+impl BatchLimits {
+    /// Lower the limits after a query of `prs` PRs with `connection_size`
+    /// comments and reviews each was too large: halve the PRs per query, or
+    /// for a single PR its connection size. `false` when neither can shrink.
+    fn shrink(&self, prs: usize, connection_size: usize) -> bool {
+        if prs > 1 {
+            self.prs_per_query.fetch_min(prs / 2, Ordering::Relaxed);
+            true
+        } else if connection_size > MIN_CONNECTION_SIZE {
+            self.connection_size.fetch_min(
+                (connection_size / 2).max(MIN_CONNECTION_SIZE),
+                Ordering::Relaxed,
+            );
+            true
+        } else {
+            false
+        }
+    }
 
+    /// Raise the limits after a query of `prs` PRs with `connection_size`
+    /// comments and reviews each succeeded at the current limits: double the
+    /// connection size first, then the PRs per query. A query that ran below
+    /// the limits, e.g. the last few PRs of a repository, says nothing about
+    /// larger ones and leaves them as they are.
+    fn grow(&self, prs: usize, connection_size: usize) {
+        if connection_size < graphql::MAX_CONNECTION_SIZE {
+            let _ = self.connection_size.compare_exchange(
+                connection_size,
+                (connection_size * 2).min(graphql::MAX_CONNECTION_SIZE),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+            return;
+        }
+        let limit = self.prs_per_query.load(Ordering::Relaxed);
+        if prs >= limit && limit < graphql::MAX_PULL_REQUESTS_PER_QUERY {
+            let _ = self.prs_per_query.compare_exchange(
+                limit,
+                (limit * 2).min(graphql::MAX_PULL_REQUESTS_PER_QUERY),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+        }
+    }
+}
+
+/// Clones share their GraphQL usage counters and query size limits.
 #[derive(Clone)]
 pub struct GitHubClient {
     http: reqwest::Client,
-    auth_token: String,
+    auth: GitHubAuth,
     log_requests: bool,
+    usage: Arc<GraphqlUsageCounters>,
+    batch_limits: Arc<BatchLimits>,
 }
 
 impl GitHubClient {
-    pub fn new(auth_token: impl Into<String>) -> anyhow::Result<Self> {
-        let auth_token = auth_token.into();
-        if auth_token.trim().is_empty() {
-            anyhow::bail!("auth token is required");
+    pub fn new(auth: impl Into<GitHubAuth>) -> anyhow::Result<Self> {
+        let auth = auth.into();
+        if let GitHubAuth::Token(token) = &auth {
+            if token.trim().is_empty() {
+                anyhow::bail!("auth token is required");
+            }
         }
 
         let mut headers = HeaderMap::new();
@@ -34,30 +197,63 @@ impl GitHubClient {
             "X-GitHub-Api-Version",
             HeaderValue::from_static("2022-11-28"),
         );
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {auth_token}"))?,
-        );
-
         let http = reqwest::Client::builder()
             .default_headers(headers)
             .build()?;
         Ok(Self {
             http,
-            auth_token,
+            auth,
             log_requests: false,
+            usage: Arc::default(),
+            batch_limits: Arc::default(),
         })
     }
 
+    pub fn auth(&self) -> &GitHubAuth {
+        &self.auth
+    }
+
     pub fn with_request_logging(mut self, log_requests: bool) -> Self {
         self.log_requests = log_requests;
         self
     }
 
+    /// GraphQL requests made so far by this client and its clones.
+    pub fn graphql_usage(&self) -> GraphqlUsage {
+        GraphqlUsage {
+            requests: self.usage.requests.load(Ordering::Relaxed),
+            cost: self.usage.cost.load(Ordering::Relaxed),
+        }
+    }
+
+    /// The token's GraphQL rate limit as of the latest response, if any
+    /// request was made yet.
+    pub fn rate_limit(&self) -> Option<RateLimitStatus> {
+        *self.usage.rate_limit.lock().unwrap()
+    }
+
     pub async fn fetch_authenticated_user(&self) -> anyhow::Result<schema::User> {
         self.get_json(&format!("{BASE_URL}/user")).await
     }
 
+    pub async fn fetch_token_info(&self) -> anyhow::Result<TokenInfo> {
+        let (user, headers): (schema::User, HeaderMap) = self
+            .get_json_with_headers(&format!("{BASE_URL}/user"))
+            .await?;
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+
+        Ok(TokenInfo {
+            user,
+            scopes: header("X-OAuth-Scopes").map(|value| parse_oauth_scopes(&value)),
+            expires_at: header("GitHub-Authentication-Token-Expiration"),
+        })
+    }
+
     pub async fn fetch_user_teams(&self) -> anyhow::Result<Vec<schema::UserTeam>> {
         let url = format!("{BASE_URL}/user/teams?per_page={PER_PAGE}&page=1");
         self.get_paginated(&url).await.map_err(|err| {
@@ -93,12 +289,86 @@ impl GitHubClient {
         })
     }
 
+    pub async fn fetch_org_repositories(
+        &self,
+        org: &str,
+    ) -> anyhow::Result<Vec<schema::Repository>> {
+        ensure_not_blank("organization", org)?;
+        let url = format!("{BASE_URL}/orgs/{org}/repos?type=all&per_page={PER_PAGE}&page=1");
+        self.get_paginated(&url).await
+    }
+
+    pub async fn fetch_team_repositories(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> anyhow::Result<Vec<schema::Repository>> {
+        ensure_not_blank("organization", org)?;
+        ensure_not_blank("team", team_slug)?;
+        let url =
+            format!("{BASE_URL}/orgs/{org}/teams/{team_slug}/repos?per_page={PER_PAGE}&page=1");
+        self.get_paginated(&url).await.map_err(|err| {
+            if err.to_string().contains("status=403") {
+                anyhow::anyhow!(
+                    "failed to fetch repositories for team '{team_slug}' in org '{org}': \
+                     token likely lacks 'read:org' scope.\n\
+                     Original error: {err}"
+                )
+            } else {
+                err
+            }
+        })
+    }
+
+    /// Look up a repository by name. Renamed and transferred repositories
+    /// redirect, so the returned `full_name` is the current canonical name.
+    pub async fn fetch_repository(&self, repo_name: &str) -> anyhow::Result<schema::Repository> {
+        let (owner, name) = split_repo_name(repo_name)?;
+        ensure_not_blank("owner", owner)?;
+        ensure_not_blank("repo", name)?;
+        self.get_json(&format!("{BASE_URL}/repos/{owner}/{name}"))
+            .await
+            .map_err(|err| {
+                if err.to_string().contains("status=404") {
+                    anyhow::anyhow!(
+                        "repository '{repo_name}' does not exist or is not accessible with this account"
+                    )
+                } else {
+                    err
+                }
+            })
+    }
+
+    /// Current `owner/name` of each repository node id that still resolves.
+    pub async fn fetch_repository_names_by_node_id(
+        &self,
+        node_ids: &[String],
+    ) -> anyhow::Result<HashMap<String, String>> {
+        let mut names = HashMap::new();
+        for chunk in node_ids.chunks(PER_PAGE as usize) {
+            let response: graphql::RepositoryNodesResponse = self
+                .post_graphql(
+                    graphql::REPOSITORY_NODES_QUERY,
+                    serde_json::json!({ "ids": chunk }),
+                )
+                .await?;
+            names.extend(
+                response
+                    .nodes
+                    .into_iter()
+                    .flatten()
+                    .map(|node| (node.id, node.name_with_owner)),
+            );
+        }
+        Ok(names)
+    }
+
     pub async fn fetch_tracked_pull_requests_search(
         &self,
         repo_name: &str,
         authors: &[String],
         updated_after: Option<DateTime<Utc>>,
-    ) -> anyhow::Result<Vec<graphql::PullRequestNode>> {
+    ) -> anyhow::Result<Vec<graphql::SearchPullRequestNode>> {
         ensure_not_blank("repo name", repo_name)?;
         if authors.is_empty() {
             return Ok(Vec::new());
@@ -108,7 +378,6 @@ impl GitHubClient {
         let mut cursor: Option<String> = None;
         let updated_after =
             updated_after.map(|cutoff| cutoff.to_rfc3339_opts(chrono::SecondsFormat::Secs, true));
-        let query = graphql::tracked_pull_requests_search_query();
         let search_query = graphql::build_tracked_pull_requests_search_query(
             repo_name,
             authors,
@@ -121,8 +390,9 @@ impl GitHubClient {
                 "cursor": cursor,
             });
 
-            let response: graphql::TrackedPullRequestSearchResponse =
-                self.post_graphql(&query, variables).await?;
+            let response: graphql::TrackedPullRequestSearchResponse = self
+                .post_graphql(graphql::TRACKED_PULL_REQUESTS_SEARCH_QUERY, variables)
+                .await?;
 
             let search = response.search;
             all_nodes.extend(search.nodes);
@@ -140,10 +410,57 @@ impl GitHubClient {
         Ok(all_nodes)
     }
 
+    /// Open PRs matching any of the review inbox searches, grouped by
+    /// repository.
+    pub async fn fetch_review_inbox(&self) -> anyhow::Result<BTreeMap<String, BTreeSet<i64>>> {
+        let mut inbox: BTreeMap<String, BTreeSet<i64>> = BTreeMap::new();
+
+        for filter in graphql::REVIEW_INBOX_FILTERS {
+            let search_query = graphql::build_review_inbox_search_query(filter);
+            let mut cursor: Option<String> = None;
+
+            loop {
+                let variables = serde_json::json!({
+                    "query": search_query,
+                    "cursor": cursor,
+                });
+
+                let response: graphql::ReviewInboxSearchResponse = self
+                    .post_graphql(graphql::REVIEW_INBOX_SEARCH_QUERY, variables)
+                    .await?;
+
+                let search = response.search;
+                for node in search.nodes {
+                    inbox
+                        .entry(node.repository.name_with_owner)
+                        .or_default()
+                        .insert(node.number);
+                }
+
+                if search.page_info.has_next_page {
+                    cursor = search.page_info.end_cursor;
+                    if cursor.is_none() {
+                        break;
+                    }
+                } else {
+                    break;
+                }
+            }
+        }
+
+        Ok(inbox)
+    }
+
+    /// Fetch PRs by number, as many per query as fit under GitHub's node
+    /// limit; queries GitHub finds too large are retried smaller. Comments
+    /// and reviews beyond those fetched with the PR are paged in until they
+    /// reach `known_comments_until[number]`, the newest comment already
+    /// stored for that PR.
     pub async fn fetch_pull_requests_by_numbers(
         &self,
         repo_name: &str,
         pr_numbers: &[i64],
+        known_comments_until: &HashMap<i64, DateTime<Utc>>,
     ) -> anyhow::Result<Vec<graphql::PullRequestNode>> {
         ensure_not_blank("repo name", repo_name)?;
         if pr_numbers.is_empty() {
@@ -152,16 +469,40 @@ impl GitHubClient {
 
         let (owner, name) = split_repo_name(repo_name)?;
         let mut all_nodes = Vec::new();
+        let mut remaining = pr_numbers;
+
+        while !remaining.is_empty() {
+            let connection_size = self.batch_limits.connection_size.load(Ordering::Relaxed);
+            let prs_per_query = graphql::pull_requests_per_query(
+                connection_size,
+                self.batch_limits.prs_per_query.load(Ordering::Relaxed),
+            );
+            let (chunk, rest) = remaining.split_at(prs_per_query.min(remaining.len()));
 
-        for chunk in pr_numbers.chunks(25) {
-            let query = graphql::build_pull_requests_by_number_query(chunk);
+            let query = graphql::build_pull_requests_by_number_query(chunk, connection_size);
             let variables = serde_json::json!({
                 "owner": owner,
                 "name": name,
             });
 
             let response: graphql::PullRequestsByNumberResponse =
-                self.post_graphql(&query, variables).await?;
+                match self.post_graphql(&query, variables).await {
+                    Ok(response) => response,
+                    Err(err)
+                        if err.is::<QueryTooLarge>()
+                            && self.batch_limits.shrink(chunk.len(), connection_size) =>
+                    {
+                        if self.log_requests {
+                            eprintln!(
+                                "[github] query for {} PRs was too large, retrying smaller: {err}",
+                                chunk.len()
+                            );
+                        }
+                        continue;
+                    }
+                    Err(err) => return Err(err),
+                };
+            self.batch_limits.grow(chunk.len(), connection_size);
 
             for number in chunk {
                 let alias = graphql::pull_request_alias(*number);
@@ -173,15 +514,111 @@ impl GitHubClient {
                     continue;
                 }
 
-                let node = serde_json::from_value(value.clone())
+                let mut node: graphql::PullRequestNode = serde_json::from_value(value.clone())
                     .map_err(|err| anyhow::anyhow!("error decoding graphql pull request: {err}"))?;
+                self.fetch_comment_history(
+                    owner,
+                    name,
+                    &mut node,
+                    known_comments_until.get(number).copied(),
+                )
+                .await?;
                 all_nodes.push(node);
             }
+            remaining = rest;
         }
 
         Ok(all_nodes)
     }
 
+    /// Prepend older pages of `node`'s comments and reviews until they reach
+    /// `known_until`, run out, or hit `MAX_COMMENT_HISTORY_PAGES`.
+    async fn fetch_comment_history(
+        &self,
+        owner: &str,
+        name: &str,
+        node: &mut graphql::PullRequestNode,
+        known_until: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<()> {
+        let mut pages = 0;
+        while let Some(cursor) = older_page_cursor(
+            &node.comments.page_info,
+            node.comments.nodes.first().map(|c| c.created_at.as_str()),
+            known_until,
+        ) {
+            if pages == MAX_COMMENT_HISTORY_PAGES {
+                node.comments_truncated = true;
+                break;
+            }
+            let variables = serde_json::json!({
+                "owner": owner,
+                "name": name,
+                "number": node.number,
+                "cursor": cursor,
+            });
+            let connection_size = self.batch_limits.connection_size.load(Ordering::Relaxed);
+            let query = graphql::build_pull_request_comments_page_query(connection_size);
+            let response: graphql::PullRequestPageResponse<graphql::CommentsPage> =
+                match self.post_graphql(&query, variables).await {
+                    Ok(response) => response,
+                    Err(err)
+                        if err.is::<QueryTooLarge>()
+                            && self.batch_limits.shrink(1, connection_size) =>
+                    {
+                        continue;
+                    }
+                    Err(err) => return Err(err),
+                };
+            let Some(page) = response.repository.pull_request else {
+                break;
+            };
+            let mut older = page.comments;
+            older.nodes.append(&mut node.comments.nodes);
+            node.comments = older;
+            pages += 1;
+        }
+
+        let mut pages = 0;
+        while let Some(cursor) = older_page_cursor(
+            &node.reviews.page_info,
+            node.reviews.nodes.first().map(|r| r.created_at.as_str()),
+            known_until,
+        ) {
+            if pages == MAX_COMMENT_HISTORY_PAGES {
+                node.comments_truncated = true;
+                break;
+            }
+            let variables = serde_json::json!({
+                "owner": owner,
+                "name": name,
+                "number": node.number,
+                "cursor": cursor,
+            });
+            let connection_size = self.batch_limits.connection_size.load(Ordering::Relaxed);
+            let query = graphql::build_pull_request_reviews_page_query(connection_size);
+            let response: graphql::PullRequestPageResponse<graphql::ReviewsPage> =
+                match self.post_graphql(&query, variables).await {
+                    Ok(response) => response,
+                    Err(err)
+                        if err.is::<QueryTooLarge>()
+                            && self.batch_limits.shrink(1, connection_size) =>
+                    {
+                        continue;
+                    }
+                    Err(err) => return Err(err),
+                };
+            let Some(page) = response.repository.pull_request else {
+                break;
+            };
+            let mut older = page.reviews;
+            older.nodes.append(&mut node.reviews.nodes);
+            node.reviews = older;
+            pages += 1;
+        }
+
+        Ok(())
+    }
+
     async fn post_graphql<T: DeserializeOwned>(
         &self,
         query: &str,
@@ -194,29 +631,52 @@ impl GitHubClient {
         }
 
         let body = serde_json::json!({
-            "query": query,
+            "query": graphql::with_rate_limit(query),
             "variables": variables,
         });
 
-        let response = self.http.post(url).json(&body).send().await?;
+        self.usage.requests.fetch_add(1, Ordering::Relaxed);
+        let response = self
+            .http
+            .post(url)
+            .header(AUTHORIZATION, self.authorization().await?)
+            .json(&body)
+            .send()
+            .await?;
         let status = response.status();
 
         if !status.is_success() {
             let body = response.text().await.unwrap_or_default();
-            anyhow::bail!(
+            let message = format!(
                 "github API request failed: status={} body={}",
                 status.as_u16(),
                 body.trim()
             );
+            // GitHub answers queries that run into its timeout with a 502.
+            if matches!(status.as_u16(), 502 | 504) {
+                return Err(QueryTooLarge(message).into());
+            }
+            anyhow::bail!(message);
         }
 
-        let response_body: serde_json::Value = response.json().await?;
+        let mut response_body: serde_json::Value = response.json().await?;
+        let rate_limit = response_body
+            .get_mut("data")
+            .and_then(|data| data.as_object_mut())
+            .and_then(|data| data.remove("rateLimit"))
+            .and_then(|rate_limit| serde_json::from_value::<graphql::RateLimit>(rate_limit).ok());
+        if let Some(rate_limit) = rate_limit {
+            self.record_rate_limit(&rate_limit);
+        }
 
         if let Some(errors) = response_body.get("errors") {
             let data = response_body.get("data");
             let data_is_present = data.is_some_and(|d| !d.is_null());
 
             if !data_is_present {
+                if is_query_too_large(errors) {
+                    return Err(QueryTooLarge(format!("graphql errors: {errors}")).into());
+                }
                 anyhow::bail!("graphql errors: {errors}");
             }
 
@@ -234,6 +694,24 @@ impl GitHubClient {
             .map_err(|err| anyhow::anyhow!("error decoding graphql response: {err}"))
     }
 
+    fn record_rate_limit(&self, rate_limit: &graphql::RateLimit) {
+        self.usage
+            .cost
+            .fetch_add(rate_limit.cost, Ordering::Relaxed);
+        let Ok(reset_at) = DateTime::parse_from_rfc3339(&rate_limit.reset_at) else {
+            return;
+        };
+        let status = RateLimitStatus {
+            remaining: rate_limit.remaining,
+            reset_at: reset_at.with_timezone(&Utc),
+        };
+        let mut latest = self.usage.rate_limit.lock().unwrap();
+        *latest = Some(match *latest {
+            Some(previous) => previous.latest(status),
+            None => status,
+        });
+    }
+
     async fn get_paginated<T>(&self, first_url: &str) -> anyhow::Result<Vec<T>>
     where
         T: DeserializeOwned,
@@ -260,19 +738,32 @@ impl GitHubClient {
     }
 
     async fn get_json_with_link<T>(&self, url: &str) -> anyhow::Result<(T, Option<String>)>
+    where
+        T: DeserializeOwned,
+    {
+        let (value, headers): (T, HeaderMap) = self.get_json_with_headers(url).await?;
+        let link_header = headers
+            .get("Link")
+            .and_then(|h| h.to_str().ok())
+            .map(|v| v.to_string());
+        Ok((value, link_header))
+    }
+
+    async fn get_json_with_headers<T>(&self, url: &str) -> anyhow::Result<(T, HeaderMap)>
     where
         T: DeserializeOwned,
     {
         if self.log_requests {
             eprintln!("[github] GET {url}");
         }
-        let response = self.http.get(url).send().await?;
+        let response = self
+            .http
+            .get(url)
+            .header(AUTHORIZATION, self.authorization().await?)
+            .send()
+            .await?;
         let status = response.status();
-        let link_header = response
-            .headers()
-            .get("Link")
-            .and_then(|h| h.to_str().ok())
-            .map(|v| v.to_string());
+        let headers = response.headers().clone();
 
         if !status.is_success() {
             let body = response.text().await.unwrap_or_default();
@@ -286,37 +777,31 @@ impl GitHubClient {
         let body = response.text().await?;
         let value = serde_json::from_str::<T>(&body)
             .map_err(|err| anyhow::anyhow!("error decoding response body for {url}: {err}"))?;
-        Ok((value, link_header))
+        Ok((value, headers))
     }
 
-    pub fn auth_token(&self) -> &str {
-        &self.auth_token
+    /// Authorization header for the next request. App installation tokens
+    /// are refreshed here, so long syncs never run into an expired token.
+    async fn authorization(&self) -> anyhow::Result<HeaderValue> {
+        let token = match &self.auth {
+            GitHubAuth::Token(token) => token.clone(),
+            GitHubAuth::App(provider) => provider.token().await?,
+        };
+        let mut value = HeaderValue::from_str(&format!("Bearer {token}"))?;
+        value.set_sensitive(true);
+        Ok(value)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::parse_next_url;
-
-    #[test]
-    fn parse_next_url_extracts_next_link() {
-        let header = concat!(
-            "<https://api.github.com/resource?page=2>; rel=\"next\", ",
-            "<https://api.github.com/resource?page=5>; rel=\"last\""
-        );
-
-        assert_eq!(
-            parse_next_url(header),
-            Some("https://api.github.com/resource?page=2".to_string())
-        );
-    }
-
-    #[test]
-    fn parse_next_url_returns_none_without_next_link() {
-        let header = "<https://api.github.com/resource?page=5>; rel=\"last\"";
-
-        assert_eq!(parse_next_url(header), None);
-    }
+/// Whether the `errors` of a GraphQL response say the query was too large.
+fn is_query_too_large(errors: &serde_json::Value) -> bool {
+    errors.as_array().is_some_and(|errors| {
+        errors.iter().any(|error| {
+            error["type"]
+                .as_str()
+                .is_some_and(|kind| QUERY_TOO_LARGE_ERROR_TYPES.contains(&kind))
+        })
+    })
 }
 
 fn ensure_not_blank(label: impl Display, value: &str) -> anyhow::Result<()> {
@@ -332,6 +817,83 @@ fn split_repo_name(repo_name: &str) -> anyhow::Result<(&str, &str)> {
         .ok_or_else(|| anyhow::anyhow!("repo name must be in 'owner/name' format: {repo_name}"))
 }
 
+/// Accept `owner/repo` as well as the forms people paste: repository or pull
+/// request URLs and SSH or HTTPS clone URLs. Returns `owner/repo`.
+pub fn normalize_repository_reference(input: &str) -> anyhow::Result<String> {
+    let trimmed = input.trim();
+    let path = trimmed
+        .strip_prefix("git@github.com:")
+        .or_else(|| trimmed.strip_prefix("ssh://git@github.com/"))
+        .or_else(|| {
+            let without_scheme = trimmed
+                .strip_prefix("https://")
+                .or_else(|| trimmed.strip_prefix("http://"))?;
+            without_scheme
+                .strip_prefix("www.github.com/")
+                .or_else(|| without_scheme.strip_prefix("github.com/"))
+        })
+        .unwrap_or(trimmed);
+
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+    let (Some(owner), Some(name)) = (segments.next(), segments.next()) else {
+        anyhow::bail!("expected 'owner/repo' or a GitHub repository URL, got '{input}'");
+    };
+    // Only URLs may carry a trailing path such as `/pull/42`.
+    if path.len() == trimmed.len() && segments.next().is_some() {
+        anyhow::bail!("expected 'owner/repo' or a GitHub repository URL, got '{input}'");
+    }
+    let name = name.strip_suffix(".git").unwrap_or(name);
+    if name.is_empty() || owner.contains(':') {
+        anyhow::bail!("expected 'owner/repo' or a GitHub repository URL, got '{input}'");
+    }
+
+    Ok(format!("{owner}/{name}"))
+}
+
+pub fn parse_oauth_scopes(header: &str) -> Vec<String> {
+    header
+        .split(',')
+        .map(str::trim)
+        .filter(|scope| !scope.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Recommended scopes not granted by `scopes`, accounting for broader scopes
+/// that imply narrower ones (e.g. `admin:org` implies `read:org`).
+pub fn missing_recommended_scopes(scopes: &[String]) -> Vec<&'static str> {
+    RECOMMENDED_SCOPES
+        .into_iter()
+        .filter(|required| {
+            !scopes.iter().any(|scope| match *required {
+                "read:org" => matches!(scope.as_str(), "read:org" | "write:org" | "admin:org"),
+                other => scope == other,
+            })
+        })
+        .collect()
+}
+
+/// Cursor of the next older page to fetch, or `None` once there is none or
+/// the oldest fetched item is no newer than what is already stored.
+pub fn older_page_cursor(
+    page_info: &graphql::BackwardPageInfo,
+    oldest_created_at: Option<&str>,
+    known_until: Option<DateTime<Utc>>,
+) -> Option<String> {
+    if !page_info.has_previous_page {
+        return None;
+    }
+    let reached_known = known_until.is_some_and(|known_until| {
+        oldest_created_at
+            .and_then(|created_at| DateTime::parse_from_rfc3339(created_at).ok())
+            .is_some_and(|created_at| created_at <= known_until)
+    });
+    if reached_known {
+        return None;
+    }
+    page_info.start_cursor.clone()
+}
+
 pub fn parse_next_url(link_header: &str) -> Option<String> {
     link_header
         .split(',')
@@ -346,3 +908,190 @@ pub fn parse_next_url(link_header: &str) -> Option<String> {
             Some(segment[start + 1..end].to_string())
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use super::{
+        graphql, is_query_too_large, missing_recommended_scopes, normalize_repository_reference,
+        older_page_cursor, parse_next_url, parse_oauth_scopes, BatchLimits, RateLimitStatus,
+        MIN_CONNECTION_SIZE,
+    };
+    use std::sync::atomic::Ordering;
+
+    #[test]
+    fn parse_next_url_extracts_next_link() {
+        let header = concat!(
+            "<https://api.github.com/resource?page=2>; rel=\"next\", ",
+            "<https://api.github.com/resource?page=5>; rel=\"last\""
+        );
+
+        assert_eq!(
+            parse_next_url(header),
+            Some("https://api.github.com/resource?page=2".to_string())
+        );
+    }
+
+    #[test]
+    fn older_page_cursor_stops_at_stored_comments() {
+        let page_info = graphql::BackwardPageInfo {
+            has_previous_page: true,
+            start_cursor: Some("cursor".to_string()),
+        };
+        let known_until = DateTime::parse_from_rfc3339("2025-06-15T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            older_page_cursor(&page_info, Some("2025-06-16T00:00:00Z"), Some(known_until)),
+            Some("cursor".to_string())
+        );
+        assert_eq!(
+            older_page_cursor(&page_info, Some("2025-06-14T00:00:00Z"), Some(known_until)),
+            None
+        );
+        assert_eq!(
+            older_page_cursor(&page_info, Some("2025-06-14T00:00:00Z"), None),
+            Some("cursor".to_string())
+        );
+        assert_eq!(
+            older_page_cursor(&graphql::BackwardPageInfo::default(), None, None),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_next_url_returns_none_without_next_link() {
+        let header = "<https://api.github.com/resource?page=5>; rel=\"last\"";
+
+        assert_eq!(parse_next_url(header), None);
+    }
+
+    #[test]
+    fn normalize_repository_reference_accepts_names_and_urls() {
+        for input in [
+            "octo/widgets",
+            " octo/widgets ",
+            "https://github.com/octo/widgets",
+            "https://github.com/octo/widgets/",
+            "https://github.com/octo/widgets.git",
+            "https://github.com/octo/widgets/pull/42",
+            "git@github.com:octo/widgets.git",
+            "ssh://git@github.com/octo/widgets.git",
+        ] {
+            assert_eq!(
+                normalize_repository_reference(input).expect("valid reference"),
+                "octo/widgets",
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn normalize_repository_reference_rejects_malformed_input() {
+        for input in [
+            "widgets",
+            "octo/",
+            "octo/widgets/extra",
+            "https://gitlab.com/octo/widgets",
+            "https://github.com/octo",
+        ] {
+            assert!(normalize_repository_reference(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_oauth_scopes_splits_and_trims() {
+        assert_eq!(
+            parse_oauth_scopes("repo, read:org,  gist"),
+            vec!["repo", "read:org", "gist"]
+        );
+        assert!(parse_oauth_scopes("").is_empty());
+    }
+
+    #[test]
+    fn missing_recommended_scopes_reports_absent_scopes() {
+        assert_eq!(
+            missing_recommended_scopes(&parse_oauth_scopes("gist")),
+            vec!["repo", "read:org"]
+        );
+        assert!(missing_recommended_scopes(&parse_oauth_scopes("repo, admin:org")).is_empty());
+    }
+
+    #[test]
+    fn is_query_too_large_matches_node_and_resource_limit_errors() {
+        let node_limit = serde_json::json!([
+            { "type": "MAX_NODE_LIMIT_EXCEEDED", "message": "exceeds the maximum limit" }
+        ]);
+        let not_found = serde_json::json!([{ "type": "NOT_FOUND", "message": "missing" }]);
+
+        assert!(is_query_too_large(&node_limit));
+        assert!(!is_query_too_large(&not_found));
+        assert!(!is_query_too_large(&serde_json::json!("boom")));
+    }
+
+    #[test]
+    fn batch_limits_shrink_prs_per_query_before_connection_size() {
+        let limits = BatchLimits::default();
+
+        assert!(limits.shrink(46, 100));
+        assert_eq!(limits.prs_per_query.load(Ordering::Relaxed), 23);
+        assert_eq!(limits.connection_size.load(Ordering::Relaxed), 100);
+
+        assert!(limits.shrink(1, 100));
+        assert_eq!(limits.connection_size.load(Ordering::Relaxed), 50);
+        assert!(limits.shrink(1, 15));
+        assert_eq!(
+            limits.connection_size.load(Ordering::Relaxed),
+            MIN_CONNECTION_SIZE
+        );
+        assert!(!limits.shrink(1, MIN_CONNECTION_SIZE));
+    }
+
+    #[test]
+    fn batch_limits_grow_back_after_successful_queries() {
+        let limits = BatchLimits::default();
+        assert!(limits.shrink(46, 100));
+        assert!(limits.shrink(1, 100));
+
+        // A short final chunk doesn't raise the PRs per query.
+        limits.grow(3, 50);
+        assert_eq!(limits.connection_size.load(Ordering::Relaxed), 100);
+        limits.grow(3, 100);
+        assert_eq!(limits.prs_per_query.load(Ordering::Relaxed), 23);
+
+        limits.grow(23, 100);
+        assert_eq!(limits.prs_per_query.load(Ordering::Relaxed), 46);
+        limits.grow(46, 100);
+        assert_eq!(
+            limits.prs_per_query.load(Ordering::Relaxed),
+            graphql::MAX_PULL_REQUESTS_PER_QUERY
+        );
+    }
+
+    #[test]
+    fn rate_limit_status_keeps_the_latest_window_and_lowest_remaining() {
+        let reset_at = DateTime::parse_from_rfc3339("2025-06-15T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let status = |remaining, reset_at| RateLimitStatus {
+            remaining,
+            reset_at,
+        };
+        let next_window = reset_at + chrono::Duration::hours(1);
+
+        assert_eq!(
+            status(400, reset_at).latest(status(380, reset_at)),
+            status(380, reset_at)
+        );
+        assert_eq!(
+            status(380, reset_at).latest(status(400, reset_at)),
+            status(380, reset_at)
+        );
+        assert_eq!(
+            status(10, reset_at).latest(status(4_990, next_window)),
+            status(4_990, next_window)
+        );
+    }
+}
@@ -26,3 +26,15 @@ pub struct UserTeam {
 pub struct TeamMember {
     pub login: String,
 }
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Repository {
+    pub full_name: String,
+    #[serde(default)]
+    pub node_id: Option<String>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub topics: Vec<String>,
+    pub created_at: String,
+}
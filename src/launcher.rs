@@ -0,0 +1,230 @@
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use crate::models::{PullRequest, ReviewLauncher};
+
+/// Placeholders understood by launcher command and argument templates.
+pub const PLACEHOLDERS: [&str; 6] = [
+    "{repo}",
+    "{owner}",
+    "{number}",
+    "{head_sha}",
+    "{url}",
+    "{local_path}",
+];
+
+/// Values substituted into a launcher template for a single pull request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchContext {
+    pub repository: String,
+    pub owner: String,
+    pub repo: String,
+    pub number: i64,
+    pub head_sha: String,
+    pub url: String,
+    pub local_path: Option<String>,
+}
+
+impl LaunchContext {
+    pub fn for_pull_request(pr: &PullRequest, local_path: Option<String>) -> Self {
+        let owner = pr
+            .repository
+            .split_once('/')
+            .map_or("", |(owner, _)| owner)
+            .to_string();
+
+        Self {
+            repository: pr.repository.clone(),
+            owner,
+            repo: pr.repository_name().to_string(),
+            number: pr.number,
+            head_sha: pr.head_sha.clone(),
+            url: pr.url(),
+            local_path: local_path.map(|path| expand_home(&path)),
+        }
+    }
+}
+
+/// A fully expanded launcher invocation, ready to be spawned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    pub working_directory: Option<String>,
+}
+
+/// Expand every placeholder in `template` using `context`.
+///
+/// Fails when the template references `{local_path}` but no local path is
+/// configured for the repository, since launching in the wrong directory is
+/// worse than not launching at all.
+pub fn expand_template(template: &str, context: &LaunchContext) -> anyhow::Result<String> {
+    let mut expanded = template
+        .replace("{repo}", &context.repo)
+        .replace("{owner}", &context.owner)
+        .replace("{number}", &context.number.to_string())
+        .replace("{head_sha}", &context.head_sha)
+        .replace("{url}", &context.url);
+
+    if expanded.contains("{local_path}") {
+        let Some(local_path) = context.local_path.as_deref() else {
+            anyhow::bail!(
+                "no local path configured for '{}', run 'prt repositories set-path {} <path>' first",
+                context.repository,
+                context.repository
+            );
+        };
+        expanded = expanded.replace("{local_path}", local_path);
+    }
+
+    Ok(expanded)
+}
+
+pub fn build_launch_command(
+    launcher: &ReviewLauncher,
+    context: &LaunchContext,
+) -> anyhow::Result<LaunchCommand> {
+    let program = expand_template(&launcher.command, context)?;
+    if program.trim().is_empty() {
+        anyhow::bail!("launcher '{}' has an empty command", launcher.name);
+    }
+
+    let args = launcher
+        .args
+        .iter()
+        .map(|arg| expand_template(arg, context))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(LaunchCommand {
+        program,
+        args,
+        working_directory: context.local_path.clone(),
+    })
+}
+
+/// Spawn the launcher detached from the terminal so it cannot draw over the TUI.
+pub fn spawn_review_launcher(
+    launcher: &ReviewLauncher,
+    context: &LaunchContext,
+) -> anyhow::Result<()> {
+    let launch = build_launch_command(launcher, context)?;
+
+    let mut command = Command::new(&launch.program);
+    command
+        .args(&launch.args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    if let Some(dir) = launch
+        .working_directory
+        .as_deref()
+        .filter(|dir| std::path::Path::new(dir).is_dir())
+    {
+        command.current_dir(dir);
+    }
+
+    command.spawn().map_err(|err| {
+        anyhow::anyhow!(
+            "failed to start launcher '{}' ({}): {err}",
+            launcher.name,
+            launch.program
+        )
+    })?;
+    Ok(())
+}
+
+/// Expand a leading `~` to the user's home directory.
+pub fn expand_home(path: &str) -> String {
+    let Some(rest) = path.strip_prefix('~') else {
+        return path.to_string();
+    };
+    if !(rest.is_empty() || rest.starts_with('/')) {
+        return path.to_string();
+    }
+
+    match std::env::var_os("HOME") {
+        Some(home) => {
+            let mut expanded = PathBuf::from(home);
+            let rest = rest.trim_start_matches('/');
+            if !rest.is_empty() {
+                expanded.push(rest);
+            }
+            expanded.display().to_string()
+        }
+        None => path.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(local_path: Option<&str>) -> LaunchContext {
+        LaunchContext {
+            repository: "octo/widgets".to_string(),
+            owner: "octo".to_string(),
+            repo: "widgets".to_string(),
+            number: 42,
+            head_sha: "abc123".to_string(),
+            url: "https://github.com/octo/widgets/pull/42".to_string(),
+            local_path: local_path.map(str::to_string),
+        }
+    }
+
+    fn launcher(command: &str, args: &[&str]) -> ReviewLauncher {
+        ReviewLauncher {
+            name: "test".to_string(),
+            command: command.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn expand_template_replaces_all_placeholders() {
+        let expanded = expand_template(
+            "{owner}/{repo}#{number} {head_sha} {url} {local_path}",
+            &context(Some("/src/widgets")),
+        )
+        .expect("expansion succeeds");
+
+        assert_eq!(
+            expanded,
+            "octo/widgets#42 abc123 https://github.com/octo/widgets/pull/42 /src/widgets"
+        );
+    }
+
+    #[test]
+    fn expand_template_requires_local_path_only_when_referenced() {
+        assert_eq!(
+            expand_template("{url}", &context(None)).expect("expansion succeeds"),
+            "https://github.com/octo/widgets/pull/42"
+        );
+
+        let err = expand_template("cd {local_path}", &context(None)).unwrap_err();
+        assert!(err.to_string().contains("octo/widgets"));
+    }
+
+    #[test]
+    fn build_launch_command_expands_command_and_args() {
+        let launch = build_launch_command(
+            &launcher("code", &["--new-window", "{local_path}"]),
+            &context(Some("/src/widgets")),
+        )
+        .expect("build succeeds");
+
+        assert_eq!(launch.program, "code");
+        assert_eq!(launch.args, vec!["--new-window", "/src/widgets"]);
+        assert_eq!(launch.working_directory.as_deref(), Some("/src/widgets"));
+    }
+
+    #[test]
+    fn build_launch_command_rejects_empty_command() {
+        assert!(build_launch_command(&launcher("  ", &[]), &context(None)).is_err());
+    }
+
+    #[test]
+    fn expand_home_leaves_other_paths_untouched() {
+        assert_eq!(expand_home("/abs/path"), "/abs/path");
+        assert_eq!(expand_home("~other/path"), "~other/path");
+    }
+}
@@ -1,13 +1,16 @@
 pub mod cli_app;
 pub mod core;
+pub mod credentials;
 pub mod db;
 pub mod github;
+pub mod launcher;
 pub mod models;
 pub mod pr_repository;
 pub mod scoring;
 pub mod service;
 pub mod sync;
 pub mod tui;
+pub mod worktree;
 
 /// Returns the default SQLite database URL, honouring XDG conventions.
 ///
@@ -25,6 +28,15 @@ pub fn default_db_path() -> String {
         return explicit;
     }
 
+    match data_dir() {
+        Some(dir) => format!("sqlite://{}/db.sqlite3", dir.display()),
+        None => "sqlite://./db.sqlite3".to_string(),
+    }
+}
+
+/// `$XDG_DATA_HOME/pr-tracker-rust`, falling back to
+/// `$HOME/.local/share/pr-tracker-rust`; `None` when neither is set.
+pub fn data_dir() -> Option<std::path::PathBuf> {
     let data_home = std::env::var("XDG_DATA_HOME")
         .ok()
         .filter(|s| !s.is_empty())
@@ -32,10 +44,6 @@ pub fn default_db_path() -> String {
             std::env::var("HOME")
                 .ok()
                 .map(|home| format!("{home}/.local/share"))
-        });
-
-    match data_home {
-        Some(base) => format!("sqlite://{base}/pr-tracker-rust/db.sqlite3"),
-        None => "sqlite://./db.sqlite3".to_string(),
-    }
+        })?;
+    Some(std::path::Path::new(&data_home).join("pr-tracker-rust"))
 }
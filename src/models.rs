@@ -11,41 +11,209 @@ pub struct PrComment {
     pub updated_at: DateTime<Utc>,
     pub is_review_comment: bool,
     pub review_state: Option<String>,
+    /// File an inline review comment is on; `None` for conversation comments.
+    pub path: Option<String>,
+    pub line: Option<i64>,
+    pub diff_hunk: Option<String>,
+    /// The diff the inline comment was made on has since changed.
+    pub is_outdated: bool,
+    /// Someone else @-mentioned the current user or one of their teams.
+    pub mentions_user: bool,
+    /// When the body was last edited, if ever.
+    pub edited_at: Option<DateTime<Utc>>,
+    /// Hidden on GitHub (spam, off-topic, resolved, ...).
+    pub is_minimized: bool,
+}
+
+impl PrComment {
+    pub fn is_inline(&self) -> bool {
+        self.path.is_some()
+    }
+
+    /// An edit to a comment that already existed at `since`.
+    pub fn is_edited_since(&self, since: DateTime<Utc>) -> bool {
+        self.created_at <= since && self.edited_at.is_some_and(|edited_at| edited_at > since)
+    }
+
+    /// Activity after `since` that is not just an edit of an older comment.
+    pub fn is_new_since(&self, since: DateTime<Utc>) -> bool {
+        self.updated_at > since && !self.is_edited_since(since)
+    }
+
+    /// Whether the body @-mentions `login` or one of `teams` (`org/slug`).
+    /// Mentions in quoted replies and code blocks don't count.
+    pub fn mentions(&self, login: &str, teams: &[String]) -> bool {
+        let mut in_code_block = false;
+        for line in self.body.lines() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block || trimmed.starts_with('>') {
+                continue;
+            }
+            if mention_handles(line).any(|handle| {
+                (!login.is_empty() && handle.eq_ignore_ascii_case(login))
+                    || teams.iter().any(|team| handle.eq_ignore_ascii_case(team))
+            }) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// The `user` or `org/team` handles @-mentioned in a line. An `@` preceded by
+/// a word character, as in an email address, is not a mention.
+fn mention_handles(line: &str) -> impl Iterator<Item = &str> {
+    line.char_indices().filter_map(move |(index, ch)| {
+        if ch != '@' {
+            return None;
+        }
+        let preceded_by_word = line[..index]
+            .chars()
+            .next_back()
+            .is_some_and(|prev| prev.is_alphanumeric() || matches!(prev, '_' | '`'));
+        if preceded_by_word {
+            return None;
+        }
+        let rest = &line[index + 1..];
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '/')))
+            .unwrap_or(rest.len());
+        let handle = rest[..end].trim_end_matches('/');
+        (!handle.is_empty()).then_some(handle)
+    })
+}
+
+/// A single check run or commit status reported for a PR's head commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrCheck {
+    /// The workflow (or, outside GitHub Actions, the app) that ran the check.
+    /// Empty for commit statuses.
+    pub workflow: String,
+    pub name: String,
+    /// GitHub's check status, e.g. `QUEUED`, `IN_PROGRESS` or `COMPLETED`.
+    pub status: String,
+    /// GitHub's conclusion once the check completed, e.g. `SUCCESS` or `FAILURE`.
+    pub conclusion: Option<String>,
+    pub ci_status: CiStatus,
+    pub is_required: bool,
+    /// Whether the repository's CI policy counted the check towards the PR's
+    /// CI status when it was synced.
+    pub counts_towards_ci: bool,
+    pub details_url: Option<String>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl PrCheck {
+    /// The name GitHub shows for the check, e.g. `CI / build`.
+    pub fn display_name(&self) -> String {
+        if self.workflow.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{} / {}", self.workflow, self.name)
+        }
+    }
+}
+
+/// A review conversation on a line of the diff. Only its first comment is kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrReviewThread {
+    pub id: String,
+    pub path: String,
+    /// The line the thread is on, `None` when it no longer maps to the diff.
+    pub line: Option<i64>,
+    pub is_resolved: bool,
+    pub is_outdated: bool,
+    pub author: String,
+    pub body: String,
+    pub comment_count: i64,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CiStatus {
+    /// Queued or running.
     Pending,
+    /// A required status has not been reported yet.
+    Expected,
     Success,
+    /// Completed without passing or failing, e.g. an informational check.
+    Neutral,
+    Skipped,
     Failure,
+    Cancelled,
+    /// Never completed and was marked stale by GitHub.
+    Stale,
+    /// Waiting for someone to act, e.g. approve a workflow run or deployment.
+    ActionRequired,
+    /// The head commit reported no checks at all.
+    NoChecks,
+    /// The checks the repository's CI policy counts passed, but others failed.
+    OptionalFailing,
 }
 
 impl std::fmt::Display for CiStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match self {
             Self::Pending => "pending",
+            Self::Expected => "expected",
             Self::Success => "succeeded",
+            Self::Neutral => "neutral",
+            Self::Skipped => "skipped",
             Self::Failure => "failed",
+            Self::Cancelled => "cancelled",
+            Self::Stale => "stale",
+            Self::ActionRequired => "action required",
+            Self::NoChecks => "no checks",
+            Self::OptionalFailing => "optional checks failing",
         })
     }
 }
 
 impl CiStatus {
-    pub fn as_i64(self) -> i64 {
+    /// The value persisted in `ci_status` columns.
+    pub fn as_str(self) -> &'static str {
         match self {
-            Self::Pending => 0,
-            Self::Success => 1,
-            Self::Failure => 2,
+            Self::Pending => "pending",
+            Self::Expected => "expected",
+            Self::Success => "success",
+            Self::Neutral => "neutral",
+            Self::Skipped => "skipped",
+            Self::Failure => "failure",
+            Self::Cancelled => "cancelled",
+            Self::Stale => "stale",
+            Self::ActionRequired => "action_required",
+            Self::NoChecks => "no_checks",
+            Self::OptionalFailing => "optional_failing",
         }
     }
 
-    pub fn from_i64(value: i64) -> Self {
+    pub fn parse(value: &str) -> Self {
         match value {
-            1 => Self::Success,
-            2 => Self::Failure,
+            "expected" => Self::Expected,
+            "success" => Self::Success,
+            "neutral" => Self::Neutral,
+            "skipped" => Self::Skipped,
+            "failure" => Self::Failure,
+            "cancelled" => Self::Cancelled,
+            "stale" => Self::Stale,
+            "action_required" => Self::ActionRequired,
+            "no_checks" => Self::NoChecks,
+            "optional_failing" => Self::OptionalFailing,
             _ => Self::Pending,
         }
     }
+
+    /// Whether the status is settled and not a pass, i.e. something went wrong.
+    pub fn is_failing(self) -> bool {
+        matches!(
+            self,
+            Self::Failure | Self::Cancelled | Self::Stale | Self::ActionRequired
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -75,11 +243,52 @@ impl ApprovalStatus {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChangeKind {
+    /// Someone @-mentioned the user or one of their teams.
+    Mentioned,
     NewComment,
+    /// An existing comment's body was edited.
+    CommentEdited,
     NewCommit,
     NewCistatus,
     NewReviewStatus,
     NewPullRequest,
+    /// A review thread was resolved or reopened.
+    ThreadResolutionChanged,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Involvement {
+    Author,
+    ReviewRequested,
+    /// A team the user belongs to was asked to review, not the user directly.
+    TeamReviewRequested,
+}
+
+/// Why a PR is in the database.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PrSource {
+    /// Opened by a tracked author in a tracked repository.
+    #[default]
+    Tracked,
+    /// Found by the review inbox searches: the user was asked to review,
+    /// mentioned, or is otherwise involved.
+    ReviewInbox,
+}
+
+impl PrSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Tracked => "tracked",
+            Self::ReviewInbox => "review_inbox",
+        }
+    }
+
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "review_inbox" => Self::ReviewInbox,
+            _ => Self::Tracked,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -106,8 +315,18 @@ pub struct PullRequest {
     pub last_review_status_update_at: DateTime<Utc>,
     pub last_acknowledged_at: Option<DateTime<Utc>>,
     pub requested_reviewers: Vec<String>,
+    /// Teams asked to review, as `org/slug`.
+    pub requested_teams: Vec<String>,
     pub user_has_reviewed: bool,
+    /// One of the current user's teams is among `requested_teams`.
+    pub team_review_requested: bool,
     pub comments: Vec<PrComment>,
+    pub checks: Vec<PrCheck>,
+    pub review_threads: Vec<PrReviewThread>,
+    pub last_thread_update_at: DateTime<Utc>,
+    /// Older comments or reviews exist that sync did not fetch.
+    pub comments_truncated: bool,
+    pub source: PrSource,
 }
 
 impl PullRequest {
@@ -126,6 +345,14 @@ impl PullRequest {
             && self.last_commit_at <= last_ack
             && self.last_ci_status_update_at <= last_ack
             && self.last_review_status_update_at <= last_ack
+            && self.last_thread_update_at <= last_ack
+    }
+
+    pub fn unresolved_thread_count(&self) -> usize {
+        self.review_threads
+            .iter()
+            .filter(|thread| !thread.is_resolved)
+            .count()
     }
 
     pub fn is_mine(&self, current_user: &str) -> bool {
@@ -153,8 +380,17 @@ impl PullRequest {
         };
 
         let mut changes = Vec::new();
+        if self.has_mention_since(Some(last_ack)) {
+            changes.push(ChangeKind::Mentioned);
+        }
         if self.last_comment_at > last_ack {
-            changes.push(ChangeKind::NewComment);
+            let edited = self.has_comment_edit_since(last_ack, "");
+            if !edited || self.has_external_comment_activity_since(last_ack, "") {
+                changes.push(ChangeKind::NewComment);
+            }
+            if edited {
+                changes.push(ChangeKind::CommentEdited);
+            }
         }
         if self.last_commit_at > last_ack {
             changes.push(ChangeKind::NewCommit);
@@ -165,6 +401,9 @@ impl PullRequest {
         if self.last_review_status_update_at > last_ack {
             changes.push(ChangeKind::NewReviewStatus);
         }
+        if self.last_thread_update_at > last_ack {
+            changes.push(ChangeKind::ThreadResolutionChanged);
+        }
         changes
     }
 
@@ -175,7 +414,7 @@ impl PullRequest {
         self.all_changes()
             .into_iter()
             .filter(|change| match change {
-                ChangeKind::NewPullRequest => true,
+                ChangeKind::NewPullRequest | ChangeKind::Mentioned => true,
                 ChangeKind::NewCommit => match perspective {
                     PrPerspective::MyPr => false,
                     PrPerspective::TrackedPr => {
@@ -186,9 +425,15 @@ impl PullRequest {
                 ChangeKind::NewComment => last_ack.is_none_or(|last_ack| {
                     self.has_external_comment_activity_since(last_ack, current_user)
                 }),
+                ChangeKind::CommentEdited => last_ack
+                    .is_none_or(|last_ack| self.has_comment_edit_since(last_ack, current_user)),
                 ChangeKind::NewReviewStatus => last_ack.is_none_or(|last_ack| {
                     self.has_external_review_activity_since(last_ack, current_user)
                 }),
+                ChangeKind::ThreadResolutionChanged => match perspective {
+                    PrPerspective::MyPr => true,
+                    PrPerspective::TrackedPr => self.user_is_or_was_involved(current_user),
+                },
             })
             .collect()
     }
@@ -207,6 +452,13 @@ impl PullRequest {
         let mut updates = String::from("  ");
         for change in changes {
             match change {
+                ChangeKind::Mentioned => {
+                    let authors = self
+                        .last_acknowledged_at
+                        .map(|last_ack| self.mention_authors_since(last_ack))
+                        .unwrap_or_default();
+                    updates.push_str(&format!("mentioned by {} | ", authors.join(", ")));
+                }
                 ChangeKind::NewComment => {
                     let authors = self
                         .last_acknowledged_at
@@ -219,27 +471,55 @@ impl PullRequest {
                         updates.push_str(&format!("new comment(s) from {} | ", authors.join(", ")));
                     }
                 }
+                ChangeKind::CommentEdited => {
+                    let authors = self
+                        .last_acknowledged_at
+                        .map(|last_ack| self.comment_editors_since(last_ack, current_user))
+                        .unwrap_or_default();
+                    updates.push_str(&format!("comment(s) edited by {} | ", authors.join(", ")));
+                }
                 ChangeKind::NewCommit => updates.push_str("New Commits | "),
-                ChangeKind::NewCistatus => updates.push_str("CI Status Changed | "),
+                ChangeKind::NewCistatus => {
+                    updates.push_str(&self.ci_change_summary());
+                    updates.push_str(" | ");
+                }
                 ChangeKind::NewReviewStatus => updates.push_str("Review Status Changed | "),
                 ChangeKind::NewPullRequest => updates.push_str("New PR | "),
+                ChangeKind::ThreadResolutionChanged => match self.unresolved_thread_count() {
+                    0 => updates.push_str("Threads Resolved | "),
+                    1 => updates.push_str("1 Unresolved Thread | "),
+                    count => updates.push_str(&format!("{count} Unresolved Threads | ")),
+                },
             }
         }
 
         updates
     }
-    pub fn user_is_involved(&self, current_user: &str) -> bool {
+
+    /// How the user is involved in the PR, strongest first.
+    pub fn involvement(&self, current_user: &str) -> Option<Involvement> {
         if current_user.is_empty() {
-            return false;
+            return None;
         }
 
         if self.author.eq_ignore_ascii_case(current_user) {
-            return true;
+            return Some(Involvement::Author);
         }
 
-        self.requested_reviewers
+        if self
+            .requested_reviewers
             .iter()
             .any(|reviewer| reviewer.eq_ignore_ascii_case(current_user))
+        {
+            return Some(Involvement::ReviewRequested);
+        }
+
+        self.team_review_requested
+            .then_some(Involvement::TeamReviewRequested)
+    }
+
+    pub fn user_is_involved(&self, current_user: &str) -> bool {
+        self.involvement(current_user).is_some()
     }
 
     fn user_is_or_was_involved(&self, current_user: &str) -> bool {
@@ -251,7 +531,80 @@ impl PullRequest {
     }
 
     fn ci_change_is_meaningful(&self) -> bool {
-        !matches!(self.ci_status, CiStatus::Pending)
+        !matches!(
+            self.ci_status,
+            CiStatus::Pending | CiStatus::Expected | CiStatus::NoChecks
+        )
+    }
+
+    /// Checks behind a failing CI status: the failing checks the CI policy
+    /// counts, or every failing check when the policy counts none of them.
+    pub fn failing_checks(&self) -> Vec<&PrCheck> {
+        let failing: Vec<&PrCheck> = self
+            .checks
+            .iter()
+            .filter(|check| check.ci_status.is_failing())
+            .collect();
+        if failing.iter().any(|check| check.counts_towards_ci) {
+            failing
+                .into_iter()
+                .filter(|check| check.counts_towards_ci)
+                .collect()
+        } else {
+            failing
+        }
+    }
+
+    /// Describe the CI outcome by naming the checks behind it, e.g. "lint failed".
+    pub fn ci_change_summary(&self) -> String {
+        let mut failing = self.failing_checks();
+        if self.ci_status.is_failing()
+            && failing
+                .iter()
+                .any(|check| check.ci_status == self.ci_status)
+        {
+            failing.retain(|check| check.ci_status == self.ci_status);
+        }
+        match self.ci_status {
+            status if status.is_failing() && !failing.is_empty() => {
+                const MAX_NAMES: usize = 3;
+                let names: Vec<&str> = failing
+                    .iter()
+                    .take(MAX_NAMES)
+                    .map(|check| check.name.as_str())
+                    .collect();
+                let mut summary = names.join(", ");
+                if failing.len() > MAX_NAMES {
+                    summary.push_str(&format!(", +{} more", failing.len() - MAX_NAMES));
+                }
+                let outcome = match status {
+                    CiStatus::Cancelled => "cancelled",
+                    CiStatus::Stale => "went stale",
+                    CiStatus::ActionRequired => "need action",
+                    _ => "failed",
+                };
+                format!("{summary} {outcome}")
+            }
+            CiStatus::OptionalFailing if !failing.is_empty() => format!(
+                "{} failed (optional)",
+                failing
+                    .iter()
+                    .map(|check| check.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            CiStatus::Success | CiStatus::Neutral | CiStatus::Skipped
+                if !self.checks.is_empty() =>
+            {
+                "CI passed".to_string()
+            }
+            _ => "CI Status Changed".to_string(),
+        }
+    }
+
+    /// Comments shown to the user: those hidden on GitHub are left out.
+    fn visible_comments(&self) -> impl Iterator<Item = &PrComment> {
+        self.comments.iter().filter(|comment| !comment.is_minimized)
     }
 
     fn has_external_comment_activity_since(
@@ -259,8 +612,8 @@ impl PullRequest {
         last_ack: DateTime<Utc>,
         current_user: &str,
     ) -> bool {
-        self.comments.iter().any(|comment| {
-            comment.updated_at > last_ack && !author_matches_user(&comment.author, current_user)
+        self.visible_comments().any(|comment| {
+            comment.is_new_since(last_ack) && !author_matches_user(&comment.author, current_user)
         })
     }
 
@@ -269,32 +622,47 @@ impl PullRequest {
         last_ack: DateTime<Utc>,
         current_user: &str,
     ) -> Vec<String> {
-        let mut comments: Vec<&PrComment> = self
-            .comments
-            .iter()
+        let comments: Vec<&PrComment> = self
+            .visible_comments()
             .filter(|comment| {
-                comment.updated_at > last_ack && !author_matches_user(&comment.author, current_user)
+                comment.is_new_since(last_ack)
+                    && !author_matches_user(&comment.author, current_user)
             })
             .collect();
+        distinct_authors_newest_first(comments)
+    }
+
+    fn has_comment_edit_since(&self, last_ack: DateTime<Utc>, current_user: &str) -> bool {
+        self.visible_comments().any(|comment| {
+            comment.is_edited_since(last_ack) && !author_matches_user(&comment.author, current_user)
+        })
+    }
 
-        comments.sort_by(|left, right| {
-            right
-                .updated_at
-                .cmp(&left.updated_at)
-                .then_with(|| left.author.cmp(&right.author))
-        });
+    fn comment_editors_since(&self, last_ack: DateTime<Utc>, current_user: &str) -> Vec<String> {
+        let comments: Vec<&PrComment> = self
+            .visible_comments()
+            .filter(|comment| {
+                comment.is_edited_since(last_ack)
+                    && !author_matches_user(&comment.author, current_user)
+            })
+            .collect();
+        distinct_authors_newest_first(comments)
+    }
 
-        let mut authors = Vec::new();
-        for comment in comments {
-            if !authors
-                .iter()
-                .any(|author: &String| author.eq_ignore_ascii_case(&comment.author))
-            {
-                authors.push(comment.author.clone());
-            }
-        }
+    /// Whether a comment mentioning the user arrived after `last_ack`, or at
+    /// all when the PR was never acknowledged.
+    pub fn has_mention_since(&self, last_ack: Option<DateTime<Utc>>) -> bool {
+        self.visible_comments().any(|comment| {
+            comment.mentions_user && last_ack.is_none_or(|last_ack| comment.updated_at > last_ack)
+        })
+    }
 
-        authors
+    fn mention_authors_since(&self, last_ack: DateTime<Utc>) -> Vec<String> {
+        let comments: Vec<&PrComment> = self
+            .visible_comments()
+            .filter(|comment| comment.mentions_user && comment.updated_at > last_ack)
+            .collect();
+        distinct_authors_newest_first(comments)
     }
 
     fn has_external_review_activity_since(
@@ -302,13 +670,31 @@ impl PullRequest {
         last_ack: DateTime<Utc>,
         current_user: &str,
     ) -> bool {
-        self.comments.iter().any(|comment| {
+        self.visible_comments().any(|comment| {
             comment.is_review_comment
                 && comment.updated_at > last_ack
                 && !author_matches_user(&comment.author, current_user)
         })
     }
 
+    /// Inline review comments grouped by file path, oldest first within a file.
+    pub fn inline_comments_by_file(&self) -> Vec<(&str, Vec<&PrComment>)> {
+        let mut by_file: std::collections::BTreeMap<&str, Vec<&PrComment>> =
+            std::collections::BTreeMap::new();
+        for comment in &self.comments {
+            if let Some(path) = comment.path.as_deref() {
+                by_file.entry(path).or_default().push(comment);
+            }
+        }
+        by_file
+            .into_iter()
+            .map(|(path, mut comments)| {
+                comments.sort_by_key(|comment| comment.created_at);
+                (path, comments)
+            })
+            .collect()
+    }
+
     pub fn url(&self) -> String {
         format!(
             "https://github.com/{}/pull/{}",
@@ -317,20 +703,402 @@ impl PullRequest {
     }
 }
 
+fn distinct_authors_newest_first(mut comments: Vec<&PrComment>) -> Vec<String> {
+    comments.sort_by(|left, right| {
+        right
+            .updated_at
+            .cmp(&left.updated_at)
+            .then_with(|| left.author.cmp(&right.author))
+    });
+
+    let mut authors = Vec::new();
+    for comment in comments {
+        if !authors
+            .iter()
+            .any(|author: &String| author.eq_ignore_ascii_case(&comment.author))
+        {
+            authors.push(comment.author.clone());
+        }
+    }
+
+    authors
+}
+
 fn author_matches_user(author: &str, current_user: &str) -> bool {
     !current_user.is_empty() && author.eq_ignore_ascii_case(current_user)
 }
 
+/// The teams a user belongs to, as `org/slug`, cached from GitHub.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserTeams {
+    pub username: String,
+    pub teams: Vec<String>,
+    pub fetched_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TrackedRepository {
     pub repository: String,
     pub last_synced_at: Option<DateTime<Utc>>,
+    /// Login of the account used to sync this repository; `None` means the
+    /// active account.
+    pub account: Option<String>,
+    pub ci_policy: CiPolicy,
+    /// GraphQL node id of the repository, which survives renames and
+    /// transfers; `None` until the repository was resolved against the API.
+    pub node_id: Option<String>,
+    /// Error of the last sync of this repository, cleared once it syncs again.
+    pub last_sync_error: Option<String>,
+}
+
+/// Which checks decide a repository's CI status. Checks left out by the
+/// policy can only make a PR `OptionalFailing`, never `Failure`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum CiPolicy {
+    /// Only checks branch protection marks as required.
+    #[default]
+    RequiredOnly,
+    All,
+    /// Only checks whose name matches one of these globs.
+    Allow(Vec<String>),
+    /// Every check except those whose name matches one of these globs.
+    Deny(Vec<String>),
+}
+
+impl CiPolicy {
+    /// The value persisted in `tracked_repositories.ci_policy`.
+    pub fn reference(&self) -> String {
+        match self {
+            Self::RequiredOnly => "required".to_string(),
+            Self::All => "all".to_string(),
+            Self::Allow(patterns) => format!("allow:{}", patterns.join(",")),
+            Self::Deny(patterns) => format!("deny:{}", patterns.join(",")),
+        }
+    }
+
+    pub fn from_reference(reference: &str) -> anyhow::Result<Self> {
+        let parse_patterns = |value: &str| -> anyhow::Result<Vec<String>> {
+            let patterns: Vec<String> = value
+                .split(',')
+                .map(str::trim)
+                .filter(|pattern| !pattern.is_empty())
+                .map(str::to_string)
+                .collect();
+            if patterns.is_empty() {
+                anyhow::bail!("CI policy '{reference}' needs at least one check name pattern");
+            }
+            Ok(patterns)
+        };
+
+        let (kind, value) = reference.split_once(':').unwrap_or((reference, ""));
+        match kind {
+            "required" if value.is_empty() => Ok(Self::RequiredOnly),
+            "all" if value.is_empty() => Ok(Self::All),
+            "allow" => Ok(Self::Allow(parse_patterns(value)?)),
+            "deny" => Ok(Self::Deny(parse_patterns(value)?)),
+            _ => anyhow::bail!("invalid CI policy '{reference}'"),
+        }
+    }
+
+    /// Whether a check counts towards the PR's CI status.
+    pub fn includes(&self, check_name: &str, is_required: bool) -> bool {
+        match self {
+            Self::RequiredOnly => is_required,
+            Self::All => true,
+            Self::Allow(patterns) => patterns
+                .iter()
+                .any(|pattern| glob_matches(pattern, check_name)),
+            Self::Deny(patterns) => !patterns
+                .iter()
+                .any(|pattern| glob_matches(pattern, check_name)),
+        }
+    }
+}
+
+/// Case-insensitive glob match supporting `*` (any run of characters) and
+/// `?` (any single character).
+pub fn glob_matches(pattern: &str, value: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let value: Vec<char> = value.to_lowercase().chars().collect();
+
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while v < value.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, v));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    v = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Filters selecting repositories of an organization, as given to
+/// `prt repositories discover`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepositoryDiscoveryRule {
+    pub org: String,
+    pub topic: Option<String>,
+    /// Only repositories this team (its slug) has access to.
+    pub team: Option<String>,
+    /// Glob matched against the repository name, without the owner.
+    pub pattern: Option<String>,
+    pub exclude_archived: bool,
+}
+
+impl RepositoryDiscoveryRule {
+    /// Whether `repository` passes the topic, name and archived filters. The
+    /// team filter is applied by listing the team's repositories instead.
+    pub fn matches(&self, repository: &DiscoveredRepository) -> bool {
+        let name = repository
+            .full_name
+            .rsplit_once('/')
+            .map_or(repository.full_name.as_str(), |(_, name)| name);
+
+        !(self.exclude_archived && repository.archived)
+            && self.topic.as_deref().is_none_or(|topic| {
+                repository
+                    .topics
+                    .iter()
+                    .any(|candidate| candidate.eq_ignore_ascii_case(topic))
+            })
+            && self
+                .pattern
+                .as_deref()
+                .is_none_or(|pattern| glob_matches(pattern, name))
+    }
+
+    /// One-line summary, e.g. `acme topic=rust name=api-* excluding archived`.
+    pub fn describe(&self) -> String {
+        let mut parts = vec![self.org.clone()];
+        if let Some(topic) = &self.topic {
+            parts.push(format!("topic={topic}"));
+        }
+        if let Some(team) = &self.team {
+            parts.push(format!("team={team}"));
+        }
+        if let Some(pattern) = &self.pattern {
+            parts.push(format!("name={pattern}"));
+        }
+        if self.exclude_archived {
+            parts.push("excluding archived".to_string());
+        }
+        parts.join(" ")
+    }
+}
+
+/// A repository listed while discovering an organization's repositories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredRepository {
+    pub full_name: String,
+    pub node_id: Option<String>,
+    pub archived: bool,
+    pub topics: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A discovery rule whose matching repositories created after `created_at`
+/// are tracked automatically at sync time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FollowedDiscoveryRule {
+    pub id: i64,
+    pub rule: RepositoryDiscoveryRule,
+    /// Account the rule lists repositories with and assigns them to; `None`
+    /// means the active account.
+    pub account: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Creation time of the newest repository the rule has listed; only
+    /// repositories created later are tracked.
+    pub seen_until: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewLauncher {
+    pub name: String,
+    pub command: String,
+    pub args: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepositoryLocalPath {
+    pub repository: String,
+    pub local_path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrWorktree {
+    pub repository: String,
+    pub pr_number: i64,
+    pub path: String,
+    pub head_sha: String,
+}
+
+/// What started a sync run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncTrigger {
+    Cli,
+    Tui,
+}
+
+impl SyncTrigger {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cli => "cli",
+            Self::Tui => "tui",
+        }
+    }
+
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "tui" => Self::Tui,
+            _ => Self::Cli,
+        }
+    }
+}
+
+/// One recorded sync run, see `sync::record_sync_run`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncRun {
+    pub id: i64,
+    pub trigger: SyncTrigger,
+    /// What the run covered, see `SyncOptions::describe`.
+    pub scope: String,
+    pub dry_run: bool,
+    pub started_at: DateTime<Utc>,
+    /// `None` while the run is going, or when it never finished.
+    pub finished_at: Option<DateTime<Utc>>,
+    pub new_prs: i64,
+    pub updated_data_prs: i64,
+    pub updated_attention_prs: i64,
+    pub deleted_prs: i64,
+    pub graphql_requests: i64,
+    /// Rate-limit points the GraphQL requests cost.
+    pub graphql_cost: i64,
+    /// Points left afterwards, for the account closest to its limit.
+    pub rate_limit_remaining: Option<i64>,
+    /// When that account's rate limit resets.
+    pub rate_limit_reset_at: Option<DateTime<Utc>>,
+    /// Why the whole run failed; failures of single repositories are kept on
+    /// their `repositories` entry.
+    pub error: Option<String>,
+    /// Progress lines as shown while the run was going.
+    pub log: Vec<String>,
+    pub repositories: Vec<SyncRunRepository>,
+}
+
+/// How one tracked repository fared in a sync run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncRunRepository {
+    pub repository: String,
+    pub duration_ms: i64,
+    pub new_prs: i64,
+    pub updated_prs: i64,
+    pub deleted_prs: i64,
+    pub error: Option<String>,
+}
+
+/// Where an account's GitHub token comes from. Only `Stored`, which older
+/// versions wrote, keeps the token itself in the database; the other variants
+/// store a reference to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CredentialSource {
+    Stored(String),
+    /// Name of an environment variable.
+    Env(String),
+    /// Shell command that prints the token, e.g. `pass show github`.
+    Command(String),
+    /// Host entry in the `gh` CLI configuration.
+    GhCli(String),
+    /// Path to a file readable only by its owner.
+    File(String),
+    /// GitHub App installation; tokens are minted from the app's private key.
+    GitHubApp {
+        app_id: String,
+        installation_id: i64,
+        private_key_path: String,
+    },
+}
+
+impl CredentialSource {
+    /// The value persisted in `users.credential_source`; never contains a token.
+    pub fn reference(&self) -> String {
+        match self {
+            Self::Stored(_) => "stored".to_string(),
+            Self::Env(name) => format!("env:{name}"),
+            Self::Command(command) => format!("command:{command}"),
+            Self::GhCli(host) => format!("gh:{host}"),
+            Self::File(path) => format!("file:{path}"),
+            Self::GitHubApp {
+                app_id,
+                installation_id,
+                private_key_path,
+            } => format!("app:{app_id}:{installation_id}:{private_key_path}"),
+        }
+    }
+
+    pub fn from_reference(reference: &str, stored_token: Option<String>) -> anyhow::Result<Self> {
+        if reference == "stored" {
+            return stored_token
+                .map(Self::Stored)
+                .ok_or_else(|| anyhow::anyhow!("stored credential is missing its token"));
+        }
+
+        let (kind, value) = reference
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("invalid credential source '{reference}'"))?;
+        match kind {
+            "env" => Ok(Self::Env(value.to_string())),
+            "command" => Ok(Self::Command(value.to_string())),
+            "gh" => Ok(Self::GhCli(value.to_string())),
+            "file" => Ok(Self::File(value.to_string())),
+            "app" => {
+                let mut parts = value.splitn(3, ':');
+                match (parts.next(), parts.next(), parts.next()) {
+                    (Some(app_id), Some(installation_id), Some(private_key_path)) => {
+                        Ok(Self::GitHubApp {
+                            app_id: app_id.to_string(),
+                            installation_id: installation_id.parse().map_err(|_| {
+                                anyhow::anyhow!("invalid credential source '{reference}'")
+                            })?,
+                            private_key_path: private_key_path.to_string(),
+                        })
+                    }
+                    _ => anyhow::bail!("invalid credential source '{reference}'"),
+                }
+            }
+            _ => anyhow::bail!("invalid credential source '{reference}'"),
+        }
+    }
+
+    pub fn stored_token(&self) -> Option<&str> {
+        match self {
+            Self::Stored(token) => Some(token),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct User {
-    pub access_token: String,
     pub username: String,
+    pub credential: CredentialSource,
+    pub is_active: bool,
+    /// Scopes granted when the token was obtained, if known.
+    pub scopes: Option<Vec<String>>,
 }
 
 #[cfg(test)]
@@ -380,8 +1148,15 @@ mod tests {
             last_review_status_update_at: base_time,
             last_acknowledged_at: None,
             requested_reviewers: vec![],
+            requested_teams: Vec::new(),
             user_has_reviewed: false,
+            team_review_requested: false,
             comments: vec![],
+            checks: vec![],
+            review_threads: vec![],
+            last_thread_update_at: base_time,
+            comments_truncated: false,
+            source: PrSource::Tracked,
         };
 
         for (index, event) in events.iter().enumerate() {
@@ -411,6 +1186,22 @@ mod tests {
             updated_at,
             is_review_comment,
             review_state: is_review_comment.then(|| "COMMENTED".to_string()),
+            path: None,
+            line: None,
+            diff_hunk: None,
+            is_outdated: false,
+            mentions_user: false,
+            edited_at: None,
+            is_minimized: false,
+        }
+    }
+
+    fn inline_comment(author: &str, path: &str, created_at: DateTime<Utc>) -> PrComment {
+        PrComment {
+            path: Some(path.to_string()),
+            line: Some(12),
+            diff_hunk: Some("@@ -1,3 +1,4 @@".to_string()),
+            ..test_comment(author, created_at, false)
         }
     }
 
@@ -506,6 +1297,48 @@ mod tests {
         assert!(!pr.is_acknowledged_for_user(&author()));
     }
 
+    #[test]
+    fn is_acknowledged_for_user_resets_for_inline_review_comment() {
+        let ack = timestamp(2);
+        let comment_at = timestamp(3);
+        let mut pr = build_pull_request(&[]);
+        pr.last_acknowledged_at = Some(ack);
+        pr.last_comment_at = comment_at;
+        pr.updated_at = comment_at;
+        pr.comments = vec![inline_comment("reviewer", "src/lib.rs", comment_at)];
+
+        assert!(!pr.is_acknowledged_for_user(&author()));
+        assert_eq!(
+            pr.updates_since_last_ack(&author()),
+            "  new comment(s) from reviewer | "
+        );
+    }
+
+    #[test]
+    fn inline_comments_by_file_groups_and_orders_comments() {
+        let mut pr = build_pull_request(&[]);
+        pr.comments = vec![
+            inline_comment("bob", "src/main.rs", timestamp(5)),
+            test_comment("carol", timestamp(4), false),
+            inline_comment("alice", "src/lib.rs", timestamp(3)),
+            inline_comment("dave", "src/main.rs", timestamp(2)),
+        ];
+
+        let grouped: Vec<(&str, Vec<&str>)> = pr
+            .inline_comments_by_file()
+            .into_iter()
+            .map(|(path, comments)| (path, comments.iter().map(|c| c.author.as_str()).collect()))
+            .collect();
+
+        assert_eq!(
+            grouped,
+            vec![
+                ("src/lib.rs", vec!["alice"]),
+                ("src/main.rs", vec!["dave", "bob"]),
+            ]
+        );
+    }
+
     #[test]
     fn is_acknowledged_for_user_stays_true_for_my_own_review() {
         let ack = timestamp(2);
@@ -554,6 +1387,83 @@ mod tests {
         );
     }
 
+    fn test_check(name: &str, ci_status: CiStatus, is_required: bool) -> PrCheck {
+        PrCheck {
+            workflow: String::new(),
+            name: name.to_string(),
+            status: "COMPLETED".to_string(),
+            conclusion: None,
+            ci_status,
+            is_required,
+            counts_towards_ci: is_required,
+            details_url: None,
+            started_at: None,
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn updates_since_last_ack_names_failing_required_checks() {
+        let mut pr = build_pull_request(&[TestPrEvent::Ack, TestPrEvent::CiStatus]);
+        pr.ci_status = CiStatus::Failure;
+        pr.checks = vec![
+            test_check("build", CiStatus::Success, true),
+            test_check("docs", CiStatus::Failure, false),
+            test_check("lint", CiStatus::Failure, true),
+        ];
+
+        assert_eq!(pr.updates_since_last_ack(&author()), "  lint failed | ");
+    }
+
+    #[test]
+    fn ci_change_summary_caps_listed_check_names() {
+        let mut pr = build_pull_request(&[]);
+        pr.ci_status = CiStatus::Failure;
+        pr.checks = ["a", "b", "c", "d", "e"]
+            .iter()
+            .map(|name| test_check(name, CiStatus::Failure, true))
+            .collect();
+
+        assert_eq!(pr.ci_change_summary(), "a, b, c, +2 more failed");
+    }
+
+    #[test]
+    fn ci_change_summary_names_checks_that_need_action() {
+        let mut pr = build_pull_request(&[]);
+        pr.ci_status = CiStatus::ActionRequired;
+        pr.checks = vec![
+            test_check("e2e", CiStatus::Cancelled, true),
+            test_check("deploy", CiStatus::ActionRequired, true),
+        ];
+
+        assert_eq!(pr.ci_change_summary(), "deploy need action");
+    }
+
+    #[test]
+    fn ci_change_summary_leaves_out_checks_the_policy_ignores() {
+        let mut pr = build_pull_request(&[]);
+        pr.ci_status = CiStatus::Failure;
+        let mut ignored = test_check("codecov/patch", CiStatus::Failure, true);
+        ignored.counts_towards_ci = false;
+        pr.checks = vec![ignored, test_check("lint", CiStatus::Failure, false)];
+        pr.checks[1].counts_towards_ci = true;
+
+        assert_eq!(pr.ci_change_summary(), "lint failed");
+
+        pr.ci_status = CiStatus::OptionalFailing;
+        pr.checks.truncate(1);
+
+        assert_eq!(pr.ci_change_summary(), "codecov/patch failed (optional)");
+    }
+
+    #[test]
+    fn ci_change_summary_falls_back_without_checks() {
+        let mut pr = build_pull_request(&[]);
+        pr.ci_status = CiStatus::Failure;
+
+        assert_eq!(pr.ci_change_summary(), "CI Status Changed");
+    }
+
     #[test]
     fn updates_since_last_ack_hides_my_commit_only_changes() {
         let pr = build_pull_request(&[TestPrEvent::Ack, TestPrEvent::Commit]);
@@ -561,6 +1471,29 @@ mod tests {
         assert_eq!(pr.updates_since_last_ack(&author()), "  ");
     }
 
+    #[test]
+    fn updates_since_last_ack_reports_unresolved_threads_after_reopen() {
+        let mut pr = build_pull_request(&[TestPrEvent::Ack]);
+        pr.last_thread_update_at = timestamp(10);
+        pr.review_threads = vec![PrReviewThread {
+            id: "t1".to_string(),
+            path: "src/lib.rs".to_string(),
+            line: Some(7),
+            is_resolved: false,
+            is_outdated: false,
+            author: "reviewer".to_string(),
+            body: "Please rename".to_string(),
+            comment_count: 2,
+        }];
+
+        assert_eq!(pr.all_changes(), vec![ChangeKind::ThreadResolutionChanged]);
+        assert_eq!(
+            pr.updates_since_last_ack(&author()),
+            "  1 Unresolved Thread | "
+        );
+        assert!(!pr.is_acknowledged());
+    }
+
     #[test]
     fn updates_since_last_ack_shows_new_pr_for_my_pr() {
         let pr = build_pull_request(&[]);
@@ -588,6 +1521,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn updates_since_last_ack_reports_edits_apart_from_new_comments() {
+        let ack = timestamp(3);
+        let mut edited = test_comment("alice", timestamp(2), false);
+        edited.updated_at = timestamp(4);
+        edited.edited_at = Some(timestamp(4));
+        let mut pr = build_pull_request(&[]);
+        pr.last_acknowledged_at = Some(ack);
+        pr.last_comment_at = timestamp(5);
+        pr.comments = vec![edited, test_comment("bob", timestamp(5), false)];
+
+        assert_eq!(
+            pr.updates_since_last_ack(&author()),
+            "  new comment(s) from bob | comment(s) edited by alice | "
+        );
+
+        pr.comments.pop();
+        pr.last_comment_at = timestamp(4);
+        assert_eq!(
+            pr.updates_since_last_ack(&author()),
+            "  comment(s) edited by alice | "
+        );
+    }
+
+    #[test]
+    fn hidden_comments_do_not_unacknowledge_a_pr() {
+        let mut hidden = test_comment("spammer", timestamp(4), false);
+        hidden.is_minimized = true;
+        hidden.mentions_user = true;
+        let mut pr = build_pull_request(&[]);
+        pr.last_acknowledged_at = Some(timestamp(3));
+        pr.last_comment_at = timestamp(4);
+        pr.comments = vec![hidden];
+
+        assert!(pr.is_acknowledged_for_user(&author()));
+    }
+
     #[test]
     fn updates_since_last_ack_excludes_my_comment_authorship() {
         let ack = timestamp(2);
@@ -628,6 +1598,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn updates_since_last_ack_shows_mention_authors_first() {
+        let ack = timestamp(2);
+        let comment_at = timestamp(3);
+        let mut pr = build_pull_request(&[]);
+        pr.author = "carol".to_string();
+        pr.last_acknowledged_at = Some(ack);
+        pr.last_comment_at = comment_at;
+        pr.updated_at = comment_at;
+        let mut mention = test_comment("bob", comment_at, false);
+        mention.mentions_user = true;
+        pr.comments = vec![mention];
+
+        assert!(pr.all_changes().contains(&ChangeKind::Mentioned));
+        assert_eq!(
+            pr.updates_since_last_ack(&author()),
+            "  mentioned by bob | new comment(s) from bob | "
+        );
+    }
+
+    #[test]
+    fn mention_before_ack_is_not_a_change() {
+        let mut pr = build_pull_request(&[]);
+        let mut mention = test_comment("bob", timestamp(1), false);
+        mention.mentions_user = true;
+        pr.comments = vec![mention];
+        pr.last_acknowledged_at = Some(timestamp(2));
+
+        assert!(!pr.all_changes().contains(&ChangeKind::Mentioned));
+        assert!(pr.has_mention_since(None));
+    }
+
+    #[test]
+    fn comment_mentions_match_login_and_teams_case_insensitively() {
+        let mut comment = test_comment("bob", timestamp(1), false);
+        let teams = vec!["acme/platform".to_string()];
+
+        comment.body = "@Alice can you look at the migration?".to_string();
+        assert!(comment.mentions("alice", &teams));
+
+        comment.body = "cc @acme/Platform.".to_string();
+        assert!(comment.mentions("alice", &teams));
+
+        comment.body = "@alicea and @acme/platform-infra".to_string();
+        assert!(!comment.mentions("alice", &teams));
+    }
+
+    #[test]
+    fn comment_mentions_ignore_emails_quotes_and_code() {
+        let mut comment = test_comment("bob", timestamp(1), false);
+
+        comment.body = "mail alice@alice.dev".to_string();
+        assert!(!comment.mentions("alice", &[]));
+
+        comment.body = "> @alice said so\nagreed".to_string();
+        assert!(!comment.mentions("alice", &[]));
+
+        comment.body = "```\n@alice\n```\ndone".to_string();
+        assert!(!comment.mentions("alice", &[]));
+    }
+
     #[test]
     fn repository_name_returns_repo_without_owner_prefix() {
         let pr = build_pull_request(&[]);
@@ -712,6 +1743,26 @@ mod tests {
         assert!(!pr.user_is_involved("someone-else"));
     }
 
+    #[test]
+    fn involvement_ranks_direct_requests_above_team_requests() {
+        let mut pr = build_pull_request(&[]);
+        pr.requested_teams = vec!["acme/platform".to_string()];
+        pr.team_review_requested = true;
+
+        assert_eq!(
+            pr.involvement("reviewer"),
+            Some(Involvement::TeamReviewRequested)
+        );
+        assert!(pr.user_is_involved("reviewer"));
+
+        pr.requested_reviewers = vec!["reviewer".to_string()];
+        assert_eq!(
+            pr.involvement("reviewer"),
+            Some(Involvement::ReviewRequested)
+        );
+        assert_eq!(pr.involvement(&author()), Some(Involvement::Author));
+    }
+
     #[test]
     fn user_is_involved_is_case_insensitive() {
         let mut pr = build_pull_request(&[]);
@@ -721,4 +1772,134 @@ mod tests {
         assert!(pr.user_is_involved("octocat"));
         assert!(pr.user_is_involved("reviewer"));
     }
+
+    fn discovered(full_name: &str, archived: bool, topics: &[&str]) -> DiscoveredRepository {
+        DiscoveredRepository {
+            full_name: full_name.to_string(),
+            node_id: None,
+            archived,
+            topics: topics.iter().map(|topic| topic.to_string()).collect(),
+            created_at: DateTime::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn discovery_rule_matches_topic_name_pattern_and_archived_state() {
+        let rule = RepositoryDiscoveryRule {
+            org: "acme".to_string(),
+            topic: Some("Rust".to_string()),
+            pattern: Some("api-*".to_string()),
+            exclude_archived: true,
+            ..RepositoryDiscoveryRule::default()
+        };
+
+        assert!(rule.matches(&discovered("acme/api-users", false, &["rust"])));
+        assert!(!rule.matches(&discovered("acme/api-users", true, &["rust"])));
+        assert!(!rule.matches(&discovered("acme/api-users", false, &["go"])));
+        assert!(!rule.matches(&discovered("acme/web-api", false, &["rust"])));
+        assert!(RepositoryDiscoveryRule::default().matches(&discovered("acme/x", true, &[])));
+    }
+
+    #[test]
+    fn discovery_rule_describe_lists_only_set_filters() {
+        let rule = RepositoryDiscoveryRule {
+            org: "acme".to_string(),
+            team: Some("platform".to_string()),
+            exclude_archived: true,
+            ..RepositoryDiscoveryRule::default()
+        };
+
+        assert_eq!(rule.describe(), "acme team=platform excluding archived");
+    }
+
+    #[test]
+    fn glob_matches_wildcards_case_insensitively() {
+        assert!(glob_matches("lint", "Lint"));
+        assert!(glob_matches("test-*", "test-linux"));
+        assert!(glob_matches("*/preview", "deploy/preview"));
+        assert!(glob_matches("build-?", "build-1"));
+        assert!(glob_matches("*", ""));
+        assert!(!glob_matches("test-*", "lint"));
+        assert!(!glob_matches("build-?", "build-10"));
+    }
+
+    #[test]
+    fn ci_status_round_trips_through_its_stored_value() {
+        for status in [
+            CiStatus::Pending,
+            CiStatus::Expected,
+            CiStatus::Success,
+            CiStatus::Neutral,
+            CiStatus::Skipped,
+            CiStatus::Failure,
+            CiStatus::Cancelled,
+            CiStatus::Stale,
+            CiStatus::ActionRequired,
+            CiStatus::NoChecks,
+            CiStatus::OptionalFailing,
+        ] {
+            assert_eq!(CiStatus::parse(status.as_str()), status);
+        }
+        assert_eq!(CiStatus::parse("unknown"), CiStatus::Pending);
+        assert!(!CiStatus::Skipped.is_failing());
+        assert!(CiStatus::Cancelled.is_failing());
+    }
+
+    #[test]
+    fn ci_policy_reference_round_trips() {
+        for policy in [
+            CiPolicy::RequiredOnly,
+            CiPolicy::All,
+            CiPolicy::Allow(vec!["lint".to_string(), "test-*".to_string()]),
+            CiPolicy::Deny(vec!["codecov/*".to_string()]),
+        ] {
+            assert_eq!(
+                CiPolicy::from_reference(&policy.reference()).expect("parses"),
+                policy
+            );
+        }
+
+        assert!(CiPolicy::from_reference("allow:").is_err());
+        assert!(CiPolicy::from_reference("some").is_err());
+    }
+
+    #[test]
+    fn ci_policy_includes_checks_by_policy() {
+        let deny = CiPolicy::Deny(vec!["codecov/*".to_string()]);
+
+        assert!(!CiPolicy::RequiredOnly.includes("lint", false));
+        assert!(CiPolicy::All.includes("lint", false));
+        assert!(CiPolicy::Allow(vec!["li*".to_string()]).includes("lint", false));
+        assert!(!deny.includes("codecov/patch", true));
+        assert!(deny.includes("lint", false));
+    }
+
+    #[test]
+    fn credential_source_reference_round_trips() {
+        for source in [
+            CredentialSource::Env("GITHUB_TOKEN".to_string()),
+            CredentialSource::Command("pass show github".to_string()),
+            CredentialSource::GhCli("github.com".to_string()),
+            CredentialSource::File("/home/me/.github-token".to_string()),
+            CredentialSource::GitHubApp {
+                app_id: "12345".to_string(),
+                installation_id: 678,
+                private_key_path: "/keys/app:prod.pem".to_string(),
+            },
+        ] {
+            assert_eq!(
+                CredentialSource::from_reference(&source.reference(), None).expect("parses"),
+                source
+            );
+        }
+
+        let stored = CredentialSource::Stored("secret".to_string());
+        assert_eq!(stored.reference(), "stored");
+        assert_eq!(
+            CredentialSource::from_reference("stored", Some("secret".to_string())).expect("parses"),
+            stored
+        );
+        assert!(CredentialSource::from_reference("stored", None).is_err());
+        assert!(CredentialSource::from_reference("vault:x", None).is_err());
+    }
 }
@@ -1,12 +1,14 @@
 use std::collections::HashSet;
 
-use crate::models::PullRequest;
+use crate::models::{Involvement, PrSource, PullRequest};
 use crate::scoring;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PrOwnerFilter {
     Tracked,
     Mine,
+    /// Other people's PRs found by the review inbox searches.
+    Inbox,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -34,6 +36,8 @@ pub struct PrDashboard {
     pub active_mine: Vec<usize>,
     pub acknowledged_tracked: Vec<usize>,
     pub acknowledged_mine: Vec<usize>,
+    pub active_inbox: Vec<usize>,
+    pub acknowledged_inbox: Vec<usize>,
 }
 
 impl PrDashboard {
@@ -43,6 +47,8 @@ impl PrDashboard {
             (PrOwnerFilter::Mine, PrStatusFilter::Active) => &self.active_mine,
             (PrOwnerFilter::Tracked, PrStatusFilter::Acknowledged) => &self.acknowledged_tracked,
             (PrOwnerFilter::Mine, PrStatusFilter::Acknowledged) => &self.acknowledged_mine,
+            (PrOwnerFilter::Inbox, PrStatusFilter::Active) => &self.active_inbox,
+            (PrOwnerFilter::Inbox, PrStatusFilter::Acknowledged) => &self.acknowledged_inbox,
         }
     }
 }
@@ -80,6 +86,22 @@ pub fn build_pr_dashboard(prs: Vec<PullRequest>, username: &str) -> PrDashboard
             status: PrStatusFilter::Acknowledged,
         },
     );
+    let active_inbox = filtered_pr_indices(
+        &prs,
+        username,
+        PrListQuery {
+            owner: PrOwnerFilter::Inbox,
+            status: PrStatusFilter::Active,
+        },
+    );
+    let acknowledged_inbox = filtered_pr_indices(
+        &prs,
+        username,
+        PrListQuery {
+            owner: PrOwnerFilter::Inbox,
+            status: PrStatusFilter::Acknowledged,
+        },
+    );
 
     PrDashboard {
         prs,
@@ -87,6 +109,8 @@ pub fn build_pr_dashboard(prs: Vec<PullRequest>, username: &str) -> PrDashboard
         active_mine,
         acknowledged_tracked,
         acknowledged_mine,
+        active_inbox,
+        acknowledged_inbox,
     }
 }
 
@@ -154,8 +178,9 @@ fn matches_query(pr: &PullRequest, username: &str, query: PrListQuery) -> bool {
     };
 
     let matches_owner = match query.owner {
-        PrOwnerFilter::Tracked => !pr.is_mine(username),
+        PrOwnerFilter::Tracked => !pr.is_mine(username) && pr.source == PrSource::Tracked,
         PrOwnerFilter::Mine => pr.is_mine(username),
+        PrOwnerFilter::Inbox => !pr.is_mine(username) && pr.source == PrSource::ReviewInbox,
     };
 
     matches_status && matches_owner
@@ -163,8 +188,10 @@ fn matches_query(pr: &PullRequest, username: &str, query: PrListQuery) -> bool {
 
 fn list_attention_score(pr: &PullRequest, username: &str) -> i64 {
     let mut score = scoring::importance_score(pr, username);
-    if pr.user_is_involved(username) {
-        score += 100;
+    match pr.involvement(username) {
+        Some(Involvement::Author | Involvement::ReviewRequested) => score += 100,
+        Some(Involvement::TeamReviewRequested) => score += 50,
+        None => {}
     }
     score
 }
@@ -193,8 +220,15 @@ mod tests {
             last_review_status_update_at: DateTime::UNIX_EPOCH,
             last_acknowledged_at: None,
             requested_reviewers: Vec::new(),
+            requested_teams: Vec::new(),
             user_has_reviewed: false,
+            team_review_requested: false,
             comments: Vec::new(),
+            checks: Vec::new(),
+            review_threads: Vec::new(),
+            last_thread_update_at: DateTime::UNIX_EPOCH,
+            comments_truncated: false,
+            source: PrSource::Tracked,
         }
     }
 
@@ -323,8 +357,22 @@ mod tests {
         let mut tracked_ack = pr_with_author(4, "carol");
         tracked_ack.last_acknowledged_at = Some(DateTime::UNIX_EPOCH);
 
+        let mut inbox_active = pr_with_author(5, "dave");
+        inbox_active.source = PrSource::ReviewInbox;
+
+        let mut inbox_ack = pr_with_author(6, "erin");
+        inbox_ack.source = PrSource::ReviewInbox;
+        inbox_ack.last_acknowledged_at = Some(DateTime::UNIX_EPOCH);
+
         let dashboard = build_pr_dashboard(
-            vec![mine_active, tracked_active, mine_ack, tracked_ack],
+            vec![
+                mine_active,
+                tracked_active,
+                mine_ack,
+                tracked_ack,
+                inbox_active,
+                inbox_ack,
+            ],
             "alice",
         );
 
@@ -332,5 +380,7 @@ mod tests {
         assert_eq!(dashboard.active_mine, vec![0]);
         assert_eq!(dashboard.acknowledged_tracked, vec![3]);
         assert_eq!(dashboard.acknowledged_mine, vec![2]);
+        assert_eq!(dashboard.active_inbox, vec![4]);
+        assert_eq!(dashboard.acknowledged_inbox, vec![5]);
     }
 }
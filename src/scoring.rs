@@ -1,4 +1,4 @@
-use crate::models::{ApprovalStatus, CiStatus, PrPerspective, PullRequest};
+use crate::models::{ApprovalStatus, CiStatus, Involvement, PrPerspective, PullRequest};
 
 /// Computes an importance score for a PR relative to the given user.
 ///
@@ -21,8 +21,14 @@ fn importance_score_for_my_pr(pr: &PullRequest) -> i64 {
 
     match pr.ci_status {
         CiStatus::Failure => score += 100,
-        CiStatus::Success => score += 50,
-        CiStatus::Pending => {}
+        // Usually waiting on someone else (e.g. approving a workflow run) or a
+        // re-run rather than on a fix from the author.
+        CiStatus::ActionRequired
+        | CiStatus::Cancelled
+        | CiStatus::Stale
+        | CiStatus::OptionalFailing => score += 75,
+        CiStatus::Success | CiStatus::Neutral | CiStatus::Skipped => score += 50,
+        CiStatus::Pending | CiStatus::Expected | CiStatus::NoChecks => {}
     }
 
     match pr.approval_status {
@@ -31,33 +37,44 @@ fn importance_score_for_my_pr(pr: &PullRequest) -> i64 {
         ApprovalStatus::None => {}
     }
 
+    if pr.has_mention_since(pr.last_acknowledged_at) {
+        score += 50;
+    }
+
     score
 }
 
 fn importance_score_for_tracked_pr(pr: &PullRequest, username: &str) -> i64 {
     let mut score: i64 = 0;
-    let is_requested_reviewer = !username.is_empty()
-        && pr
-            .requested_reviewers
-            .iter()
-            .any(|reviewer| reviewer.eq_ignore_ascii_case(username));
 
-    if is_requested_reviewer {
-        score += 500;
+    match pr.involvement(username) {
+        Some(Involvement::ReviewRequested) => score += 500,
+        // Someone else on the team may pick it up.
+        Some(Involvement::TeamReviewRequested) => score += 400,
+        Some(Involvement::Author) | None => {}
     }
 
     if pr.user_has_reviewed {
         score += 100;
     }
 
+    // Asked for by name, if not necessarily as a reviewer.
+    if pr.has_mention_since(pr.last_acknowledged_at) {
+        score += 300;
+    }
+
     if pr.draft {
         score -= 200;
     }
 
     match pr.ci_status {
         CiStatus::Failure => score -= 50,
-        CiStatus::Success => score += 50,
-        CiStatus::Pending => {}
+        // A reviewer can often unblock it, e.g. by approving a fork's workflow run.
+        CiStatus::ActionRequired => score += 100,
+        CiStatus::Cancelled | CiStatus::Stale => score -= 25,
+        CiStatus::OptionalFailing => score += 25,
+        CiStatus::Success | CiStatus::Neutral | CiStatus::Skipped => score += 50,
+        CiStatus::Pending | CiStatus::Expected | CiStatus::NoChecks => {}
     }
 
     match pr.approval_status {
@@ -72,7 +89,7 @@ fn importance_score_for_tracked_pr(pr: &PullRequest, username: &str) -> i64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{ApprovalStatus, CiStatus, PullRequest};
+    use crate::models::{ApprovalStatus, CiStatus, PrComment, PrSource, PullRequest};
     use chrono::DateTime;
 
     fn test_pr() -> PullRequest {
@@ -93,8 +110,15 @@ mod tests {
             last_review_status_update_at: DateTime::UNIX_EPOCH,
             last_acknowledged_at: None,
             requested_reviewers: Vec::new(),
+            requested_teams: Vec::new(),
             user_has_reviewed: false,
+            team_review_requested: false,
             comments: Vec::new(),
+            checks: Vec::new(),
+            review_threads: Vec::new(),
+            last_thread_update_at: DateTime::UNIX_EPOCH,
+            comments_truncated: false,
+            source: PrSource::Tracked,
         }
     }
 
@@ -131,6 +155,59 @@ mod tests {
         assert!(reviewer_score > unrelated_score);
     }
 
+    #[test]
+    fn team_review_request_scores_between_direct_request_and_unrelated() {
+        let mut unrelated = test_pr();
+        unrelated.author = "bob".to_string();
+
+        let mut team = unrelated.clone();
+        team.requested_teams = vec!["acme/platform".to_string()];
+        team.team_review_requested = true;
+
+        let mut direct = unrelated.clone();
+        direct.requested_reviewers = vec!["alice".to_string()];
+
+        let team_score = importance_score(&team, "alice");
+        assert!(importance_score(&direct, "alice") > team_score);
+        assert!(team_score > importance_score(&unrelated, "alice"));
+    }
+
+    #[test]
+    fn unacknowledged_mention_raises_score_until_acknowledged() {
+        let mut unrelated = test_pr();
+        unrelated.author = "bob".to_string();
+
+        let mut mentioned = test_pr();
+        mentioned.author = "bob".to_string();
+        mentioned.comments = vec![PrComment {
+            id: "c1".to_string(),
+            repository: "org/repo".to_string(),
+            pr_number: 1,
+            author: "bob".to_string(),
+            body: "@alice thoughts?".to_string(),
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+            is_review_comment: false,
+            review_state: None,
+            path: None,
+            line: None,
+            diff_hunk: None,
+            is_outdated: false,
+            mentions_user: true,
+            edited_at: None,
+            is_minimized: false,
+        }];
+
+        let mentioned_score = importance_score(&mentioned, "alice");
+        assert!(mentioned_score > importance_score(&unrelated, "alice"));
+
+        mentioned.last_acknowledged_at = Some(DateTime::UNIX_EPOCH);
+        assert_eq!(
+            importance_score(&mentioned, "alice"),
+            importance_score(&unrelated, "alice")
+        );
+    }
+
     #[test]
     fn author_scores_higher_than_requested_reviewer() {
         let mut pr_author = test_pr();
@@ -260,6 +337,41 @@ mod tests {
         assert!(s > p);
     }
 
+    #[test]
+    fn author_optional_failure_scores_between_failure_and_success() {
+        let mut pr = test_pr();
+        pr.author = "alice".to_string();
+        let mut score_for = |status| {
+            pr.ci_status = status;
+            importance_score(&pr, "alice")
+        };
+
+        let failure = score_for(CiStatus::Failure);
+        let optional = score_for(CiStatus::OptionalFailing);
+        let success = score_for(CiStatus::Success);
+
+        assert!(failure > optional);
+        assert!(optional > success);
+    }
+
+    #[test]
+    fn action_required_is_not_scored_as_a_failure() {
+        let mut mine = test_pr();
+        mine.author = "alice".to_string();
+        let mut tracked = test_pr();
+        tracked.author = "bob".to_string();
+
+        let score = |pr: &PullRequest, status| {
+            let mut pr = pr.clone();
+            pr.ci_status = status;
+            importance_score(&pr, "alice")
+        };
+
+        assert!(score(&mine, CiStatus::Failure) > score(&mine, CiStatus::ActionRequired));
+        assert!(score(&tracked, CiStatus::ActionRequired) > score(&tracked, CiStatus::Success));
+        assert!(score(&tracked, CiStatus::Success) > score(&tracked, CiStatus::Failure));
+    }
+
     #[test]
     fn author_ci_success_scores_above_pending() {
         let mut success = test_pr();
@@ -1,15 +1,21 @@
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 
-use crate::github::graphql;
 use crate::github::GitHubClient;
-use crate::models::{ApprovalStatus, CiStatus, PrComment, PullRequest};
+use crate::github::{graphql, normalize_repository_reference, schema};
+use crate::models::{
+    ApprovalStatus, CiPolicy, CiStatus, DiscoveredRepository, PrCheck, PrComment, PrReviewThread,
+    PrSource, PullRequest, RepositoryDiscoveryRule, TrackedRepository,
+};
 
 pub struct TrackedPullRequestSyncData {
     pub open_prs: Vec<PullRequest>,
     pub all_comments: Vec<PrComment>,
     pub closed_pr_numbers: Vec<i64>,
     pub max_updated_at: Option<DateTime<Utc>>,
+    /// Per open PR, the time after which every comment and review was
+    /// fetched; stored comments created later that were not returned are gone.
+    pub comments_complete_after: HashMap<i64, DateTime<Utc>>,
 }
 
 pub async fn fetch_tracked_pull_requests_for_sync(
@@ -18,6 +24,8 @@ pub async fn fetch_tracked_pull_requests_for_sync(
     authors_to_track: &[String],
     updated_after: Option<DateTime<Utc>>,
     username: &str,
+    ci_policy: &CiPolicy,
+    known_comments_until: &HashMap<i64, DateTime<Utc>>,
 ) -> anyhow::Result<TrackedPullRequestSyncData> {
     let discovery_prs = github
         .fetch_tracked_pull_requests_search(repo_name, authors_to_track, updated_after)
@@ -38,15 +46,22 @@ pub async fn fetch_tracked_pull_requests_for_sync(
         .map(|pr| pr.number)
         .collect();
 
-    let open_prs =
-        refresh_tracked_pull_requests_for_sync(github, repo_name, &open_pr_numbers, username)
-            .await?;
+    let open_prs = refresh_tracked_pull_requests_for_sync(
+        github,
+        repo_name,
+        &open_pr_numbers,
+        username,
+        ci_policy,
+        known_comments_until,
+    )
+    .await?;
 
     Ok(TrackedPullRequestSyncData {
         open_prs: open_prs.open_prs,
         all_comments: open_prs.all_comments,
         closed_pr_numbers,
         max_updated_at,
+        comments_complete_after: open_prs.comments_complete_after,
     })
 }
 
@@ -55,12 +70,69 @@ pub async fn refresh_tracked_pull_requests_for_sync(
     repo_name: &str,
     pr_numbers: &[i64],
     username: &str,
+    ci_policy: &CiPolicy,
+    known_comments_until: &HashMap<i64, DateTime<Utc>>,
 ) -> anyhow::Result<TrackedPullRequestSyncData> {
     let prs = github
-        .fetch_pull_requests_by_numbers(repo_name, pr_numbers)
+        .fetch_pull_requests_by_numbers(repo_name, pr_numbers, known_comments_until)
         .await?;
 
-    process_tracked_pull_request_nodes(repo_name, &prs, username)
+    process_tracked_pull_request_nodes(repo_name, &prs, username, ci_policy)
+}
+
+/// Resolve `reference` (`owner/repo` or a GitHub URL) to the repository it
+/// names, under its canonical name, failing when `github` cannot see it or
+/// it is already among `tracked`, possibly under an older name.
+pub async fn resolve_repository_to_track(
+    github: &GitHubClient,
+    reference: &str,
+    tracked: &[TrackedRepository],
+) -> anyhow::Result<schema::Repository> {
+    let requested = normalize_repository_reference(reference)?;
+    let found = github.fetch_repository(&requested).await?;
+    if let Some(existing) = tracked.iter().find(|tracked_repo| {
+        tracked_repo
+            .repository
+            .eq_ignore_ascii_case(&found.full_name)
+            || (found.node_id.is_some() && tracked_repo.node_id == found.node_id)
+    }) {
+        anyhow::bail!("repository '{}' is already tracked", existing.repository);
+    }
+    Ok(found)
+}
+
+/// Repositories of `rule.org`, or of its team when the rule names one, that
+/// pass the rule's filters, sorted by name.
+pub async fn discover_repositories(
+    github: &GitHubClient,
+    rule: &RepositoryDiscoveryRule,
+) -> anyhow::Result<Vec<DiscoveredRepository>> {
+    let repositories = match &rule.team {
+        Some(team) => github.fetch_team_repositories(&rule.org, team).await?,
+        None => github.fetch_org_repositories(&rule.org).await?,
+    };
+
+    let mut discovered = Vec::with_capacity(repositories.len());
+    for repository in repositories {
+        let repository = DiscoveredRepository {
+            created_at: parse_github_timestamp(&repository.created_at)?,
+            full_name: repository.full_name,
+            node_id: repository.node_id,
+            archived: repository.archived,
+            topics: repository.topics,
+        };
+        // A team can have access to repositories of other organizations.
+        let in_org = repository
+            .full_name
+            .split_once('/')
+            .is_some_and(|(owner, _)| owner.eq_ignore_ascii_case(&rule.org));
+        if in_org && rule.matches(&repository) {
+            discovered.push(repository);
+        }
+    }
+    discovered.sort_by_key(|repository| repository.full_name.to_lowercase());
+
+    Ok(discovered)
 }
 
 pub fn merge_tracked_pull_request_sync_data(
@@ -91,11 +163,22 @@ pub fn merge_tracked_pull_request_sync_data(
     let mut all_comments: Vec<PrComment> = comments_by_id.into_values().collect();
     all_comments.sort_by(|left, right| left.id.cmp(&right.id));
 
+    // Refreshed PRs the discovery search cannot see, such as review inbox
+    // PRs by untracked authors, are only found closed by the refresh.
+    let mut closed_pr_numbers = discovery.closed_pr_numbers;
+    closed_pr_numbers.extend(refresh.closed_pr_numbers);
+    closed_pr_numbers.sort_unstable();
+    closed_pr_numbers.dedup();
+
+    let mut comments_complete_after = discovery.comments_complete_after;
+    comments_complete_after.extend(refresh.comments_complete_after);
+
     TrackedPullRequestSyncData {
         open_prs,
         all_comments,
-        closed_pr_numbers: discovery.closed_pr_numbers,
+        closed_pr_numbers,
         max_updated_at: discovery.max_updated_at,
+        comments_complete_after,
     }
 }
 
@@ -103,11 +186,13 @@ fn process_tracked_pull_request_nodes(
     repo_name: &str,
     prs: &[graphql::PullRequestNode],
     username: &str,
+    ci_policy: &CiPolicy,
 ) -> anyhow::Result<TrackedPullRequestSyncData> {
     let mut open_prs = Vec::new();
     let mut all_comments = Vec::new();
     let mut closed_pr_numbers = Vec::new();
     let mut max_updated_at = None;
+    let mut comments_complete_after = HashMap::new();
 
     for pr in prs {
         let updated_at = parse_github_timestamp(&pr.updated_at)?;
@@ -116,9 +201,10 @@ fn process_tracked_pull_request_nodes(
         );
 
         if pr.state == "OPEN" {
-            let pr_model = graphql_pr_to_model(repo_name, pr, username)?;
+            let pr_model = graphql_pr_to_model(repo_name, pr, username, ci_policy)?;
             all_comments.extend(pr_model.comments.clone());
             open_prs.push(pr_model);
+            comments_complete_after.insert(pr.number, comments_complete_after_for(pr));
         } else {
             closed_pr_numbers.push(pr.number);
         }
@@ -129,13 +215,38 @@ fn process_tracked_pull_request_nodes(
         all_comments,
         closed_pr_numbers,
         max_updated_at,
+        comments_complete_after,
     })
 }
 
+/// The time after which `pr`'s fetched comments and reviews are complete:
+/// the oldest fetched one of whichever connection still had older pages, or
+/// the start of time when both were read in full.
+fn comments_complete_after_for(pr: &graphql::PullRequestNode) -> DateTime<Utc> {
+    let window_start = |has_previous_page: bool, oldest: Option<&str>| {
+        if !has_previous_page {
+            return DateTime::<Utc>::MIN_UTC;
+        }
+        oldest
+            .and_then(|created_at| parse_github_timestamp(created_at).ok())
+            .unwrap_or(DateTime::<Utc>::MAX_UTC)
+    };
+    let comments = window_start(
+        pr.comments.page_info.has_previous_page,
+        pr.comments.nodes.first().map(|c| c.created_at.as_str()),
+    );
+    let reviews = window_start(
+        pr.reviews.page_info.has_previous_page,
+        pr.reviews.nodes.first().map(|r| r.created_at.as_str()),
+    );
+    comments.max(reviews)
+}
+
 fn graphql_pr_to_model(
     repo_name: &str,
     pr: &graphql::PullRequestNode,
     username: &str,
+    ci_policy: &CiPolicy,
 ) -> anyhow::Result<PullRequest> {
     let created_at = parse_github_timestamp(&pr.created_at)?;
     let updated_at = parse_github_timestamp(&pr.updated_at)?;
@@ -146,6 +257,12 @@ fn graphql_pr_to_model(
         .iter()
         .filter_map(|rr| rr.requested_reviewer.as_ref()?.login.clone())
         .collect();
+    let requested_teams = pr
+        .review_requests
+        .nodes
+        .iter()
+        .filter_map(|rr| rr.requested_reviewer.as_ref()?.combined_slug.clone())
+        .collect();
 
     let user_has_reviewed = !username.is_empty()
         && pr.latest_reviews.nodes.iter().any(|review| {
@@ -170,7 +287,7 @@ fn graphql_pr_to_model(
         draft: pr.is_draft,
         created_at,
         updated_at,
-        ci_status: map_ci_status(pr),
+        ci_status: map_ci_status(pr, ci_policy),
         last_comment_at: latest_comment_time(pr),
         last_commit_at: DateTime::UNIX_EPOCH,
         last_ci_status_update_at: DateTime::UNIX_EPOCH,
@@ -180,62 +297,190 @@ fn graphql_pr_to_model(
         requested_reviewers,
         user_has_reviewed,
         comments,
+        checks: map_checks(pr, ci_policy),
+        review_threads: map_review_threads(pr),
+        last_thread_update_at: DateTime::UNIX_EPOCH,
+        comments_truncated: pr.comments_truncated,
+        requested_teams,
+        // Depends on the user's team memberships, which sync fills in.
+        team_review_requested: false,
+        // Sync retags PRs that only the review inbox searches found.
+        source: PrSource::Tracked,
     })
 }
 
-fn map_ci_status(pr: &graphql::PullRequestNode) -> CiStatus {
-    let rollup = pr
-        .commits
+fn status_check_rollup(pr: &graphql::PullRequestNode) -> Option<&graphql::StatusCheckRollup> {
+    pr.commits
         .nodes
         .first()
-        .and_then(|c| c.commit.status_check_rollup.as_ref());
+        .and_then(|c| c.commit.status_check_rollup.as_ref())
+}
 
-    rollup.map_or(CiStatus::Success, map_rollup_ci_status)
+fn map_ci_status(pr: &graphql::PullRequestNode, ci_policy: &CiPolicy) -> CiStatus {
+    status_check_rollup(pr).map_or(CiStatus::NoChecks, |rollup| {
+        map_rollup_ci_status(rollup, ci_policy)
+    })
 }
 
-fn map_rollup_ci_status(rollup: &graphql::StatusCheckRollup) -> CiStatus {
-    let mut saw_required = false;
-    let mut saw_pending = false;
+fn map_checks(pr: &graphql::PullRequestNode, ci_policy: &CiPolicy) -> Vec<PrCheck> {
+    status_check_rollup(pr)
+        .map(|rollup| {
+            rollup
+                .contexts
+                .nodes
+                .iter()
+                .map(|context| map_pr_check(context, ci_policy))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn map_review_threads(pr: &graphql::PullRequestNode) -> Vec<PrReviewThread> {
+    pr.review_threads
+        .nodes
+        .iter()
+        .map(|thread| {
+            let first_comment = thread.comments.nodes.first();
+            PrReviewThread {
+                id: thread.id.clone(),
+                path: thread.path.clone(),
+                line: thread.line,
+                is_resolved: thread.is_resolved,
+                is_outdated: thread.is_outdated,
+                author: first_comment
+                    .and_then(|comment| comment.author.as_ref())
+                    .map(|author| author.login.clone())
+                    .unwrap_or_default(),
+                body: first_comment
+                    .map(|comment| comment.body.clone())
+                    .unwrap_or_default(),
+                comment_count: thread.comments.total_count,
+            }
+        })
+        .collect()
+}
+
+/// Commit statuses have no separate status/conclusion, so a settled state is
+/// recorded as a `COMPLETED` check with the state as its conclusion.
+fn map_pr_check(context: &graphql::StatusCheckRollupContext, ci_policy: &CiPolicy) -> PrCheck {
+    let (check_name, check_is_required, ci_status) = map_status_check_rollup_context(context);
+    let counts_towards_ci = ci_policy.includes(check_name, check_is_required);
+    match context {
+        graphql::StatusCheckRollupContext::CheckRun {
+            name,
+            check_suite,
+            status,
+            conclusion,
+            is_required,
+            details_url,
+            started_at,
+            completed_at,
+        } => PrCheck {
+            workflow: check_suite
+                .as_ref()
+                .and_then(graphql::CheckSuiteNode::workflow_name)
+                .unwrap_or_default()
+                .to_string(),
+            name: name.clone(),
+            status: status.clone(),
+            conclusion: conclusion.clone(),
+            ci_status,
+            is_required: *is_required,
+            counts_towards_ci,
+            details_url: details_url.clone(),
+            started_at: parse_optional_timestamp(started_at.as_deref()),
+            completed_at: parse_optional_timestamp(completed_at.as_deref()),
+        },
+        graphql::StatusCheckRollupContext::StatusContext {
+            context,
+            state,
+            target_url,
+            created_at,
+            is_required,
+        } => {
+            let settled = !matches!(state.as_str(), "PENDING" | "EXPECTED");
+            PrCheck {
+                workflow: String::new(),
+                name: context.clone(),
+                status: if settled { "COMPLETED" } else { "PENDING" }.to_string(),
+                conclusion: settled.then(|| state.clone()),
+                ci_status,
+                is_required: *is_required,
+                counts_towards_ci,
+                details_url: target_url.clone(),
+                started_at: parse_optional_timestamp(created_at.as_deref()),
+                completed_at: None,
+            }
+        }
+    }
+}
+
+/// Fold a rollup into one status. Only checks the policy includes can make
+/// it fail or stay pending; failures of the others are reported separately.
+fn map_rollup_ci_status(rollup: &graphql::StatusCheckRollup, ci_policy: &CiPolicy) -> CiStatus {
+    if rollup.contexts.nodes.is_empty() {
+        return CiStatus::NoChecks;
+    }
+
+    let mut worst: Option<CiStatus> = None;
+    let mut saw_excluded_failure = false;
 
     for context in &rollup.contexts.nodes {
-        let (is_required, status) = map_status_check_rollup_context(context);
-        if !is_required {
+        let (name, is_required, status) = map_status_check_rollup_context(context);
+        if !ci_policy.includes(name, is_required) {
+            saw_excluded_failure |= status.is_failing();
             continue;
         }
+        if worst.is_none_or(|current| rollup_severity(status) > rollup_severity(current)) {
+            worst = Some(status);
+        }
+    }
 
-        saw_required = true;
-        match status {
-            CiStatus::Failure => return CiStatus::Failure,
-            CiStatus::Pending => saw_pending = true,
-            CiStatus::Success => {}
+    match worst.unwrap_or(CiStatus::Success) {
+        CiStatus::Success | CiStatus::Neutral | CiStatus::Skipped if saw_excluded_failure => {
+            CiStatus::OptionalFailing
         }
+        status => status,
     }
+}
 
-    if !saw_required {
-        CiStatus::Success
-    } else if saw_pending {
-        CiStatus::Pending
-    } else {
-        CiStatus::Success
+/// Which counted check decides the PR's overall CI status: a real failure
+/// outranks checks that need attention, which outrank ones still running.
+fn rollup_severity(status: CiStatus) -> u8 {
+    match status {
+        CiStatus::Failure => 8,
+        CiStatus::ActionRequired => 7,
+        CiStatus::Cancelled => 6,
+        CiStatus::Stale => 5,
+        CiStatus::Pending => 4,
+        CiStatus::Expected => 3,
+        CiStatus::Success => 2,
+        CiStatus::Neutral => 1,
+        CiStatus::Skipped | CiStatus::NoChecks | CiStatus::OptionalFailing => 0,
     }
 }
 
 fn map_status_check_rollup_context(
     context: &graphql::StatusCheckRollupContext,
-) -> (bool, CiStatus) {
+) -> (&str, bool, CiStatus) {
     match context {
         graphql::StatusCheckRollupContext::CheckRun {
+            name,
             status,
             conclusion,
             is_required,
             ..
         } => (
+            name,
             *is_required,
             map_check_run_status(status, conclusion.as_deref()),
         ),
         graphql::StatusCheckRollupContext::StatusContext {
-            state, is_required, ..
-        } => (*is_required, map_status_context_state(state)),
+            context,
+            state,
+            is_required,
+            ..
+        } => (context, *is_required, map_status_context_state(state)),
     }
 }
 
@@ -245,10 +490,13 @@ fn map_check_run_status(status: &str, conclusion: Option<&str>) -> CiStatus {
     }
 
     match conclusion {
-        Some("SUCCESS" | "NEUTRAL" | "SKIPPED") => CiStatus::Success,
-        Some(
-            "ACTION_REQUIRED" | "TIMED_OUT" | "CANCELLED" | "FAILURE" | "STARTUP_FAILURE" | "STALE",
-        ) => CiStatus::Failure,
+        Some("SUCCESS") => CiStatus::Success,
+        Some("NEUTRAL") => CiStatus::Neutral,
+        Some("SKIPPED") => CiStatus::Skipped,
+        Some("CANCELLED") => CiStatus::Cancelled,
+        Some("STALE") => CiStatus::Stale,
+        Some("ACTION_REQUIRED") => CiStatus::ActionRequired,
+        Some("TIMED_OUT" | "FAILURE" | "STARTUP_FAILURE") => CiStatus::Failure,
         _ => CiStatus::Pending,
     }
 }
@@ -257,6 +505,7 @@ fn map_status_context_state(state: &str) -> CiStatus {
     match state {
         "SUCCESS" => CiStatus::Success,
         "FAILURE" | "ERROR" => CiStatus::Failure,
+        "EXPECTED" => CiStatus::Expected,
         _ => CiStatus::Pending,
     }
 }
@@ -287,11 +536,12 @@ fn latest_review_submitted_at(pr: &graphql::PullRequestNode) -> DateTime<Utc> {
 }
 
 fn latest_comment_time(pr: &graphql::PullRequestNode) -> DateTime<Utc> {
+    // Comments hidden on GitHub no longer count as activity.
     let comment_times = pr
         .comments
         .nodes
         .iter()
-        .filter(|c| !is_bot_author(&c.author))
+        .filter(|c| !is_bot_author(&c.author) && !c.is_minimized)
         .filter_map(|c| parse_optional_timestamp(Some(&c.updated_at)));
 
     let review_times = pr
@@ -301,8 +551,13 @@ fn latest_comment_time(pr: &graphql::PullRequestNode) -> DateTime<Utc> {
         .filter(|r| !is_bot_author(&r.author))
         .filter_map(|r| parse_optional_timestamp(Some(&r.updated_at)));
 
+    let inline_times = inline_review_comments(pr)
+        .filter(|c| !c.is_minimized)
+        .filter_map(|c| parse_optional_timestamp(Some(&c.updated_at)));
+
     comment_times
         .chain(review_times)
+        .chain(inline_times)
         .max()
         .unwrap_or(DateTime::UNIX_EPOCH)
 }
@@ -344,6 +599,13 @@ fn map_comments_from_pr(repo_name: &str, pr: &graphql::PullRequestNode) -> Vec<P
             updated_at,
             is_review_comment: false,
             review_state: None,
+            path: None,
+            line: None,
+            diff_hunk: None,
+            is_outdated: false,
+            mentions_user: false,
+            edited_at: parse_optional_timestamp(comment.last_edited_at.as_deref()),
+            is_minimized: comment.is_minimized,
         });
     }
 
@@ -372,12 +634,63 @@ fn map_comments_from_pr(repo_name: &str, pr: &graphql::PullRequestNode) -> Vec<P
             updated_at,
             is_review_comment: true,
             review_state: Some(review.state.clone()),
+            path: None,
+            line: None,
+            diff_hunk: None,
+            is_outdated: false,
+            mentions_user: false,
+            edited_at: parse_optional_timestamp(review.last_edited_at.as_deref()),
+            // Reviews cannot be hidden, only their comments.
+            is_minimized: false,
+        });
+    }
+
+    for comment in inline_review_comments(pr) {
+        let author = comment
+            .author
+            .as_ref()
+            .map(|a| a.login.clone())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        let created_at =
+            parse_github_timestamp(&comment.created_at).unwrap_or(DateTime::UNIX_EPOCH);
+        let updated_at =
+            parse_github_timestamp(&comment.updated_at).unwrap_or(DateTime::UNIX_EPOCH);
+
+        comments.push(PrComment {
+            id: comment.id.clone(),
+            repository: repo_name.to_string(),
+            pr_number: pr.number,
+            author,
+            body: comment.body.clone(),
+            created_at,
+            updated_at,
+            is_review_comment: false,
+            review_state: None,
+            path: Some(comment.path.clone()),
+            line: comment.line,
+            diff_hunk: Some(comment.diff_hunk.clone()),
+            is_outdated: comment.outdated,
+            mentions_user: false,
+            edited_at: parse_optional_timestamp(comment.last_edited_at.as_deref()),
+            is_minimized: comment.is_minimized,
         });
     }
 
     comments
 }
 
+/// Inline comments of every review, skipping those left by bots.
+fn inline_review_comments(
+    pr: &graphql::PullRequestNode,
+) -> impl Iterator<Item = &graphql::ReviewCommentNode> {
+    pr.reviews
+        .nodes
+        .iter()
+        .flat_map(|review| review.comments.nodes.iter())
+        .filter(|comment| !is_bot_author(&comment.author))
+}
+
 fn parse_github_timestamp(value: &str) -> anyhow::Result<DateTime<Utc>> {
     if value.is_empty() {
         return Ok(DateTime::UNIX_EPOCH);
@@ -394,10 +707,13 @@ fn parse_optional_timestamp(value: Option<&str>) -> Option<DateTime<Utc>> {
 mod tests {
     use super::*;
     use crate::github::graphql::{
-        Author, CommentConnection, CommentNode, CommitConnection, CommitDetail, CommitNode,
-        LatestReviewConnection, LatestReviewNode, PullRequestNode, RequestedReviewer,
-        ReviewConnection, ReviewNode, ReviewRequestConnection, ReviewRequestNode,
-        StatusCheckRollup, StatusCheckRollupContext, StatusCheckRollupContextConnection,
+        Author, BackwardPageInfo, CheckSuiteApp, CheckSuiteNode, CheckSuiteWorkflow,
+        CheckSuiteWorkflowRun, CommentConnection, CommentNode, CommitConnection, CommitDetail,
+        CommitNode, LatestReviewConnection, LatestReviewNode, PullRequestNode, RequestedReviewer,
+        ReviewCommentConnection, ReviewCommentNode, ReviewConnection, ReviewNode,
+        ReviewRequestConnection, ReviewRequestNode, ReviewThreadCommentConnection,
+        ReviewThreadCommentNode, ReviewThreadConnection, ReviewThreadNode, StatusCheckRollup,
+        StatusCheckRollupContext, StatusCheckRollupContextConnection,
     };
 
     fn test_pr(
@@ -424,14 +740,47 @@ mod tests {
                     commit: CommitDetail {
                         status_check_rollup: ci_state.map(|state| StatusCheckRollup {
                             state: state.to_string(),
-                            contexts: StatusCheckRollupContextConnection::default(),
+                            contexts: StatusCheckRollupContextConnection {
+                                nodes: vec![check_run("build", Some(state), true)],
+                            },
                         }),
                     },
                 }],
             },
-            comments: CommentConnection { nodes: vec![] },
-            reviews: ReviewConnection { nodes: vec![] },
+            comments: CommentConnection::default(),
+            reviews: ReviewConnection::default(),
             latest_reviews: LatestReviewConnection { nodes: vec![] },
+            review_threads: ReviewThreadConnection::default(),
+            comments_truncated: false,
+        }
+    }
+
+    fn check_run(
+        name: &str,
+        conclusion: Option<&str>,
+        is_required: bool,
+    ) -> StatusCheckRollupContext {
+        StatusCheckRollupContext::CheckRun {
+            name: name.to_string(),
+            check_suite: None,
+            status: if conclusion.is_some() {
+                "COMPLETED"
+            } else {
+                "IN_PROGRESS"
+            }
+            .to_string(),
+            conclusion: conclusion.map(str::to_string),
+            is_required,
+            details_url: None,
+            started_at: None,
+            completed_at: None,
+        }
+    }
+
+    fn rollup(nodes: Vec<StatusCheckRollupContext>) -> StatusCheckRollup {
+        StatusCheckRollup {
+            state: "PENDING".to_string(),
+            contexts: StatusCheckRollupContextConnection { nodes },
         }
     }
 
@@ -461,14 +810,21 @@ mod tests {
             contexts: StatusCheckRollupContextConnection {
                 nodes: vec![StatusCheckRollupContext::CheckRun {
                     name: "build".to_string(),
+                    check_suite: None,
                     status: "COMPLETED".to_string(),
                     conclusion: Some("SUCCESS".to_string()),
                     is_required: true,
+                    details_url: None,
+                    started_at: None,
+                    completed_at: None,
                 }],
             },
         };
 
-        assert_eq!(map_rollup_ci_status(&rollup), CiStatus::Success);
+        assert_eq!(
+            map_rollup_ci_status(&rollup, &CiPolicy::RequiredOnly),
+            CiStatus::Success
+        );
     }
 
     #[test]
@@ -478,14 +834,21 @@ mod tests {
             contexts: StatusCheckRollupContextConnection {
                 nodes: vec![StatusCheckRollupContext::CheckRun {
                     name: "build".to_string(),
+                    check_suite: None,
                     status: "COMPLETED".to_string(),
                     conclusion: Some("FAILURE".to_string()),
                     is_required: true,
+                    details_url: None,
+                    started_at: None,
+                    completed_at: None,
                 }],
             },
         };
 
-        assert_eq!(map_rollup_ci_status(&rollup), CiStatus::Failure);
+        assert_eq!(
+            map_rollup_ci_status(&rollup, &CiPolicy::RequiredOnly),
+            CiStatus::Failure
+        );
     }
 
     #[test]
@@ -495,41 +858,137 @@ mod tests {
             contexts: StatusCheckRollupContextConnection {
                 nodes: vec![StatusCheckRollupContext::CheckRun {
                     name: "build".to_string(),
+                    check_suite: None,
                     status: "IN_PROGRESS".to_string(),
                     conclusion: None,
                     is_required: true,
+                    details_url: None,
+                    started_at: None,
+                    completed_at: None,
                 }],
             },
         };
 
-        assert_eq!(map_rollup_ci_status(&rollup), CiStatus::Pending);
+        assert_eq!(
+            map_rollup_ci_status(&rollup, &CiPolicy::RequiredOnly),
+            CiStatus::Pending
+        );
     }
 
     #[test]
-    fn map_rollup_ci_status_ignores_optional_failures() {
-        let rollup = StatusCheckRollup {
-            state: "FAILURE".to_string(),
-            contexts: StatusCheckRollupContextConnection {
-                nodes: vec![StatusCheckRollupContext::CheckRun {
-                    name: "optional".to_string(),
-                    status: "COMPLETED".to_string(),
-                    conclusion: Some("FAILURE".to_string()),
-                    is_required: false,
-                }],
-            },
-        };
+    fn map_rollup_ci_status_reports_optional_failures_separately() {
+        let rollup = rollup(vec![
+            check_run("build", Some("SUCCESS"), true),
+            check_run("optional", Some("FAILURE"), false),
+        ]);
 
-        assert_eq!(map_rollup_ci_status(&rollup), CiStatus::Success);
+        assert_eq!(
+            map_rollup_ci_status(&rollup, &CiPolicy::RequiredOnly),
+            CiStatus::OptionalFailing
+        );
+    }
+
+    #[test]
+    fn map_rollup_ci_status_returns_no_checks_for_empty_rollup() {
+        assert_eq!(
+            map_rollup_ci_status(&rollup(vec![]), &CiPolicy::RequiredOnly),
+            CiStatus::NoChecks
+        );
     }
 
     #[test]
     fn map_rollup_ci_status_returns_success_when_no_required_checks_exist() {
-        let rollup = StatusCheckRollup {
-            state: "PENDING".to_string(),
-            contexts: StatusCheckRollupContextConnection { nodes: vec![] },
-        };
+        let rollup = rollup(vec![check_run("lint", Some("SUCCESS"), false)]);
+
+        assert_eq!(
+            map_rollup_ci_status(&rollup, &CiPolicy::RequiredOnly),
+            CiStatus::Success
+        );
+    }
+
+    #[test]
+    fn map_rollup_ci_status_applies_policy() {
+        let rollup = rollup(vec![
+            check_run("lint", Some("FAILURE"), false),
+            check_run("codecov/patch", None, false),
+        ]);
+
+        assert_eq!(
+            map_rollup_ci_status(&rollup, &CiPolicy::All),
+            CiStatus::Failure
+        );
+        assert_eq!(
+            map_rollup_ci_status(&rollup, &CiPolicy::Deny(vec!["lint".to_string()])),
+            CiStatus::Pending
+        );
+        assert_eq!(
+            map_rollup_ci_status(&rollup, &CiPolicy::Allow(vec!["codecov/*".to_string()])),
+            CiStatus::Pending
+        );
+        assert_eq!(
+            map_rollup_ci_status(&rollup, &CiPolicy::Deny(vec!["*".to_string()])),
+            CiStatus::OptionalFailing
+        );
+    }
 
-        assert_eq!(map_rollup_ci_status(&rollup), CiStatus::Success);
+    #[test]
+    fn map_check_run_status_keeps_each_conclusion() {
+        assert_eq!(
+            map_check_run_status("COMPLETED", Some("NEUTRAL")),
+            CiStatus::Neutral
+        );
+        assert_eq!(
+            map_check_run_status("COMPLETED", Some("SKIPPED")),
+            CiStatus::Skipped
+        );
+        assert_eq!(
+            map_check_run_status("COMPLETED", Some("CANCELLED")),
+            CiStatus::Cancelled
+        );
+        assert_eq!(
+            map_check_run_status("COMPLETED", Some("ACTION_REQUIRED")),
+            CiStatus::ActionRequired
+        );
+        assert_eq!(
+            map_check_run_status("COMPLETED", Some("TIMED_OUT")),
+            CiStatus::Failure
+        );
+        assert_eq!(map_status_context_state("EXPECTED"), CiStatus::Expected);
+    }
+
+    #[test]
+    fn map_rollup_ci_status_ranks_failure_above_action_required() {
+        let rollup = rollup(vec![
+            check_run("deploy", Some("ACTION_REQUIRED"), true),
+            check_run("build", Some("FAILURE"), true),
+            check_run("docs", Some("SKIPPED"), true),
+        ]);
+
+        assert_eq!(
+            map_rollup_ci_status(&rollup, &CiPolicy::RequiredOnly),
+            CiStatus::Failure
+        );
+    }
+
+    #[test]
+    fn map_rollup_ci_status_does_not_treat_cancelled_or_skipped_as_failure() {
+        let cancelled = rollup(vec![
+            check_run("build", Some("SUCCESS"), true),
+            check_run("e2e", Some("CANCELLED"), true),
+        ]);
+        let skipped = rollup(vec![
+            check_run("build", Some("NEUTRAL"), true),
+            check_run("docs", Some("SKIPPED"), true),
+        ]);
+
+        assert_eq!(
+            map_rollup_ci_status(&cancelled, &CiPolicy::RequiredOnly),
+            CiStatus::Cancelled
+        );
+        assert_eq!(
+            map_rollup_ci_status(&skipped, &CiPolicy::RequiredOnly),
+            CiStatus::Neutral
+        );
     }
 
     #[test]
@@ -540,8 +999,13 @@ mod tests {
             test_pr(3, "CLOSED", "2025-06-14T00:00:00Z", None),
         ];
 
-        let result = process_tracked_pull_request_nodes("owner/repo", &prs, "alice")
-            .expect("processing succeeds");
+        let result = process_tracked_pull_request_nodes(
+            "owner/repo",
+            &prs,
+            "alice",
+            &CiPolicy::RequiredOnly,
+        )
+        .expect("processing succeeds");
 
         assert_eq!(result.open_prs.len(), 1);
         assert_eq!(result.open_prs[0].number, 1);
@@ -556,6 +1020,7 @@ mod tests {
     fn process_tracked_pull_request_nodes_collects_comments() {
         let mut pr = test_pr(1, "OPEN", "2025-06-15T00:00:00Z", Some("SUCCESS"));
         pr.comments = CommentConnection {
+            page_info: BackwardPageInfo::default(),
             nodes: vec![CommentNode {
                 id: "comment-1".to_string(),
                 author: Some(Author {
@@ -565,9 +1030,12 @@ mod tests {
                 body: "hello".to_string(),
                 created_at: "2025-06-15T00:00:00Z".to_string(),
                 updated_at: "2025-06-15T00:01:00Z".to_string(),
+                is_minimized: false,
+                last_edited_at: None,
             }],
         };
         pr.reviews = ReviewConnection {
+            page_info: BackwardPageInfo::default(),
             nodes: vec![ReviewNode {
                 id: "review-1".to_string(),
                 author: Some(Author {
@@ -579,16 +1047,45 @@ mod tests {
                 updated_at: "2025-06-15T00:03:00Z".to_string(),
                 state: "APPROVED".to_string(),
                 submitted_at: Some("2025-06-15T00:03:00Z".to_string()),
+                last_edited_at: None,
+                comments: ReviewCommentConnection {
+                    nodes: vec![ReviewCommentNode {
+                        id: "review-comment-1".to_string(),
+                        author: Some(Author {
+                            login: "bob".to_string(),
+                            actor_type: None,
+                        }),
+                        body: "nit: rename".to_string(),
+                        created_at: "2025-06-15T00:02:00Z".to_string(),
+                        updated_at: "2025-06-15T00:04:00Z".to_string(),
+                        path: "src/lib.rs".to_string(),
+                        line: Some(12),
+                        diff_hunk: "@@ -10,3 +10,4 @@".to_string(),
+                        outdated: true,
+                        is_minimized: false,
+                        last_edited_at: None,
+                    }],
+                },
             }],
         };
 
-        let result = process_tracked_pull_request_nodes("owner/repo", &[pr], "alice")
-            .expect("processing succeeds");
+        let result = process_tracked_pull_request_nodes(
+            "owner/repo",
+            &[pr],
+            "alice",
+            &CiPolicy::RequiredOnly,
+        )
+        .expect("processing succeeds");
 
-        assert_eq!(result.all_comments.len(), 2);
+        assert_eq!(result.all_comments.len(), 3);
+        let inline = &result.all_comments[2];
+        assert_eq!(inline.path.as_deref(), Some("src/lib.rs"));
+        assert_eq!(inline.line, Some(12));
+        assert!(inline.is_outdated);
+        assert!(!inline.is_review_comment);
         assert_eq!(
             result.open_prs[0].last_comment_at,
-            parse_github_timestamp("2025-06-15T00:03:00Z").unwrap()
+            parse_github_timestamp("2025-06-15T00:04:00Z").unwrap()
         );
     }
 
@@ -596,6 +1093,7 @@ mod tests {
     fn process_tracked_pull_request_nodes_ignores_bot_comments() {
         let mut pr = test_pr(1, "OPEN", "2025-06-15T00:00:00Z", Some("SUCCESS"));
         pr.comments = CommentConnection {
+            page_info: BackwardPageInfo::default(),
             nodes: vec![
                 CommentNode {
                     id: "bot-comment".to_string(),
@@ -606,6 +1104,8 @@ mod tests {
                     body: "generated output".to_string(),
                     created_at: "2025-06-15T00:00:00Z".to_string(),
                     updated_at: "2025-06-15T00:10:00Z".to_string(),
+                    is_minimized: false,
+                    last_edited_at: None,
                 },
                 CommentNode {
                     id: "human-comment".to_string(),
@@ -616,10 +1116,13 @@ mod tests {
                     body: "real feedback".to_string(),
                     created_at: "2025-06-15T00:01:00Z".to_string(),
                     updated_at: "2025-06-15T00:02:00Z".to_string(),
+                    is_minimized: false,
+                    last_edited_at: None,
                 },
             ],
         };
         pr.reviews = ReviewConnection {
+            page_info: BackwardPageInfo::default(),
             nodes: vec![ReviewNode {
                 id: "bot-review".to_string(),
                 author: Some(Author {
@@ -631,11 +1134,18 @@ mod tests {
                 updated_at: "2025-06-15T00:11:00Z".to_string(),
                 state: "COMMENTED".to_string(),
                 submitted_at: Some("2025-06-15T00:11:00Z".to_string()),
+                last_edited_at: None,
+                comments: ReviewCommentConnection::default(),
             }],
         };
 
-        let result = process_tracked_pull_request_nodes("owner/repo", &[pr], "alice")
-            .expect("processing succeeds");
+        let result = process_tracked_pull_request_nodes(
+            "owner/repo",
+            &[pr],
+            "alice",
+            &CiPolicy::RequiredOnly,
+        )
+        .expect("processing succeeds");
 
         assert_eq!(result.all_comments.len(), 1);
         assert_eq!(result.all_comments[0].author, "juliehockey30");
@@ -656,11 +1166,20 @@ mod tests {
     fn graphql_pr_to_model_maps_requested_reviewers_and_user_reviewed() {
         let mut pr = test_pr_with_reviews(&["APPROVED"]);
         pr.review_requests = ReviewRequestConnection {
-            nodes: vec![ReviewRequestNode {
-                requested_reviewer: Some(RequestedReviewer {
-                    login: Some("carol".to_string()),
-                }),
-            }],
+            nodes: vec![
+                ReviewRequestNode {
+                    requested_reviewer: Some(RequestedReviewer {
+                        login: Some("carol".to_string()),
+                        combined_slug: None,
+                    }),
+                },
+                ReviewRequestNode {
+                    requested_reviewer: Some(RequestedReviewer {
+                        login: None,
+                        combined_slug: Some("owner/platform".to_string()),
+                    }),
+                },
+            ],
         };
         pr.latest_reviews = LatestReviewConnection {
             nodes: vec![LatestReviewNode {
@@ -673,9 +1192,12 @@ mod tests {
             }],
         };
 
-        let model = graphql_pr_to_model("owner/repo", &pr, "Alice").expect("mapping succeeds");
+        let model = graphql_pr_to_model("owner/repo", &pr, "Alice", &CiPolicy::RequiredOnly)
+            .expect("mapping succeeds");
 
         assert_eq!(model.requested_reviewers, vec!["carol".to_string()]);
+        assert_eq!(model.requested_teams, vec!["owner/platform".to_string()]);
+        assert!(!model.team_review_requested);
         assert!(model.user_has_reviewed);
     }
 
@@ -685,12 +1207,14 @@ mod tests {
             "owner/repo",
             &[test_pr(1, "OPEN", "2025-06-15T00:00:00Z", Some("FAILURE"))],
             "alice",
+            &CiPolicy::RequiredOnly,
         )
         .expect("processing succeeds");
         let refresh = process_tracked_pull_request_nodes(
             "owner/repo",
             &[test_pr(1, "OPEN", "2025-06-15T00:00:00Z", Some("SUCCESS"))],
             "alice",
+            &CiPolicy::RequiredOnly,
         )
         .expect("processing succeeds");
 
@@ -701,4 +1225,168 @@ mod tests {
         assert_eq!(merged.open_prs[0].ci_status, CiStatus::Success);
         assert_eq!(merged.closed_pr_numbers, vec![99]);
     }
+
+    #[test]
+    fn merge_tracked_pull_request_sync_data_keeps_prs_only_refresh_found_closed() {
+        let discovery = process_tracked_pull_request_nodes(
+            "owner/repo",
+            &[test_pr(1, "CLOSED", "2025-06-15T00:00:00Z", None)],
+            "alice",
+            &CiPolicy::RequiredOnly,
+        )
+        .expect("processing succeeds");
+        let refresh = process_tracked_pull_request_nodes(
+            "owner/repo",
+            &[
+                test_pr(1, "CLOSED", "2025-06-15T00:00:00Z", None),
+                test_pr(2, "MERGED", "2025-06-15T00:00:00Z", None),
+            ],
+            "alice",
+            &CiPolicy::RequiredOnly,
+        )
+        .expect("processing succeeds");
+
+        let merged = merge_tracked_pull_request_sync_data(discovery, refresh);
+
+        assert_eq!(merged.closed_pr_numbers, vec![1, 2]);
+    }
+
+    #[test]
+    fn graphql_pr_to_model_keeps_each_check() {
+        let mut pr = test_pr(1, "OPEN", "2025-06-15T00:00:00Z", None);
+        pr.commits.nodes[0].commit.status_check_rollup = Some(StatusCheckRollup {
+            state: "FAILURE".to_string(),
+            contexts: StatusCheckRollupContextConnection {
+                nodes: vec![
+                    StatusCheckRollupContext::CheckRun {
+                        name: "lint".to_string(),
+                        check_suite: Some(CheckSuiteNode {
+                            app: Some(CheckSuiteApp {
+                                slug: "github-actions".to_string(),
+                            }),
+                            workflow_run: Some(CheckSuiteWorkflowRun {
+                                workflow: CheckSuiteWorkflow {
+                                    name: "CI".to_string(),
+                                },
+                            }),
+                        }),
+                        status: "COMPLETED".to_string(),
+                        conclusion: Some("FAILURE".to_string()),
+                        is_required: true,
+                        details_url: Some("https://ci.example/lint".to_string()),
+                        started_at: Some("2025-06-15T00:00:00Z".to_string()),
+                        completed_at: Some("2025-06-15T00:02:00Z".to_string()),
+                    },
+                    StatusCheckRollupContext::StatusContext {
+                        context: "deploy/preview".to_string(),
+                        state: "PENDING".to_string(),
+                        target_url: None,
+                        created_at: Some("2025-06-15T00:01:00Z".to_string()),
+                        is_required: false,
+                    },
+                ],
+            },
+        });
+
+        let model = graphql_pr_to_model("owner/repo", &pr, "alice", &CiPolicy::RequiredOnly)
+            .expect("mapping succeeds");
+
+        assert_eq!(model.checks.len(), 2);
+        assert_eq!(model.checks[0].workflow, "CI");
+        assert_eq!(model.checks[0].name, "lint");
+        assert_eq!(model.checks[0].ci_status, CiStatus::Failure);
+        assert_eq!(
+            model.checks[0].completed_at,
+            parse_optional_timestamp(Some("2025-06-15T00:02:00Z"))
+        );
+        assert_eq!(model.checks[1].workflow, "");
+        assert_eq!(model.checks[1].name, "deploy/preview");
+        assert_eq!(model.checks[1].status, "PENDING");
+        assert_eq!(model.checks[1].conclusion, None);
+        assert_eq!(model.checks[1].ci_status, CiStatus::Pending);
+        assert!(model.checks[0].counts_towards_ci);
+        assert!(!model.checks[1].counts_towards_ci);
+
+        let model = graphql_pr_to_model(
+            "owner/repo",
+            &pr,
+            "alice",
+            &CiPolicy::Deny(vec!["lint".to_string()]),
+        )
+        .expect("mapping succeeds");
+        assert!(!model.checks[0].counts_towards_ci);
+        assert!(model.checks[1].counts_towards_ci);
+    }
+
+    #[test]
+    fn graphql_pr_to_model_keeps_comment_truncation() {
+        let mut pr = test_pr(1, "OPEN", "2025-06-15T00:00:00Z", None);
+        pr.comments_truncated = true;
+
+        let model = graphql_pr_to_model("owner/repo", &pr, "alice", &CiPolicy::RequiredOnly)
+            .expect("mapping succeeds");
+
+        assert!(model.comments_truncated);
+    }
+
+    #[test]
+    fn comments_complete_after_starts_at_the_oldest_fetched_page() {
+        let mut pr = test_pr(1, "OPEN", "2025-06-15T00:00:00Z", None);
+        assert_eq!(comments_complete_after_for(&pr), DateTime::<Utc>::MIN_UTC);
+
+        pr.comments = CommentConnection {
+            page_info: BackwardPageInfo {
+                has_previous_page: true,
+                start_cursor: Some("Y3Vy".to_string()),
+            },
+            nodes: vec![CommentNode {
+                id: "comment-1".to_string(),
+                author: None,
+                body: "hello".to_string(),
+                created_at: "2025-06-15T00:05:00Z".to_string(),
+                updated_at: "2025-06-15T00:05:00Z".to_string(),
+                is_minimized: false,
+                last_edited_at: None,
+            }],
+        };
+        assert_eq!(
+            comments_complete_after_for(&pr),
+            parse_github_timestamp("2025-06-15T00:05:00Z").unwrap()
+        );
+    }
+
+    #[test]
+    fn graphql_pr_to_model_maps_review_threads() {
+        let mut pr = test_pr(1, "OPEN", "2025-06-15T00:00:00Z", None);
+        pr.review_threads = ReviewThreadConnection {
+            nodes: vec![ReviewThreadNode {
+                id: "thread-1".to_string(),
+                is_resolved: false,
+                is_outdated: true,
+                path: "src/main.rs".to_string(),
+                line: None,
+                comments: ReviewThreadCommentConnection {
+                    total_count: 3,
+                    nodes: vec![ReviewThreadCommentNode {
+                        author: Some(Author {
+                            login: "bob".to_string(),
+                            actor_type: None,
+                        }),
+                        body: "Why is this needed?".to_string(),
+                        created_at: "2025-06-15T00:00:00Z".to_string(),
+                    }],
+                },
+            }],
+        };
+
+        let model = graphql_pr_to_model("owner/repo", &pr, "alice", &CiPolicy::RequiredOnly)
+            .expect("mapping succeeds");
+
+        assert_eq!(model.review_threads.len(), 1);
+        let thread = &model.review_threads[0];
+        assert_eq!(thread.author, "bob");
+        assert_eq!(thread.comment_count, 3);
+        assert!(thread.is_outdated);
+        assert_eq!(model.unresolved_thread_count(), 1);
+    }
 }
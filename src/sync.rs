@@ -1,5 +1,6 @@
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use chrono::{DateTime, Utc};
 use tokio::sync::Semaphore;
@@ -7,15 +8,21 @@ use tokio::task::JoinSet;
 
 use crate::core::{
     count_update_reasons, partition_updated_pull_requests, process_pull_request_sync_results,
-    SyncDiff,
+    SyncDiff, UpdatedPullRequest,
 };
+use crate::credentials::github_client_for_user;
 use crate::db::DatabaseRepository;
-use crate::github::GitHubClient;
-use crate::models::{PullRequest, TrackedRepository};
+use crate::github::{GitHubClient, GraphqlUsage};
+use crate::models::{
+    CredentialSource, DiscoveredRepository, FollowedDiscoveryRule, PrComment, PrSource,
+    PullRequest, SyncRun, SyncRunRepository, SyncTrigger, TrackedRepository, User, UserTeams,
+};
 use crate::service;
+use crate::worktree::{self, format_kept_worktree, KeptWorktree, WorktreeCleanup};
 
 const DEFAULT_MAX_PR_AGE_DAYS: i64 = 7;
 const MAX_CONCURRENT_REPOS: usize = 5;
+const USER_TEAMS_MAX_AGE_HOURS: i64 = 24;
 
 fn pr_age_cutoff() -> Option<DateTime<Utc>> {
     let days: i64 = std::env::var("PR_TRACKER_MAX_PR_AGE_DAYS")
@@ -58,6 +65,183 @@ fn effective_tracked_authors(tracked_authors: &[String], username: &str) -> Vec<
     authors
 }
 
+/// An authenticated account together with the client used to sync its repositories.
+#[derive(Clone)]
+pub struct SyncAccount {
+    /// Account name used to match repository assignments.
+    pub username: String,
+    /// GitHub login whose PRs and reviews count as "mine"; empty for GitHub
+    /// Apps, which have no user of their own.
+    pub login: String,
+    pub github: GitHubClient,
+    pub is_active: bool,
+}
+
+impl SyncAccount {
+    pub fn from_user(user: User) -> anyhow::Result<Self> {
+        Ok(Self {
+            github: github_client_for_user(&user)?,
+            login: match user.credential {
+                CredentialSource::GitHubApp { .. } => String::new(),
+                _ => user.username.clone(),
+            },
+            username: user.username,
+            is_active: user.is_active,
+        })
+    }
+}
+
+/// A stored account whose credential could not be resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedAccount {
+    pub username: String,
+    pub error: String,
+    pub is_active: bool,
+}
+
+/// The accounts a sync can use, and those it has to skip.
+#[derive(Default)]
+pub struct SyncAccounts {
+    pub accounts: Vec<SyncAccount>,
+    pub skipped: Vec<SkippedAccount>,
+}
+
+/// Build a sync account for every stored user. An account whose credential
+/// fails to resolve is skipped rather than failing the others; it is only an
+/// error when every stored account fails.
+pub async fn load_sync_accounts(repository: &DatabaseRepository) -> anyhow::Result<SyncAccounts> {
+    let mut loaded = SyncAccounts::default();
+    for user in repository.get_users().await? {
+        let username = user.username.clone();
+        let is_active = user.is_active;
+        match SyncAccount::from_user(user) {
+            Ok(account) => loaded.accounts.push(account),
+            Err(err) => loaded.skipped.push(SkippedAccount {
+                username,
+                error: err.to_string(),
+                is_active,
+            }),
+        }
+    }
+
+    if loaded.accounts.is_empty() && !loaded.skipped.is_empty() {
+        let failures: Vec<String> = loaded
+            .skipped
+            .iter()
+            .map(|skipped| format!("{}: {}", skipped.username, skipped.error))
+            .collect();
+        anyhow::bail!("no account could be loaded: {}", failures.join("; "));
+    }
+    Ok(loaded)
+}
+
+/// The skipped account `tracked_repo` would be synced with, if any: its
+/// assigned account, or the active one when it has no loaded account of its
+/// own. Such a repository fails rather than falling back to another account's
+/// token.
+fn skipped_account_for_repository<'a>(
+    accounts: &[SyncAccount],
+    skipped: &'a [SkippedAccount],
+    tracked_repo: &TrackedRepository,
+) -> Option<&'a SkippedAccount> {
+    skipped_account_for_assignment(accounts, skipped, tracked_repo.account.as_deref())
+}
+
+fn skipped_account_for_assignment<'a>(
+    accounts: &[SyncAccount],
+    skipped: &'a [SkippedAccount],
+    assigned_account: Option<&str>,
+) -> Option<&'a SkippedAccount> {
+    if let Some(login) = assigned_account {
+        if accounts
+            .iter()
+            .any(|account| account.username.eq_ignore_ascii_case(login))
+        {
+            return None;
+        }
+        if let Some(assigned) = skipped
+            .iter()
+            .find(|skipped| skipped.username.eq_ignore_ascii_case(login))
+        {
+            return Some(assigned);
+        }
+    }
+    skipped.iter().find(|skipped| skipped.is_active)
+}
+
+/// Pick the account a repository is synced with: its assigned account when
+/// that account still exists, otherwise the active one.
+fn account_index_for_repository(
+    accounts: &[SyncAccount],
+    tracked_repo: &TrackedRepository,
+) -> Option<usize> {
+    account_index_for_assignment(accounts, tracked_repo.account.as_deref())
+}
+
+fn account_index_for_assignment(
+    accounts: &[SyncAccount],
+    assigned_account: Option<&str>,
+) -> Option<usize> {
+    let assigned = assigned_account.and_then(|login| {
+        accounts
+            .iter()
+            .position(|account| account.username.eq_ignore_ascii_case(login))
+    });
+
+    assigned
+        .or_else(|| accounts.iter().position(|account| account.is_active))
+        .or(if accounts.is_empty() { None } else { Some(0) })
+}
+
+/// Which part of the tracked set a sync covers, and whether it writes. The
+/// default covers all of it, including followed discovery rules, renames and
+/// the review inbox; a narrower scope only syncs the listed repositories and
+/// leaves everything else, including the watermarks of repositories it does
+/// not fully sync, untouched.
+#[derive(Debug, Clone, Default)]
+pub struct SyncOptions {
+    /// Tracked repositories to sync; empty means all of them.
+    pub repositories: Vec<String>,
+    /// Only sync PRs by this author.
+    pub author: Option<String>,
+    /// Fetch and diff without writing anything; the changes a real sync
+    /// would make are returned in `SyncRunSummary::previews`.
+    pub dry_run: bool,
+}
+
+impl SyncOptions {
+    pub fn repository(repository: impl Into<String>) -> Self {
+        Self {
+            repositories: vec![repository.into()],
+            ..Self::default()
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.repositories.is_empty() && self.author.is_none()
+    }
+
+    /// What the sync covers, e.g. `all` or `acme/api author=bob`.
+    pub fn describe(&self) -> String {
+        let mut parts = self.repositories.clone();
+        if parts.is_empty() {
+            parts.push("all".to_string());
+        }
+        if let Some(author) = &self.author {
+            parts.push(format!("author={author}"));
+        }
+        parts.join(" ")
+    }
+
+    fn includes(&self, tracked_repo: &TrackedRepository) -> bool {
+        self.repositories.is_empty()
+            || self
+                .repositories
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&tracked_repo.repository))
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct SyncRunSummary {
     pub synced_repositories: usize,
@@ -66,10 +250,105 @@ pub struct SyncRunSummary {
     pub updated_attention_prs: Vec<PullRequest>,
     pub updated_reason_counts: BTreeMap<String, usize>,
     pub deleted_prs: Vec<PullRequest>,
+    pub removed_worktrees: usize,
+    /// Worktrees of closed PRs left in place because they hold local work.
+    pub kept_worktrees: Vec<KeptWorktree>,
+    /// Stored comments GitHub no longer returned, since they were deleted.
+    pub removed_comments: usize,
+    pub renamed_repositories: Vec<RepositoryRename>,
+    pub failed_repositories: Vec<RepositorySyncFailure>,
+    /// Accounts left out because their credential failed to resolve.
+    pub skipped_accounts: Vec<SkippedAccount>,
+    /// Accounts whose review inbox search failed; the inbox PRs stored for
+    /// them were kept as they are.
+    pub failed_inbox_searches: Vec<InboxSearchFailure>,
+    /// Per-PR changes of a dry run, which saved none of them.
+    pub previews: Vec<PrChangePreview>,
+    /// How long each tracked repository took and what it changed.
+    pub repository_stats: Vec<SyncRunRepository>,
+}
+
+/// A change a dry run found for one PR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrChangePreview {
+    pub repository: String,
+    pub number: i64,
+    pub title: String,
+    pub change: PreviewChange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewChange {
+    New,
+    /// Update reasons with their old and new values, see `UpdateReason::describe`.
+    Updated(Vec<String>),
+    Closed,
+}
+
+pub fn format_pr_change_preview(preview: &PrChangePreview) -> String {
+    let (label, details) = match &preview.change {
+        PreviewChange::New => ("new", String::new()),
+        PreviewChange::Updated(reasons) => ("updated", format!(" ({})", reasons.join("; "))),
+        PreviewChange::Closed => ("closed", String::new()),
+    };
+    format!(
+        "{label:<7} {}#{} {}{details}",
+        preview.repository, preview.number, preview.title
+    )
+}
+
+/// A tracked repository GitHub now reports under another name, after a
+/// rename, a transfer or a change of case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepositoryRename {
+    pub from: String,
+    pub to: String,
+}
+
+/// A repository whose sync failed; the other repositories still sync.
+#[derive(Debug, Clone)]
+pub struct RepositorySyncFailure {
+    pub repository: String,
+    pub error: String,
+}
+
+/// An account whose tracked repositories could not be looked up by node id
+/// to detect renames.
+#[derive(Debug, Clone)]
+struct RenameCheckFailure {
+    username: String,
+    error: String,
+}
+
+/// An account whose review inbox search failed; the rest of the inbox still
+/// syncs.
+#[derive(Debug, Clone)]
+pub struct InboxSearchFailure {
+    pub username: String,
+    pub error: String,
 }
 
 #[derive(Debug, Clone)]
 pub enum SyncProgress {
+    /// An account left out of the sync because its credential failed.
+    AccountSkipped {
+        username: String,
+        error: String,
+    },
+    /// Repositories a followed discovery rule started tracking.
+    RepositoriesFollowed {
+        repositories: Vec<String>,
+    },
+    RepositoryRenamed {
+        from: String,
+        to: String,
+    },
+    /// An account's repositories could not be checked for renames; they keep
+    /// their stored names until the next run.
+    RenameCheckFailed {
+        username: String,
+        error: String,
+    },
     FullSyncStarted {
         total_repositories: usize,
     },
@@ -88,10 +367,44 @@ pub enum SyncProgress {
         updated_reason_counts: BTreeMap<String, usize>,
         deleted_prs: usize,
     },
+    FullSyncRepositoryFailed {
+        repository: String,
+        repository_index: usize,
+        total_repositories: usize,
+        error: String,
+    },
+    ReviewInboxSearchFailed {
+        username: String,
+        error: String,
+    },
+    ReviewInboxRepositoryFailed {
+        repository: String,
+        error: String,
+    },
+    ReviewInboxCompleted {
+        repositories: usize,
+        new_prs: usize,
+        updated_data_prs: usize,
+        deleted_prs: usize,
+    },
 }
 
 pub fn format_sync_progress(progress: &SyncProgress) -> Option<String> {
     match progress {
+        SyncProgress::AccountSkipped { username, error } => {
+            Some(format!("[sync] skipping account {username}: {error}"))
+        }
+        SyncProgress::RepositoriesFollowed { repositories } => Some(format!(
+            "[sync] now tracking new repositor{}: {}",
+            if repositories.len() == 1 { "y" } else { "ies" },
+            repositories.join(", ")
+        )),
+        SyncProgress::RepositoryRenamed { from, to } => {
+            Some(format!("[sync] repository renamed: {from} -> {to}"))
+        }
+        SyncProgress::RenameCheckFailed { username, error } => Some(format!(
+            "[sync] rename check failed for {username}: {error}"
+        )),
         SyncProgress::FullSyncStarted { total_repositories } => Some(format!(
             "[sync] starting sync for {total_repositories} repositor{}",
             if *total_repositories == 1 { "y" } else { "ies" }
@@ -111,11 +424,28 @@ pub fn format_sync_progress(progress: &SyncProgress) -> Option<String> {
             "[sync] repository complete: {repository} new={} updated_data={} updated_attention={} deleted={} reasons={:?}",
             new_prs, updated_data_prs, updated_attention_prs, deleted_prs, updated_reason_counts
         )),
+        SyncProgress::FullSyncRepositoryFailed {
+            repository, error, ..
+        } => Some(format!("[sync] repository failed: {repository}: {error}")),
+        SyncProgress::ReviewInboxSearchFailed { username, error } => Some(format!(
+            "[sync] review inbox search failed for {username}: {error}"
+        )),
+        SyncProgress::ReviewInboxRepositoryFailed { repository, error } => Some(format!(
+            "[sync] review inbox failed for {repository}: {error}"
+        )),
+        SyncProgress::ReviewInboxCompleted {
+            repositories,
+            new_prs,
+            updated_data_prs,
+            deleted_prs,
+        } => Some(format!(
+            "[sync] review inbox complete: repos={repositories} new={new_prs} updated_data={updated_data_prs} deleted={deleted_prs}"
+        )),
     }
 }
 
 pub fn format_sync_summary(summary: &SyncRunSummary) -> String {
-    format!(
+    let mut line = format!(
         "Sync complete: repos={} new={} updated_data={} updated_attention={} deleted={} reasons={:?}",
         summary.synced_repositories,
         summary.new_prs.len(),
@@ -123,7 +453,49 @@ pub fn format_sync_summary(summary: &SyncRunSummary) -> String {
         summary.updated_attention_prs.len(),
         summary.deleted_prs.len(),
         summary.updated_reason_counts
-    )
+    );
+    if summary.removed_worktrees > 0 {
+        line.push_str(&format!(" removed_worktrees={}", summary.removed_worktrees));
+    }
+    if !summary.kept_worktrees.is_empty() {
+        let kept: Vec<String> = summary
+            .kept_worktrees
+            .iter()
+            .map(|kept| format!("{}#{}", kept.repository, kept.pr_number))
+            .collect();
+        line.push_str(&format!(" kept_worktrees={}", kept.join(",")));
+    }
+    if summary.removed_comments > 0 {
+        line.push_str(&format!(" removed_comments={}", summary.removed_comments));
+    }
+    if !summary.renamed_repositories.is_empty() {
+        line.push_str(&format!(" renamed={}", summary.renamed_repositories.len()));
+    }
+    if !summary.skipped_accounts.is_empty() {
+        let skipped: Vec<&str> = summary
+            .skipped_accounts
+            .iter()
+            .map(|skipped| skipped.username.as_str())
+            .collect();
+        line.push_str(&format!(" skipped_accounts={}", skipped.join(",")));
+    }
+    if !summary.failed_inbox_searches.is_empty() {
+        let failed: Vec<&str> = summary
+            .failed_inbox_searches
+            .iter()
+            .map(|failure| failure.username.as_str())
+            .collect();
+        line.push_str(&format!(" failed_inbox={}", failed.join(",")));
+    }
+    if !summary.failed_repositories.is_empty() {
+        let failed: Vec<&str> = summary
+            .failed_repositories
+            .iter()
+            .map(|failure| failure.repository.as_str())
+            .collect();
+        line.push_str(&format!(" failed={}", failed.join(",")));
+    }
+    line
 }
 
 struct RepoSyncResult {
@@ -134,6 +506,10 @@ struct RepoSyncResult {
     updated_attention_prs: Vec<PullRequest>,
     updated_reason_counts: BTreeMap<String, usize>,
     deleted_prs: Vec<PullRequest>,
+    removed_worktrees: usize,
+    kept_worktrees: Vec<KeptWorktree>,
+    removed_comments: usize,
+    previews: Vec<PrChangePreview>,
 }
 
 fn merge_reason_counts(target: &mut BTreeMap<String, usize>, source: BTreeMap<String, usize>) {
@@ -144,41 +520,120 @@ fn merge_reason_counts(target: &mut BTreeMap<String, usize>, source: BTreeMap<St
 
 pub async fn sync_all_tracked(
     repository: &DatabaseRepository,
-    github: &GitHubClient,
-    username: &str,
+    accounts: &SyncAccounts,
 ) -> anyhow::Result<SyncRunSummary> {
-    sync_all_tracked_with_progress(repository, github, username, |_| {}).await
+    sync_all_tracked_with_progress(repository, accounts, &SyncOptions::default(), |_| {}).await
 }
 
+/// Sync the tracked repositories in `scope`, each with the client of the
+/// account it is assigned to. Repositories assigned to a skipped account are
+/// reported as failed.
 pub async fn sync_all_tracked_with_progress<F>(
     repository: &DatabaseRepository,
-    github: &GitHubClient,
-    username: &str,
+    accounts: &SyncAccounts,
+    options: &SyncOptions,
     mut progress_callback: F,
 ) -> anyhow::Result<SyncRunSummary>
 where
     F: FnMut(SyncProgress),
 {
-    let repositories = repository.get_tracked_repositories().await?;
-    let tracked_authors =
-        effective_tracked_authors(&repository.get_tracked_authors().await?, username);
-
     let mut summary = SyncRunSummary::default();
+    for skipped in &accounts.skipped {
+        progress_callback(SyncProgress::AccountSkipped {
+            username: skipped.username.clone(),
+            error: skipped.error.clone(),
+        });
+    }
+    summary.skipped_accounts = accounts.skipped.clone();
+    let skipped_accounts = &accounts.skipped;
+    let accounts = accounts.accounts.as_slice();
+    if options.is_full() && !options.dry_run {
+        let followed = follow_discovery_rules(repository, accounts, skipped_accounts).await?;
+        if !followed.is_empty() {
+            progress_callback(SyncProgress::RepositoriesFollowed {
+                repositories: followed,
+            });
+        }
+
+        let (renames, failed_checks) =
+            canonicalize_tracked_repositories(repository, accounts).await?;
+        for failure in failed_checks {
+            progress_callback(SyncProgress::RenameCheckFailed {
+                username: failure.username,
+                error: failure.error,
+            });
+        }
+        summary.renamed_repositories = renames;
+        for rename in &summary.renamed_repositories {
+            progress_callback(SyncProgress::RepositoryRenamed {
+                from: rename.from.clone(),
+                to: rename.to.clone(),
+            });
+        }
+    }
+
+    let repositories: Vec<TrackedRepository> = repository
+        .get_tracked_repositories()
+        .await?
+        .into_iter()
+        .filter(|tracked_repo| options.includes(tracked_repo))
+        .collect();
+    if let Some(missing) = options.repositories.iter().find(|name| {
+        !repositories
+            .iter()
+            .any(|tracked_repo| tracked_repo.repository.eq_ignore_ascii_case(name))
+    }) {
+        anyhow::bail!("repository '{missing}' is not tracked");
+    }
+    let tracked_authors = repository.get_tracked_authors().await?;
+    if let Some(author) = &options.author {
+        let known = tracked_authors
+            .iter()
+            .chain(accounts.iter().map(|account| &account.login))
+            .any(|login| login.eq_ignore_ascii_case(author));
+        if !known {
+            anyhow::bail!("author '{author}' is not tracked");
+        }
+    }
+
     progress_callback(SyncProgress::FullSyncStarted {
         total_repositories: repositories.len(),
     });
 
-    if repositories.is_empty() || tracked_authors.is_empty() {
+    if accounts.is_empty() {
         return Ok(summary);
     }
 
+    let mut teams_by_account = Vec::with_capacity(accounts.len());
+    for account in accounts {
+        teams_by_account.push(Arc::new(
+            account_teams(repository, account, options.dry_run).await?,
+        ));
+    }
+
     let total_repositories = repositories.len();
+    let tracked_repositories = repositories.clone();
     let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_REPOS));
     let mut join_set = JoinSet::new();
 
-    let username_owned = username.to_string();
-
     for (index, tracked_repo) in repositories.into_iter().enumerate() {
+        if let Some(skipped) =
+            skipped_account_for_repository(accounts, skipped_accounts, &tracked_repo)
+        {
+            let repo_name = tracked_repo.repository.clone();
+            let error = anyhow::anyhow!(
+                "account '{}' could not be loaded: {}",
+                skipped.username,
+                skipped.error
+            );
+            join_set.spawn(async move { (repo_name, index + 1, Duration::ZERO, Err(error)) });
+            continue;
+        }
+        let Some(account_index) = account_index_for_repository(accounts, &tracked_repo) else {
+            continue;
+        };
+        let account = &accounts[account_index];
+
         progress_callback(SyncProgress::FullSyncRepositoryStarted {
             repository: tracked_repo.repository.clone(),
             repository_index: index + 1,
@@ -187,18 +642,62 @@ where
 
         let sem = semaphore.clone();
         let db = repository.clone();
-        let gh = github.clone();
-        let authors = tracked_authors.clone();
-        let uname = username_owned.clone();
+        let context = RepoSyncContext {
+            github: account.github.clone(),
+            tracked_authors: effective_tracked_authors(&tracked_authors, &account.login),
+            only_author: options.author.clone(),
+            username: account.login.clone(),
+            user_teams: teams_by_account[account_index].clone(),
+            dry_run: options.dry_run,
+        };
 
         join_set.spawn(async move {
             let _permit = sem.acquire().await.unwrap();
-            sync_single_repo(&db, &gh, &authors, tracked_repo, index + 1, &uname).await
+            let repo_name = tracked_repo.repository.clone();
+            let started = Instant::now();
+            let result = sync_single_repo(&db, &context, tracked_repo, index + 1).await;
+            (repo_name, index + 1, started.elapsed(), result)
         });
     }
 
     while let Some(result) = join_set.join_next().await {
-        let repo_result = result??;
+        let (repo_name, repo_index, duration, result) = result?;
+        let duration_ms = i64::try_from(duration.as_millis()).unwrap_or(i64::MAX);
+        // One inaccessible or broken repository must not abort the others.
+        let repo_result = match result {
+            Ok(repo_result) => repo_result,
+            Err(err) => {
+                if !options.dry_run {
+                    repository
+                        .set_tracked_repository_sync_error(&repo_name, Some(&err.to_string()))
+                        .await?;
+                }
+                progress_callback(SyncProgress::FullSyncRepositoryFailed {
+                    repository: repo_name.clone(),
+                    repository_index: repo_index,
+                    total_repositories,
+                    error: err.to_string(),
+                });
+                summary.repository_stats.push(SyncRunRepository {
+                    repository: repo_name.clone(),
+                    duration_ms,
+                    new_prs: 0,
+                    updated_prs: 0,
+                    deleted_prs: 0,
+                    error: Some(err.to_string()),
+                });
+                summary.failed_repositories.push(RepositorySyncFailure {
+                    repository: repo_name,
+                    error: err.to_string(),
+                });
+                continue;
+            }
+        };
+        if !options.dry_run {
+            repository
+                .set_tracked_repository_sync_error(&repo_name, None)
+                .await?;
+        }
         progress_callback(SyncProgress::FullSyncRepositoryCompleted {
             repository: repo_result.repo_name.clone(),
             repository_index: repo_result.repo_index,
@@ -210,6 +709,15 @@ where
             deleted_prs: repo_result.deleted_prs.len(),
         });
         summary.synced_repositories += 1;
+        summary.repository_stats.push(SyncRunRepository {
+            repository: repo_name,
+            duration_ms,
+            new_prs: repo_result.new_prs.len() as i64,
+            updated_prs: (repo_result.updated_data_prs.len()
+                + repo_result.updated_attention_prs.len()) as i64,
+            deleted_prs: repo_result.deleted_prs.len() as i64,
+            error: None,
+        });
         summary.new_prs.extend(repo_result.new_prs);
         summary
             .updated_data_prs
@@ -222,40 +730,862 @@ where
             repo_result.updated_reason_counts,
         );
         summary.deleted_prs.extend(repo_result.deleted_prs);
+        summary.removed_worktrees += repo_result.removed_worktrees;
+        summary.kept_worktrees.extend(repo_result.kept_worktrees);
+        summary.removed_comments += repo_result.removed_comments;
+        summary.previews.extend(repo_result.previews);
+    }
+
+    if !options.is_full() || options.dry_run {
+        return Ok(summary);
+    }
+
+    // The inbox runs after the tracked repositories so it only adds the PRs
+    // they did not already cover.
+    let ReviewInboxSync {
+        results: inbox_results,
+        failed_searches,
+        failed_repositories,
+    } = sync_review_inbox(
+        repository,
+        accounts,
+        skipped_accounts.is_empty(),
+        &teams_by_account,
+        &tracked_repositories,
+        &tracked_authors,
+    )
+    .await?;
+    for failure in &failed_searches {
+        progress_callback(SyncProgress::ReviewInboxSearchFailed {
+            username: failure.username.clone(),
+            error: failure.error.clone(),
+        });
+    }
+    for failure in &failed_repositories {
+        progress_callback(SyncProgress::ReviewInboxRepositoryFailed {
+            repository: failure.repository.clone(),
+            error: failure.error.clone(),
+        });
+    }
+    summary.failed_inbox_searches = failed_searches;
+    summary.failed_repositories.extend(failed_repositories);
+    progress_callback(SyncProgress::ReviewInboxCompleted {
+        repositories: inbox_results.len(),
+        new_prs: inbox_results.iter().map(|r| r.new_prs.len()).sum(),
+        updated_data_prs: inbox_results.iter().map(|r| r.updated_data_prs.len()).sum(),
+        deleted_prs: inbox_results.iter().map(|r| r.deleted_prs.len()).sum(),
+    });
+    for inbox_result in inbox_results {
+        summary.new_prs.extend(inbox_result.new_prs);
+        summary
+            .updated_data_prs
+            .extend(inbox_result.updated_data_prs);
+        summary
+            .updated_attention_prs
+            .extend(inbox_result.updated_attention_prs);
+        merge_reason_counts(
+            &mut summary.updated_reason_counts,
+            inbox_result.updated_reason_counts,
+        );
+        summary.deleted_prs.extend(inbox_result.deleted_prs);
+        summary.removed_worktrees += inbox_result.removed_worktrees;
+        summary.kept_worktrees.extend(inbox_result.kept_worktrees);
+        summary.removed_comments += inbox_result.removed_comments;
     }
 
     Ok(summary)
 }
 
-async fn sync_single_repo(
+/// Run `sync_all_tracked_with_progress` and record it in `sync_runs`: its
+/// counts, per-repository durations, GraphQL usage and progress log. Dry
+/// runs write nothing, so they are not recorded either.
+pub async fn record_sync_run<F>(
+    repository: &DatabaseRepository,
+    accounts: &SyncAccounts,
+    options: &SyncOptions,
+    trigger: SyncTrigger,
+    mut progress_callback: F,
+) -> anyhow::Result<SyncRunSummary>
+where
+    F: FnMut(SyncProgress),
+{
+    if options.dry_run {
+        return sync_all_tracked_with_progress(repository, accounts, options, progress_callback)
+            .await;
+    }
+
+    let started_at = Utc::now();
+    let scope = options.describe();
+    let id = repository
+        .start_sync_run(trigger, &scope, options.dry_run, started_at)
+        .await?;
+    let usage_before: Vec<GraphqlUsage> = accounts
+        .accounts
+        .iter()
+        .map(|account| account.github.graphql_usage())
+        .collect();
+
+    let mut log = Vec::new();
+    let result = sync_all_tracked_with_progress(repository, accounts, options, |progress| {
+        if let Some(line) = format_sync_progress(&progress) {
+            log.push(line);
+        }
+        progress_callback(progress);
+    })
+    .await;
+
+    let usage = accounts
+        .accounts
+        .iter()
+        .zip(usage_before)
+        .map(|(account, before)| account.github.graphql_usage().since(before))
+        .fold(GraphqlUsage::default(), |total, usage| total + usage);
+    let rate_limit = accounts
+        .accounts
+        .iter()
+        .filter_map(|account| account.github.rate_limit())
+        .min_by_key(|rate_limit| rate_limit.remaining);
+    let mut run = SyncRun {
+        id,
+        trigger,
+        scope,
+        dry_run: options.dry_run,
+        started_at,
+        finished_at: Some(Utc::now()),
+        new_prs: 0,
+        updated_data_prs: 0,
+        updated_attention_prs: 0,
+        deleted_prs: 0,
+        graphql_requests: usage.requests as i64,
+        graphql_cost: usage.cost as i64,
+        rate_limit_remaining: rate_limit.map(|rate_limit| rate_limit.remaining as i64),
+        rate_limit_reset_at: rate_limit.map(|rate_limit| rate_limit.reset_at),
+        error: None,
+        log,
+        repositories: Vec::new(),
+    };
+    match &result {
+        Ok(summary) => {
+            run.new_prs = summary.new_prs.len() as i64;
+            run.updated_data_prs = summary.updated_data_prs.len() as i64;
+            run.updated_attention_prs = summary.updated_attention_prs.len() as i64;
+            run.deleted_prs = summary.deleted_prs.len() as i64;
+            run.repositories = summary.repository_stats.clone();
+            run.log
+                .extend(summary.kept_worktrees.iter().map(format_kept_worktree));
+            run.log.push(format_sync_summary(summary));
+        }
+        Err(err) => {
+            run.error = Some(err.to_string());
+            run.log.push(format!("Sync failed: {err}"));
+        }
+    }
+    repository.finish_sync_run(&run).await?;
+
+    result
+}
+
+/// One line describing a recorded run, for `prt sync history` and the TUI.
+pub fn format_sync_run(run: &SyncRun) -> String {
+    let duration = match run.finished_at {
+        Some(finished_at) => format_run_duration(finished_at - run.started_at),
+        None => "-".to_string(),
+    };
+    let failed_repositories = run
+        .repositories
+        .iter()
+        .filter(|repository| repository.error.is_some())
+        .count();
+    let status = if run.finished_at.is_none() {
+        "unfinished".to_string()
+    } else if run.error.is_some() {
+        "failed".to_string()
+    } else if failed_repositories > 0 {
+        format!("{failed_repositories} failed")
+    } else {
+        "ok".to_string()
+    };
+    let mut line = format!(
+        "#{} {} {}{} {} {} {} new={} updated={} deleted={} graphql={} cost={}",
+        run.id,
+        run.started_at
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M"),
+        run.trigger.as_str(),
+        if run.dry_run { " dry-run" } else { "" },
+        run.scope,
+        duration,
+        status,
+        run.new_prs,
+        run.updated_data_prs + run.updated_attention_prs,
+        run.deleted_prs,
+        run.graphql_requests,
+        run.graphql_cost
+    );
+    if let Some(remaining) = run.rate_limit_remaining {
+        line.push_str(&format!(" remaining={remaining}"));
+    }
+    line
+}
+
+/// One repository of a recorded run, e.g. `acme/api 1.3s new=1 updated=2 deleted=0`.
+pub fn format_sync_run_repository(repository: &SyncRunRepository) -> String {
+    let mut line = format!(
+        "{} {} new={} updated={} deleted={}",
+        repository.repository,
+        format_run_duration(chrono::Duration::milliseconds(repository.duration_ms)),
+        repository.new_prs,
+        repository.updated_prs,
+        repository.deleted_prs
+    );
+    if let Some(error) = &repository.error {
+        line.push_str(&format!(" failed: {error}"));
+    }
+    line
+}
+
+/// `850ms`, `4.2s` or `3m12s`.
+pub fn format_run_duration(duration: chrono::Duration) -> String {
+    let millis = duration.num_milliseconds().max(0);
+    if millis < 1_000 {
+        format!("{millis}ms")
+    } else if millis < 60_000 {
+        format!("{:.1}s", millis as f64 / 1_000.0)
+    } else {
+        format!("{}m{:02}s", millis / 60_000, (millis % 60_000) / 1_000)
+    }
+}
+
+/// Bring stored repository names in line with GitHub. Repositories are
+/// matched by node id, so renames and transfers are picked up; those added
+/// before node ids were stored are looked up by name once to record theirs.
+/// An account whose node id lookup fails is returned as a failure, since
+/// its renames go unnoticed; failures of the per-repository name lookups are
+/// left for the repository's own sync to report.
+async fn canonicalize_tracked_repositories(
+    repository: &DatabaseRepository,
+    accounts: &[SyncAccount],
+) -> anyhow::Result<(Vec<RepositoryRename>, Vec<RenameCheckFailure>)> {
+    let mut by_account: BTreeMap<usize, Vec<TrackedRepository>> = BTreeMap::new();
+    for tracked_repo in repository.get_tracked_repositories().await? {
+        if let Some(account_index) = account_index_for_repository(accounts, &tracked_repo) {
+            by_account
+                .entry(account_index)
+                .or_default()
+                .push(tracked_repo);
+        }
+    }
+
+    let mut renames = Vec::new();
+    let mut failures = Vec::new();
+    for (account_index, mut tracked) in by_account {
+        let account = &accounts[account_index];
+        let github = &account.github;
+        let node_ids: Vec<String> = tracked
+            .iter()
+            .filter_map(|tracked_repo| tracked_repo.node_id.clone())
+            .collect();
+        let mut names_by_node_id = if node_ids.is_empty() {
+            HashMap::new()
+        } else {
+            match github.fetch_repository_names_by_node_id(&node_ids).await {
+                Ok(names) => names,
+                Err(err) => {
+                    failures.push(RenameCheckFailure {
+                        username: account.username.clone(),
+                        error: err.to_string(),
+                    });
+                    HashMap::new()
+                }
+            }
+        };
+
+        for tracked_repo in tracked.iter_mut().filter(|repo| repo.node_id.is_none()) {
+            let Ok(found) = github.fetch_repository(&tracked_repo.repository).await else {
+                continue;
+            };
+            let Some(node_id) = found.node_id else {
+                continue;
+            };
+            repository
+                .set_tracked_repository_node_id(&tracked_repo.repository, &node_id)
+                .await?;
+            names_by_node_id.insert(node_id.clone(), found.full_name);
+            tracked_repo.node_id = Some(node_id);
+        }
+
+        for rename in repository_renames(&tracked, &names_by_node_id) {
+            repository
+                .rename_tracked_repository(&rename.from, &rename.to)
+                .await?;
+            renames.push(rename);
+        }
+    }
+
+    Ok((renames, failures))
+}
+
+/// Tracked repositories whose node id now resolves to a different name.
+fn repository_renames(
+    tracked: &[TrackedRepository],
+    names_by_node_id: &HashMap<String, String>,
+) -> Vec<RepositoryRename> {
+    tracked
+        .iter()
+        .filter_map(|tracked_repo| {
+            let current = names_by_node_id.get(tracked_repo.node_id.as_ref()?)?;
+            (current != &tracked_repo.repository).then(|| RepositoryRename {
+                from: tracked_repo.repository.clone(),
+                to: current.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Track the repositories created since each followed discovery rule was
+/// saved that match it. Returns the repositories added.
+async fn follow_discovery_rules(
+    repository: &DatabaseRepository,
+    accounts: &[SyncAccount],
+    skipped_accounts: &[SkippedAccount],
+) -> anyhow::Result<Vec<String>> {
+    let rules = repository.get_repository_follow_rules().await?;
+    if rules.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut tracked: Vec<String> = repository
+        .get_tracked_repositories()
+        .await?
+        .into_iter()
+        .map(|tracked_repo| tracked_repo.repository)
+        .collect();
+    let mut added = Vec::new();
+    for followed in rules {
+        // Like the repositories of a skipped account, its rules wait for the
+        // next run instead of being listed with another account's token.
+        if skipped_account_for_assignment(accounts, skipped_accounts, followed.account.as_deref())
+            .is_some()
+        {
+            continue;
+        }
+        let Some(account_index) =
+            account_index_for_assignment(accounts, followed.account.as_deref())
+        else {
+            continue;
+        };
+        // A rule that cannot be listed right now (e.g. its team was removed)
+        // must not fail the sync; it is retried on the next run instead.
+        let Ok(discovered) =
+            service::discover_repositories(&accounts[account_index].github, &followed.rule).await
+        else {
+            continue;
+        };
+        let followed_now =
+            track_discovered_repositories(repository, &followed, &discovered, &tracked).await?;
+        tracked.extend(followed_now.iter().cloned());
+        added.extend(followed_now);
+    }
+
+    Ok(added)
+}
+
+/// Track the repositories `followed` listed that were created since it last
+/// listed any, and move its `seen_until` past them.
+async fn track_discovered_repositories(
+    repository: &DatabaseRepository,
+    followed: &FollowedDiscoveryRule,
+    discovered: &[DiscoveredRepository],
+    tracked: &[String],
+) -> anyhow::Result<Vec<String>> {
+    let mut added = Vec::new();
+    for discovered_repo in newly_created_repositories(followed, discovered, tracked) {
+        repository
+            .save_tracked_repository(
+                &discovered_repo.full_name,
+                followed.account.as_deref(),
+                discovered_repo.node_id.as_deref(),
+            )
+            .await?;
+        added.push(discovered_repo.full_name.clone());
+    }
+    if let Some(seen_until) = advanced_seen_until(followed, discovered) {
+        repository
+            .set_repository_follow_rule_seen_until(followed.id, seen_until)
+            .await?;
+    }
+    Ok(added)
+}
+
+/// Untracked repositories among `discovered` created after the newest one
+/// `followed` listed before.
+fn newly_created_repositories<'a>(
+    followed: &FollowedDiscoveryRule,
+    discovered: &'a [DiscoveredRepository],
+    tracked: &[String],
+) -> Vec<&'a DiscoveredRepository> {
+    discovered
+        .iter()
+        .filter(|repository| repository.created_at > followed.seen_until)
+        .filter(|repository| {
+            !tracked
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&repository.full_name))
+        })
+        .collect()
+}
+
+/// The new `seen_until` of `followed` once it listed `discovered`, if that
+/// holds a repository created after it. Repositories up to then were already
+/// considered, so untracking one keeps it untracked.
+fn advanced_seen_until(
+    followed: &FollowedDiscoveryRule,
+    discovered: &[DiscoveredRepository],
+) -> Option<DateTime<Utc>> {
+    discovered
+        .iter()
+        .map(|repository| repository.created_at)
+        .max()
+        .filter(|newest| *newest > followed.seen_until)
+}
+
+/// Teams `account` belongs to, as `org/slug`. The list is cached in the
+/// database and refreshed once a day; when a refresh fails (e.g. the token
+/// lacks `read:org`) the cached list, if any, is used instead. A dry run
+/// uses a refreshed list without caching it.
+async fn account_teams(
+    repository: &DatabaseRepository,
+    account: &SyncAccount,
+    dry_run: bool,
+) -> anyhow::Result<Vec<String>> {
+    if account.login.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let cached = repository.get_user_teams(&account.login).await?;
+    if let Some(cached) = &cached {
+        if Utc::now() - cached.fetched_at < chrono::Duration::hours(USER_TEAMS_MAX_AGE_HOURS) {
+            return Ok(cached.teams.clone());
+        }
+    }
+
+    let Ok(teams) = account.github.fetch_user_teams().await else {
+        return Ok(cached.map(|cached| cached.teams).unwrap_or_default());
+    };
+    let user_teams = UserTeams {
+        username: account.login.clone(),
+        teams: teams
+            .into_iter()
+            .map(|team| format!("{}/{}", team.organization.login, team.slug))
+            .collect(),
+        fetched_at: Utc::now(),
+    };
+    if !dry_run {
+        repository.save_user_teams(&user_teams).await?;
+    }
+    Ok(user_teams.teams)
+}
+
+/// Flag PRs that requested a review from one of the user's teams.
+fn mark_team_review_requests(prs: &mut [PullRequest], user_teams: &[String]) {
+    for pr in prs {
+        pr.team_review_requested = pr.requested_teams.iter().any(|team| {
+            user_teams
+                .iter()
+                .any(|mine| mine.eq_ignore_ascii_case(team))
+        });
+    }
+}
+
+/// Flag comments by someone else that @-mention `login` or one of its teams.
+fn mark_mentions(comments: &mut [PrComment], login: &str, user_teams: &[String]) {
+    for comment in comments {
+        comment.mentions_user = !login.is_empty()
+            && !comment.author.eq_ignore_ascii_case(login)
+            && comment.mentions(login, user_teams);
+    }
+}
+
+/// Tag PRs not opened by a tracked author as review inbox PRs; in a tracked
+/// repository only the inbox searches bring those in.
+fn tag_review_inbox_prs(prs: &mut [PullRequest], tracked_authors: &[String]) {
+    for pr in prs {
+        pr.source = if tracked_authors
+            .iter()
+            .any(|author| author.eq_ignore_ascii_case(&pr.author))
+        {
+            PrSource::Tracked
+        } else {
+            PrSource::ReviewInbox
+        };
+    }
+}
+
+/// Stored inbox PRs that are no longer open in anyone's inbox.
+fn stale_review_inbox_prs(existing_prs: &[PullRequest], open_in_inbox: &HashSet<i64>) -> Vec<i64> {
+    existing_prs
+        .iter()
+        .filter(|pr| pr.source == PrSource::ReviewInbox && !open_in_inbox.contains(&pr.number))
+        .map(|pr| pr.number)
+        .collect()
+}
+
+/// The inbox PRs of one repository, under the name it is stored as, by the
+/// account whose search found them.
+#[derive(Debug, Default)]
+struct InboxRepository {
+    name: String,
+    numbers_by_account: BTreeMap<usize, BTreeSet<i64>>,
+}
+
+/// Collect the inbox search results and the stored inbox repositories per
+/// repository, matching names case-insensitively. A repository is named as
+/// it is tracked, else as it is stored, else as the search returned it; a PR
+/// found by several accounts is kept for the first of them.
+fn collect_review_inbox(
+    tracked_repositories: &[TrackedRepository],
+    stored_inbox_repositories: Vec<String>,
+    searches: Vec<(usize, BTreeMap<String, BTreeSet<i64>>)>,
+) -> Vec<InboxRepository> {
+    let mut inbox: BTreeMap<String, InboxRepository> = BTreeMap::new();
+    for name in stored_inbox_repositories {
+        inbox_entry(&mut inbox, tracked_repositories, name);
+    }
+    for (account_index, found) in searches {
+        for (name, numbers) in found {
+            let repo = inbox_entry(&mut inbox, tracked_repositories, name);
+            let new_numbers: Vec<i64> = numbers
+                .into_iter()
+                .filter(|number| {
+                    !repo
+                        .numbers_by_account
+                        .values()
+                        .any(|taken| taken.contains(number))
+                })
+                .collect();
+            if !new_numbers.is_empty() {
+                repo.numbers_by_account
+                    .entry(account_index)
+                    .or_default()
+                    .extend(new_numbers);
+            }
+        }
+    }
+    inbox.into_values().collect()
+}
+
+fn inbox_entry<'a>(
+    inbox: &'a mut BTreeMap<String, InboxRepository>,
+    tracked_repositories: &[TrackedRepository],
+    name: String,
+) -> &'a mut InboxRepository {
+    let name = tracked_repositories
+        .iter()
+        .find(|tracked| tracked.repository.eq_ignore_ascii_case(&name))
+        .map_or(name, |tracked| tracked.repository.clone());
+    inbox
+        .entry(name.to_ascii_lowercase())
+        .or_insert_with(|| InboxRepository {
+            name,
+            numbers_by_account: BTreeMap::new(),
+        })
+}
+
+/// What the review inbox sync did. A failed search or repository leaves the
+/// PRs it covers as stored and does not stop the rest.
+#[derive(Default)]
+struct ReviewInboxSync {
+    results: Vec<RepoSyncResult>,
+    failed_searches: Vec<InboxSearchFailure>,
+    failed_repositories: Vec<RepositorySyncFailure>,
+}
+
+/// Sync the PRs the review inbox searches find for each account. In tracked
+/// repositories only PRs the repository sync did not store are added; in
+/// other repositories the inbox owns its PRs. In both, inbox PRs that left
+/// every inbox are dropped, unless an account could not be searched.
+async fn sync_review_inbox(
     repository: &DatabaseRepository,
-    github: &GitHubClient,
+    accounts: &[SyncAccount],
+    all_accounts_loaded: bool,
+    teams_by_account: &[Arc<Vec<String>>],
+    tracked_repositories: &[TrackedRepository],
     tracked_authors: &[String],
+) -> anyhow::Result<ReviewInboxSync> {
+    let mut sync = ReviewInboxSync::default();
+    let mut searches = Vec::new();
+    for (account_index, account) in accounts.iter().enumerate() {
+        if account.login.is_empty() {
+            continue;
+        }
+        match account.github.fetch_review_inbox().await {
+            Ok(found) => searches.push((account_index, found)),
+            Err(err) => sync.failed_searches.push(InboxSearchFailure {
+                username: account.username.clone(),
+                error: format!("{err:#}"),
+            }),
+        }
+    }
+    let drop_stale = all_accounts_loaded && sync.failed_searches.is_empty();
+
+    let inbox = collect_review_inbox(
+        tracked_repositories,
+        repository.get_review_inbox_repositories().await?,
+        searches,
+    );
+    for inbox_repo in inbox {
+        let repo_name = inbox_repo.name.clone();
+        let tracked_repo = tracked_repositories
+            .iter()
+            .find(|tracked| tracked.repository == repo_name);
+        match sync_review_inbox_repository(
+            repository,
+            accounts,
+            teams_by_account,
+            tracked_repo,
+            tracked_authors,
+            inbox_repo,
+            drop_stale,
+        )
+        .await
+        {
+            Ok(result) => sync.results.push(result),
+            Err(err) => sync.failed_repositories.push(RepositorySyncFailure {
+                repository: repo_name,
+                error: format!("{err:#}"),
+            }),
+        }
+    }
+
+    Ok(sync)
+}
+
+async fn sync_review_inbox_repository(
+    repository: &DatabaseRepository,
+    accounts: &[SyncAccount],
+    teams_by_account: &[Arc<Vec<String>>],
+    tracked_repo: Option<&TrackedRepository>,
+    tracked_authors: &[String],
+    inbox_repo: InboxRepository,
+    drop_stale: bool,
+) -> anyhow::Result<RepoSyncResult> {
+    let repo_name = inbox_repo.name;
+    let existing_prs = repository.get_prs_by_repository(&repo_name).await?;
+    let ci_policy = tracked_repo
+        .map(|tracked| tracked.ci_policy.clone())
+        .unwrap_or_default();
+    let known_comments_until = known_comments_until(&existing_prs);
+
+    let mut in_inbox: HashSet<i64> = HashSet::new();
+    let mut fresh_prs = Vec::new();
+    let mut all_comments = Vec::new();
+    let mut closed_pr_numbers = Vec::new();
+    let mut comments_complete_after = HashMap::new();
+    for (account_index, numbers) in inbox_repo.numbers_by_account {
+        in_inbox.extend(&numbers);
+        let account = &accounts[account_index];
+        let user_teams = &teams_by_account[account_index];
+        let pr_numbers: Vec<i64> = numbers
+            .into_iter()
+            .filter(|number| {
+                tracked_repo.is_none() || !existing_prs.iter().any(|pr| pr.number == *number)
+            })
+            .collect();
+        if pr_numbers.is_empty() {
+            continue;
+        }
+        let service::TrackedPullRequestSyncData {
+            open_prs: mut account_prs,
+            all_comments: mut account_comments,
+            closed_pr_numbers: account_closed,
+            comments_complete_after: account_complete_after,
+            ..
+        } = service::refresh_tracked_pull_requests_for_sync(
+            &account.github,
+            &repo_name,
+            &pr_numbers,
+            &account.login,
+            &ci_policy,
+            &known_comments_until,
+        )
+        .await?;
+        match tracked_repo {
+            Some(_) => tag_review_inbox_prs(
+                &mut account_prs,
+                &effective_tracked_authors(tracked_authors, &account.login),
+            ),
+            None => account_prs
+                .iter_mut()
+                .for_each(|pr| pr.source = PrSource::ReviewInbox),
+        }
+        mark_team_review_requests(&mut account_prs, user_teams);
+        mark_mentions(&mut account_comments, &account.login, user_teams);
+        for pr in &mut account_prs {
+            mark_mentions(&mut pr.comments, &account.login, user_teams);
+        }
+        fresh_prs.extend(account_prs);
+        all_comments.extend(account_comments);
+        closed_pr_numbers.extend(account_closed);
+        comments_complete_after.extend(account_complete_after);
+    }
+
+    // Outside tracked repositories only the refreshed PRs count as open;
+    // inside them the repository sync refreshes the stored ones.
+    let open_in_inbox: HashSet<i64> = match tracked_repo {
+        Some(_) => in_inbox,
+        None => fresh_prs.iter().map(|pr| pr.number).collect(),
+    };
+    let removed_numbers: Vec<i64> = if drop_stale {
+        stale_review_inbox_prs(&existing_prs, &open_in_inbox)
+    } else {
+        existing_prs
+            .iter()
+            .filter(|pr| {
+                pr.source == PrSource::ReviewInbox && closed_pr_numbers.contains(&pr.number)
+            })
+            .map(|pr| pr.number)
+            .collect()
+    };
+
+    let SyncDiff {
+        new_prs,
+        updated_prs,
+        removed_prs: _,
+    } = process_pull_request_sync_results(&existing_prs, &fresh_prs, Utc::now());
+    let updated_reason_counts = count_update_reasons(&updated_prs);
+    let (updated_data_prs, updated_attention_prs) = partition_updated_pull_requests(updated_prs);
+
+    let prs_to_save: Vec<&PullRequest> = new_prs.iter().chain(&updated_data_prs).collect();
+    let stale_comments = stale_comment_ids(&existing_prs, &all_comments, &comments_complete_after);
+    repository
+        .save_repository_sync(
+            &repo_name,
+            &prs_to_save,
+            &removed_numbers,
+            &stale_comments,
+            &all_comments,
+            None,
+        )
+        .await?;
+    let worktree_cleanup =
+        worktree::cleanup_closed_pr_worktrees(repository, &repo_name, &removed_numbers).await?;
+
+    let removed_set: HashSet<i64> = removed_numbers.into_iter().collect();
+    Ok(RepoSyncResult {
+        repo_name,
+        repo_index: 0,
+        new_prs,
+        updated_data_prs,
+        updated_attention_prs,
+        updated_reason_counts,
+        deleted_prs: existing_prs
+            .into_iter()
+            .filter(|pr| removed_set.contains(&pr.number))
+            .collect(),
+        removed_worktrees: worktree_cleanup.removed,
+        kept_worktrees: worktree_cleanup.kept,
+        removed_comments: stale_comments.len(),
+        previews: Vec::new(),
+    })
+}
+
+/// Stored comments of refreshed PRs that GitHub no longer returned although
+/// they fall inside the window it returned in full, i.e. deleted comments.
+fn stale_comment_ids(
+    existing_prs: &[PullRequest],
+    fetched_comments: &[PrComment],
+    comments_complete_after: &HashMap<i64, DateTime<Utc>>,
+) -> Vec<String> {
+    let fetched: HashSet<&str> = fetched_comments
+        .iter()
+        .map(|comment| comment.id.as_str())
+        .collect();
+    existing_prs
+        .iter()
+        .filter_map(|pr| Some((pr, *comments_complete_after.get(&pr.number)?)))
+        .flat_map(|(pr, complete_after)| {
+            pr.comments
+                .iter()
+                .filter(move |comment| comment.created_at > complete_after)
+        })
+        .filter(|comment| !fetched.contains(comment.id.as_str()))
+        .map(|comment| comment.id.clone())
+        .collect()
+}
+
+/// The newest stored comment of each PR, so sync only pages through comments
+/// posted since. PRs without stored comments fetch their full history.
+fn known_comments_until(existing_prs: &[PullRequest]) -> HashMap<i64, DateTime<Utc>> {
+    existing_prs
+        .iter()
+        .filter_map(|pr| {
+            pr.comments
+                .iter()
+                .map(|comment| comment.created_at)
+                .max()
+                .map(|created_at| (pr.number, created_at))
+        })
+        .collect()
+}
+
+/// What `sync_single_repo` needs besides the repository itself.
+struct RepoSyncContext {
+    github: GitHubClient,
+    /// Tracked authors plus the account's own login.
+    tracked_authors: Vec<String>,
+    /// Only discover and refresh this author's PRs. The repository's
+    /// watermark is then left alone since the other authors' PRs were not
+    /// looked at.
+    only_author: Option<String>,
+    username: String,
+    user_teams: Arc<Vec<String>>,
+    dry_run: bool,
+}
+
+async fn sync_single_repo(
+    repository: &DatabaseRepository,
+    context: &RepoSyncContext,
     tracked_repo: TrackedRepository,
     repo_index: usize,
-    username: &str,
 ) -> anyhow::Result<RepoSyncResult> {
     let repo_name = &tracked_repo.repository;
+    let github = &context.github;
+    let username = context.username.as_str();
+    let only_author = context.only_author.as_deref();
 
     // Step 1: Compute cutoff
     let discovery_cutoff = compute_discovery_cutoff(tracked_repo.last_synced_at, pr_age_cutoff());
 
     // Step 2: Fetch tracked PRs updated since the cutoff and refresh known open PRs.
-    let existing_prs = repository.get_prs_by_repository(repo_name).await?;
+    let existing_prs: Vec<PullRequest> = repository
+        .get_prs_by_repository(repo_name)
+        .await?
+        .into_iter()
+        .filter(|pr| only_author.is_none_or(|author| pr.author.eq_ignore_ascii_case(author)))
+        .collect();
+    let discovery_authors = match only_author {
+        Some(author) => vec![author.to_string()],
+        None => context.tracked_authors.clone(),
+    };
     let tracked_pr_numbers: Vec<i64> = existing_prs.iter().map(|pr| pr.number).collect();
+    let known_comments_until = known_comments_until(&existing_prs);
     let (discovery_sync_data, refresh_sync_data) = tokio::try_join!(
         service::fetch_tracked_pull_requests_for_sync(
             github,
             repo_name,
-            tracked_authors,
+            &discovery_authors,
             discovery_cutoff,
             username,
+            &tracked_repo.ci_policy,
+            &known_comments_until,
         ),
         service::refresh_tracked_pull_requests_for_sync(
             github,
             repo_name,
             &tracked_pr_numbers,
-            username
+            username,
+            &tracked_repo.ci_policy,
+            &known_comments_until,
         ),
     )?;
     let service::TrackedPullRequestSyncData {
@@ -263,7 +1593,16 @@ async fn sync_single_repo(
         all_comments,
         closed_pr_numbers,
         max_updated_at,
+        comments_complete_after,
     } = service::merge_tracked_pull_request_sync_data(discovery_sync_data, refresh_sync_data);
+    let mut fresh_prs = fresh_prs;
+    let mut all_comments = all_comments;
+    tag_review_inbox_prs(&mut fresh_prs, &context.tracked_authors);
+    mark_team_review_requests(&mut fresh_prs, &context.user_teams);
+    mark_mentions(&mut all_comments, username, &context.user_teams);
+    for pr in &mut fresh_prs {
+        mark_mentions(&mut pr.comments, username, &context.user_teams);
+    }
 
     // Step 3: Diff & persist.
     let SyncDiff {
@@ -272,41 +1611,55 @@ async fn sync_single_repo(
         removed_prs: _,
     } = process_pull_request_sync_results(&existing_prs, &fresh_prs, Utc::now());
 
+    let closed_set: HashSet<i64> = closed_pr_numbers.iter().copied().collect();
+    let deleted_prs: Vec<PullRequest> = existing_prs
+        .iter()
+        .filter(|pr| closed_set.contains(&pr.number))
+        .cloned()
+        .collect();
+    let previews = if context.dry_run {
+        preview_changes(&existing_prs, &new_prs, &updated_prs, &deleted_prs)
+    } else {
+        Vec::new()
+    };
+
     let updated_reason_counts = count_update_reasons(&updated_prs);
     let (updated_data_prs, updated_attention_prs) = partition_updated_pull_requests(updated_prs);
 
-    for pr in &new_prs {
-        repository.save_pr(pr).await?;
-    }
-    for pr in &updated_data_prs {
-        repository.save_pr(pr).await?;
-    }
-
-    // Delete closed/merged PRs reported by GitHub search.
-    for pr_number in &closed_pr_numbers {
-        repository.delete_pr(repo_name, *pr_number).await?;
-    }
-
-    // Persist comments for all open PRs returned by the search query.
-    for comment in all_comments {
-        repository.save_comment(&comment).await?;
-    }
-
-    // Step 4: Update last_synced_at using the GitHub-side watermark.
-    if let Some(max_ts) = max_updated_at {
-        let watermark = max_ts - chrono::Duration::seconds(1);
+    let mut worktree_cleanup = WorktreeCleanup::default();
+    let mut removed_comments = 0;
+    if !context.dry_run {
+        // Step 4: Persist the diff, the closed PRs, the comments deleted on
+        // GitHub, the comments of all open PRs returned by the search query
+        // and the GitHub-side watermark in one transaction. The watermark is
+        // left alone for author-scoped syncs.
+        let prs_to_save: Vec<&PullRequest> = new_prs.iter().chain(&updated_data_prs).collect();
+        let watermark = match (max_updated_at, only_author) {
+            (Some(max_ts), None) => Some(max_ts - chrono::Duration::seconds(1)),
+            _ => None,
+        };
+        let stale_comments =
+            stale_comment_ids(&existing_prs, &all_comments, &comments_complete_after);
+        removed_comments = stale_comments.len();
         repository
-            .update_tracked_repository_last_synced_at(repo_name, watermark)
+            .save_repository_sync(
+                repo_name,
+                &prs_to_save,
+                &closed_pr_numbers,
+                &stale_comments,
+                &all_comments,
+                watermark,
+            )
             .await?;
+
+        // Worktrees holding local work are kept and reported; the next sync
+        // checks them again.
+        worktree_cleanup =
+            worktree::cleanup_closed_pr_worktrees(repository, repo_name, &closed_pr_numbers)
+                .await?;
     }
 
     // Step 5: Build result.
-    let closed_set: HashSet<i64> = closed_pr_numbers.iter().copied().collect();
-    let deleted_prs: Vec<PullRequest> = existing_prs
-        .into_iter()
-        .filter(|pr| closed_set.contains(&pr.number))
-        .collect();
-
     Ok(RepoSyncResult {
         repo_name: repo_name.clone(),
         repo_index,
@@ -315,12 +1668,57 @@ async fn sync_single_repo(
         updated_attention_prs,
         updated_reason_counts,
         deleted_prs,
+        removed_worktrees: worktree_cleanup.removed,
+        kept_worktrees: worktree_cleanup.kept,
+        removed_comments,
+        previews,
     })
 }
 
+/// Per-PR changes of a sync diff, described against the stored PRs.
+fn preview_changes(
+    existing_prs: &[PullRequest],
+    new_prs: &[PullRequest],
+    updated_prs: &[UpdatedPullRequest],
+    closed_prs: &[PullRequest],
+) -> Vec<PrChangePreview> {
+    let preview = |pr: &PullRequest, change| PrChangePreview {
+        repository: pr.repository.clone(),
+        number: pr.number,
+        title: pr.title.clone(),
+        change,
+    };
+
+    let mut previews: Vec<PrChangePreview> = new_prs
+        .iter()
+        .map(|pr| preview(pr, PreviewChange::New))
+        .collect();
+    for updated in updated_prs {
+        let Some(old) = existing_prs
+            .iter()
+            .find(|pr| pr.number == updated.pr.number)
+        else {
+            continue;
+        };
+        let reasons = updated
+            .reasons
+            .iter()
+            .map(|reason| reason.describe(old, &updated.pr))
+            .collect();
+        previews.push(preview(&updated.pr, PreviewChange::Updated(reasons)));
+    }
+    previews.extend(
+        closed_prs
+            .iter()
+            .map(|pr| preview(pr, PreviewChange::Closed)),
+    );
+    previews
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{ApprovalStatus, CiPolicy, CiStatus, RepositoryDiscoveryRule};
     use chrono::TimeZone;
 
     fn dt(year: i32, month: u32, day: u32) -> DateTime<Utc> {
@@ -382,4 +1780,632 @@ mod tests {
 
         assert_eq!(result, vec!["Alice".to_string()]);
     }
+
+    #[test]
+    fn format_sync_summary_mentions_removed_worktrees_only_when_present() {
+        let mut summary = SyncRunSummary::default();
+        assert!(!format_sync_summary(&summary).contains("removed_worktrees"));
+
+        summary.removed_worktrees = 2;
+        assert!(format_sync_summary(&summary).ends_with(" removed_worktrees=2"));
+
+        summary.kept_worktrees.push(KeptWorktree {
+            repository: "octo/widgets".to_string(),
+            pr_number: 42,
+            path: "/tmp/pr-42".to_string(),
+            reason: "it has uncommitted changes or untracked files".to_string(),
+        });
+        assert!(format_sync_summary(&summary)
+            .ends_with(" removed_worktrees=2 kept_worktrees=octo/widgets#42"));
+    }
+
+    #[test]
+    fn format_sync_progress_reports_failed_rename_checks() {
+        let progress = SyncProgress::RenameCheckFailed {
+            username: "work".to_string(),
+            error: "Bad credentials".to_string(),
+        };
+
+        assert_eq!(
+            format_sync_progress(&progress).as_deref(),
+            Some("[sync] rename check failed for work: Bad credentials")
+        );
+    }
+
+    #[test]
+    fn format_sync_summary_lists_failed_inbox_searches() {
+        let summary = SyncRunSummary {
+            failed_inbox_searches: vec![InboxSearchFailure {
+                username: "work".to_string(),
+                error: "rate limited".to_string(),
+            }],
+            ..SyncRunSummary::default()
+        };
+
+        assert!(format_sync_summary(&summary).ends_with(" failed_inbox=work"));
+    }
+
+    #[test]
+    fn format_sync_summary_lists_failed_repositories() {
+        let mut summary = SyncRunSummary::default();
+        assert!(!format_sync_summary(&summary).contains("failed="));
+
+        summary.failed_repositories = vec![
+            RepositorySyncFailure {
+                repository: "octo/gone".to_string(),
+                error: "not found".to_string(),
+            },
+            RepositorySyncFailure {
+                repository: "octo/private".to_string(),
+                error: "forbidden".to_string(),
+            },
+        ];
+        assert!(format_sync_summary(&summary).ends_with(" failed=octo/gone,octo/private"));
+    }
+
+    #[test]
+    fn repository_renames_follow_node_ids() {
+        let with_node = |name: &str, node_id: Option<&str>| TrackedRepository {
+            repository: name.to_string(),
+            node_id: node_id.map(str::to_string),
+            ..tracked(None)
+        };
+        let tracked = vec![
+            with_node("octo/widgets", Some("R_1")),
+            with_node("octo/Gadgets", Some("R_2")),
+            with_node("octo/tools", Some("R_3")),
+            with_node("octo/legacy", None),
+            with_node("octo/deleted", Some("R_4")),
+        ];
+        let names_by_node_id = HashMap::from([
+            ("R_1".to_string(), "octo/widgets".to_string()),
+            ("R_2".to_string(), "octo/gadgets".to_string()),
+            ("R_3".to_string(), "acme/tools".to_string()),
+        ]);
+
+        assert_eq!(
+            repository_renames(&tracked, &names_by_node_id),
+            vec![
+                RepositoryRename {
+                    from: "octo/Gadgets".to_string(),
+                    to: "octo/gadgets".to_string(),
+                },
+                RepositoryRename {
+                    from: "octo/tools".to_string(),
+                    to: "acme/tools".to_string(),
+                },
+            ]
+        );
+    }
+
+    fn pr_requesting(teams: &[&str]) -> PullRequest {
+        PullRequest {
+            number: 1,
+            title: String::new(),
+            repository: "acme/repo".to_string(),
+            author: "bob".to_string(),
+            head_sha: String::new(),
+            draft: false,
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+            ci_status: CiStatus::Pending,
+            last_comment_at: DateTime::UNIX_EPOCH,
+            last_commit_at: DateTime::UNIX_EPOCH,
+            last_ci_status_update_at: DateTime::UNIX_EPOCH,
+            approval_status: ApprovalStatus::None,
+            last_review_status_update_at: DateTime::UNIX_EPOCH,
+            last_acknowledged_at: None,
+            requested_reviewers: Vec::new(),
+            requested_teams: teams.iter().map(|team| team.to_string()).collect(),
+            user_has_reviewed: false,
+            team_review_requested: false,
+            comments: Vec::new(),
+            checks: Vec::new(),
+            review_threads: Vec::new(),
+            last_thread_update_at: DateTime::UNIX_EPOCH,
+            comments_truncated: false,
+            source: PrSource::Tracked,
+        }
+    }
+
+    #[test]
+    fn mark_team_review_requests_matches_teams_case_insensitively() {
+        let mut prs = vec![
+            pr_requesting(&["acme/Platform"]),
+            pr_requesting(&["acme/design"]),
+        ];
+
+        mark_team_review_requests(&mut prs, &["acme/platform".to_string()]);
+
+        assert!(prs[0].team_review_requested);
+        assert!(!prs[1].team_review_requested);
+    }
+
+    #[test]
+    fn mark_mentions_ignores_the_users_own_comments() {
+        let comment = |author: &str| PrComment {
+            id: author.to_string(),
+            repository: "acme/repo".to_string(),
+            pr_number: 1,
+            author: author.to_string(),
+            body: "@alice and @acme/platform".to_string(),
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+            is_review_comment: false,
+            review_state: None,
+            path: None,
+            line: None,
+            diff_hunk: None,
+            is_outdated: false,
+            mentions_user: false,
+            edited_at: None,
+            is_minimized: false,
+        };
+        let mut comments = vec![comment("bob"), comment("Alice")];
+
+        mark_mentions(&mut comments, "alice", &[]);
+
+        assert!(comments[0].mentions_user);
+        assert!(!comments[1].mentions_user);
+    }
+
+    #[test]
+    fn stale_comment_ids_only_drops_missing_comments_inside_the_fetched_window() {
+        let comment = |id: &str, created_at: i64| PrComment {
+            id: id.to_string(),
+            repository: "acme/repo".to_string(),
+            pr_number: 1,
+            author: "bob".to_string(),
+            body: String::new(),
+            created_at: DateTime::from_timestamp(created_at, 0).unwrap(),
+            updated_at: DateTime::from_timestamp(created_at, 0).unwrap(),
+            is_review_comment: false,
+            review_state: None,
+            path: None,
+            line: None,
+            diff_hunk: None,
+            is_outdated: false,
+            mentions_user: false,
+            edited_at: None,
+            is_minimized: false,
+        };
+        let mut stored = pr_requesting(&[]);
+        stored.comments = vec![
+            comment("old", 10),
+            comment("kept", 30),
+            comment("deleted", 40),
+        ];
+        let mut not_refreshed = pr_requesting(&[]);
+        not_refreshed.number = 2;
+        not_refreshed.comments = vec![comment("other", 40)];
+        let complete_after = HashMap::from([(1, DateTime::from_timestamp(20, 0).unwrap())]);
+
+        let stale = stale_comment_ids(
+            &[stored, not_refreshed],
+            &[comment("kept", 30), comment("new", 50)],
+            &complete_after,
+        );
+
+        assert_eq!(stale, vec!["deleted".to_string()]);
+    }
+
+    #[test]
+    fn newly_created_repositories_skips_older_and_tracked_repositories() {
+        let followed = FollowedDiscoveryRule {
+            id: 1,
+            rule: RepositoryDiscoveryRule {
+                org: "acme".to_string(),
+                ..RepositoryDiscoveryRule::default()
+            },
+            account: None,
+            created_at: dt(2025, 6, 10),
+            seen_until: dt(2025, 6, 10),
+        };
+        let repository = |name: &str, created_at| DiscoveredRepository {
+            full_name: name.to_string(),
+            node_id: None,
+            archived: false,
+            topics: Vec::new(),
+            created_at,
+        };
+        let discovered = vec![
+            repository("acme/old", dt(2025, 6, 1)),
+            repository("acme/Tracked", dt(2025, 6, 11)),
+            repository("acme/new", dt(2025, 6, 12)),
+        ];
+
+        assert_eq!(
+            newly_created_repositories(&followed, &discovered, &["acme/tracked".to_string()])
+                .into_iter()
+                .map(|repository| repository.full_name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["acme/new"]
+        );
+    }
+
+    #[tokio::test]
+    async fn followed_repositories_stay_untracked_once_removed() {
+        let repository = database_with_users("follow-rules", &[]).await;
+        let rule = RepositoryDiscoveryRule {
+            org: "acme".to_string(),
+            ..RepositoryDiscoveryRule::default()
+        };
+        repository
+            .save_repository_follow_rule(&rule, None)
+            .await
+            .unwrap();
+        let discovered = vec![DiscoveredRepository {
+            full_name: "acme/new".to_string(),
+            node_id: None,
+            archived: false,
+            topics: Vec::new(),
+            // GitHub reports whole seconds, as the database stores them.
+            created_at: DateTime::from_timestamp(Utc::now().timestamp() + 60, 0).unwrap(),
+        }];
+        let followed = repository.get_repository_follow_rules().await.unwrap();
+
+        let added = track_discovered_repositories(&repository, &followed[0], &discovered, &[])
+            .await
+            .unwrap();
+        assert_eq!(added, vec!["acme/new".to_string()]);
+
+        repository
+            .delete_tracked_repository("acme/new")
+            .await
+            .unwrap();
+        let followed = repository.get_repository_follow_rules().await.unwrap();
+        let added = track_discovered_repositories(&repository, &followed[0], &discovered, &[])
+            .await
+            .unwrap();
+
+        assert!(added.is_empty());
+        assert!(repository
+            .get_tracked_repositories()
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn tag_review_inbox_prs_keeps_tracked_authors_tracked() {
+        let mut prs = vec![pr_requesting(&[]), pr_requesting(&[])];
+        prs[1].author = "carol".to_string();
+
+        tag_review_inbox_prs(&mut prs, &["Bob".to_string()]);
+
+        assert_eq!(prs[0].source, PrSource::Tracked);
+        assert_eq!(prs[1].source, PrSource::ReviewInbox);
+    }
+
+    #[test]
+    fn collect_review_inbox_uses_stored_names_and_the_first_account_per_pr() {
+        let searches = vec![
+            (
+                0,
+                BTreeMap::from([("Octo/Widgets".to_string(), BTreeSet::from([1, 2]))]),
+            ),
+            (
+                1,
+                BTreeMap::from([
+                    ("octo/WIDGETS".to_string(), BTreeSet::from([2, 3])),
+                    ("Acme/Api".to_string(), BTreeSet::from([7])),
+                ]),
+            ),
+        ];
+
+        let inbox = collect_review_inbox(
+            &[tracked(None)],
+            vec!["acme/api".to_string(), "acme/old".to_string()],
+            searches,
+        );
+
+        let names: Vec<&str> = inbox.iter().map(|repo| repo.name.as_str()).collect();
+        assert_eq!(names, vec!["acme/api", "acme/old", "octo/widgets"]);
+        assert_eq!(
+            inbox[0].numbers_by_account,
+            BTreeMap::from([(1, BTreeSet::from([7]))])
+        );
+        assert!(inbox[1].numbers_by_account.is_empty());
+        assert_eq!(
+            inbox[2].numbers_by_account,
+            BTreeMap::from([(0, BTreeSet::from([1, 2])), (1, BTreeSet::from([3]))])
+        );
+    }
+
+    #[test]
+    fn stale_review_inbox_prs_only_drops_inbox_prs_gone_from_inbox() {
+        let mut tracked_pr = pr_requesting(&[]);
+        tracked_pr.number = 1;
+        let mut still_open = pr_requesting(&[]);
+        still_open.number = 2;
+        still_open.source = PrSource::ReviewInbox;
+        let mut gone = pr_requesting(&[]);
+        gone.number = 3;
+        gone.source = PrSource::ReviewInbox;
+
+        let stale = stale_review_inbox_prs(&[tracked_pr, still_open, gone], &HashSet::from([2]));
+
+        assert_eq!(stale, vec![3]);
+    }
+
+    fn account(username: &str, is_active: bool) -> SyncAccount {
+        SyncAccount {
+            username: username.to_string(),
+            login: username.to_string(),
+            github: GitHubClient::new(format!("token-{username}")).expect("client builds"),
+            is_active,
+        }
+    }
+
+    fn tracked(account: Option<&str>) -> TrackedRepository {
+        TrackedRepository {
+            repository: "octo/widgets".to_string(),
+            last_synced_at: None,
+            account: account.map(str::to_string),
+            ci_policy: CiPolicy::RequiredOnly,
+            node_id: None,
+            last_sync_error: None,
+        }
+    }
+
+    #[test]
+    fn preview_changes_lists_new_updated_and_closed_prs() {
+        let numbered = |number: i64, title: &str| PullRequest {
+            number,
+            title: title.to_string(),
+            ..pr_requesting(&[])
+        };
+        let existing = vec![numbered(1, "Old title"), numbered(2, "Closing")];
+        let fresh = vec![numbered(1, "New title"), numbered(3, "Brand new")];
+        let diff = process_pull_request_sync_results(&existing, &fresh, Utc::now());
+
+        let previews = preview_changes(
+            &existing,
+            &diff.new_prs,
+            &diff.updated_prs,
+            &[existing[1].clone()],
+        );
+
+        let lines: Vec<String> = previews.iter().map(format_pr_change_preview).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "new     acme/repo#3 Brand new",
+                "updated acme/repo#1 New title (title: \"Old title\" -> \"New title\")",
+                "closed  acme/repo#2 Closing",
+            ]
+        );
+    }
+
+    #[test]
+    fn sync_options_includes_listed_repositories_ignoring_case() {
+        assert!(SyncOptions::default().includes(&tracked(None)));
+        assert!(SyncOptions::repository("Octo/Widgets").includes(&tracked(None)));
+        assert!(!SyncOptions::repository("octo/gadgets").includes(&tracked(None)));
+    }
+
+    #[test]
+    fn sync_options_with_author_is_not_full() {
+        let options = SyncOptions {
+            author: Some("alice".to_string()),
+            ..SyncOptions::default()
+        };
+
+        assert!(!options.is_full());
+        assert!(options.includes(&tracked(None)));
+    }
+
+    #[test]
+    fn unassigned_repositories_fail_when_the_active_account_is_skipped() {
+        let accounts = vec![account("personal", false)];
+        let skipped = vec![SkippedAccount {
+            username: "work".to_string(),
+            error: "environment variable 'TOKEN' is not set".to_string(),
+            is_active: true,
+        }];
+
+        assert_eq!(
+            skipped_account_for_repository(&accounts, &skipped, &tracked(None)),
+            Some(&skipped[0])
+        );
+        assert_eq!(
+            skipped_account_for_repository(&accounts, &skipped, &tracked(Some("removed"))),
+            Some(&skipped[0])
+        );
+        assert_eq!(
+            skipped_account_for_repository(&accounts, &skipped, &tracked(Some("personal"))),
+            None
+        );
+    }
+
+    #[test]
+    fn account_for_repository_prefers_assigned_account() {
+        let accounts = vec![account("work", true), account("personal", false)];
+
+        assert_eq!(
+            account_index_for_repository(&accounts, &tracked(Some("Personal"))),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn repositories_of_skipped_accounts_do_not_fall_back() {
+        let accounts = vec![account("work", true)];
+        let skipped = vec![SkippedAccount {
+            username: "personal".to_string(),
+            error: "environment variable 'TOKEN' is not set".to_string(),
+            is_active: false,
+        }];
+
+        assert_eq!(
+            skipped_account_for_repository(&accounts, &skipped, &tracked(Some("Personal"))),
+            Some(&skipped[0])
+        );
+        assert_eq!(
+            skipped_account_for_repository(&accounts, &skipped, &tracked(Some("work"))),
+            None
+        );
+        assert_eq!(
+            skipped_account_for_repository(&accounts, &skipped, &tracked(None)),
+            None
+        );
+
+        let summary = SyncRunSummary {
+            skipped_accounts: skipped,
+            ..SyncRunSummary::default()
+        };
+        assert!(format_sync_summary(&summary).ends_with(" skipped_accounts=personal"));
+    }
+
+    #[test]
+    fn account_for_repository_falls_back_to_active_account() {
+        let accounts = vec![account("work", false), account("personal", true)];
+
+        assert_eq!(
+            account_index_for_repository(&accounts, &tracked(None)),
+            Some(1)
+        );
+        assert_eq!(
+            account_index_for_repository(&accounts, &tracked(Some("removed"))),
+            Some(1)
+        );
+        assert_eq!(account_index_for_repository(&[], &tracked(None)), None);
+    }
+
+    #[test]
+    fn describe_names_the_scope_of_a_sync() {
+        assert_eq!(SyncOptions::default().describe(), "all");
+
+        let options = SyncOptions {
+            repositories: vec!["acme/api".to_string(), "acme/web".to_string()],
+            author: Some("bob".to_string()),
+            dry_run: false,
+        };
+        assert_eq!(options.describe(), "acme/api acme/web author=bob");
+    }
+
+    #[test]
+    fn format_run_duration_scales_its_unit() {
+        assert_eq!(
+            format_run_duration(chrono::Duration::milliseconds(850)),
+            "850ms"
+        );
+        assert_eq!(
+            format_run_duration(chrono::Duration::milliseconds(4_230)),
+            "4.2s"
+        );
+        assert_eq!(format_run_duration(chrono::Duration::seconds(192)), "3m12s");
+    }
+
+    fn sync_run(repositories: Vec<SyncRunRepository>) -> SyncRun {
+        let started_at = Utc::now();
+        SyncRun {
+            id: 12,
+            trigger: SyncTrigger::Tui,
+            scope: "all".to_string(),
+            dry_run: false,
+            started_at,
+            finished_at: Some(started_at + chrono::Duration::milliseconds(4_200)),
+            new_prs: 1,
+            updated_data_prs: 2,
+            updated_attention_prs: 1,
+            deleted_prs: 0,
+            graphql_requests: 7,
+            graphql_cost: 9,
+            rate_limit_remaining: None,
+            rate_limit_reset_at: None,
+            error: None,
+            log: Vec::new(),
+            repositories,
+        }
+    }
+
+    #[test]
+    fn format_sync_run_shows_counts_usage_and_status() {
+        let line = format_sync_run(&sync_run(Vec::new()));
+
+        assert!(line.starts_with("#12 "));
+        assert!(line.ends_with(" tui all 4.2s ok new=1 updated=3 deleted=0 graphql=7 cost=9"));
+
+        let mut run = sync_run(Vec::new());
+        run.rate_limit_remaining = Some(4_812);
+        assert!(format_sync_run(&run).ends_with(" cost=9 remaining=4812"));
+    }
+
+    #[test]
+    fn format_sync_run_reports_failures() {
+        let failed_repository = SyncRunRepository {
+            repository: "acme/api".to_string(),
+            duration_ms: 10,
+            new_prs: 0,
+            updated_prs: 0,
+            deleted_prs: 0,
+            error: Some("boom".to_string()),
+        };
+        assert_eq!(
+            format_sync_run_repository(&failed_repository),
+            "acme/api 10ms new=0 updated=0 deleted=0 failed: boom"
+        );
+        assert!(format_sync_run(&sync_run(vec![failed_repository])).contains(" 1 failed "));
+
+        let mut failed = sync_run(Vec::new());
+        failed.error = Some("no account".to_string());
+        assert!(format_sync_run(&failed).contains(" failed "));
+
+        let mut unfinished = sync_run(Vec::new());
+        unfinished.finished_at = None;
+        unfinished.dry_run = true;
+        assert!(format_sync_run(&unfinished).contains(" tui dry-run all - unfinished "));
+    }
+
+    async fn database_with_users(name: &str, users: &[User]) -> DatabaseRepository {
+        let path = std::env::temp_dir().join(format!("prt-{name}-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let repository = DatabaseRepository::connect(&path.display().to_string())
+            .await
+            .unwrap();
+        repository.apply_migrations().await.unwrap();
+        for user in users {
+            repository.save_user(user).await.unwrap();
+        }
+        repository
+    }
+
+    fn user(username: &str, credential: CredentialSource) -> User {
+        User {
+            username: username.to_string(),
+            credential,
+            is_active: false,
+            scopes: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn load_sync_accounts_skips_accounts_whose_credential_fails() {
+        let missing = CredentialSource::Env("PRT_TEST_MISSING_TOKEN_VARIABLE".to_string());
+        let repository = database_with_users(
+            "load-accounts",
+            &[
+                user("work", CredentialSource::Stored("token".to_string())),
+                user("personal", missing.clone()),
+            ],
+        )
+        .await;
+
+        let loaded = load_sync_accounts(&repository).await.unwrap();
+
+        let usernames: Vec<&str> = loaded
+            .accounts
+            .iter()
+            .map(|account| account.username.as_str())
+            .collect();
+        assert_eq!(usernames, vec!["work"]);
+        assert_eq!(loaded.skipped.len(), 1);
+        assert_eq!(loaded.skipped[0].username, "personal");
+
+        let repository =
+            database_with_users("load-accounts-none", &[user("personal", missing)]).await;
+        let err = load_sync_accounts(&repository).await.err().unwrap();
+        assert!(err.to_string().contains("personal"));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::models::PullRequest;
+use crate::models::{PullRequest, ReviewLauncher};
 use crate::tui::navigation::Screen;
 use crate::tui::tasks::BackgroundJob;
 
@@ -6,6 +6,7 @@ pub enum TuiAction {
     Continue,
     Quit,
     SwitchScreen(Screen),
-    ReviewPr(Box<PullRequest>),
+    ShowPrDetail(Box<PullRequest>),
+    ReviewPr(Box<PullRequest>, ReviewLauncher),
     StartJob(BackgroundJob),
 }
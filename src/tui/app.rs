@@ -1,7 +1,4 @@
-use std::env;
 use std::io;
-use std::path::PathBuf;
-use std::process::Command;
 use std::time::Duration;
 
 use crossterm::event::{self, Event};
@@ -14,15 +11,20 @@ use ratatui::Terminal;
 use tokio::sync::mpsc;
 
 use crate::db::DatabaseRepository;
-use crate::models::PullRequest;
+use crate::launcher::{spawn_review_launcher, LaunchContext};
+use crate::models::{PullRequest, ReviewLauncher};
 use crate::pr_repository::{PrOwnerFilter, PrStatusFilter};
 use crate::sync::{format_sync_progress, format_sync_summary};
 use crate::tui::action::TuiAction;
 use crate::tui::authors;
 use crate::tui::navigation::Screen;
+use crate::tui::pr_detail;
 use crate::tui::pr_list;
+use crate::tui::repositories;
 use crate::tui::state::SharedState;
+use crate::tui::sync_history;
 use crate::tui::tasks::{spawn_full_sync, spawn_teams_fetch, BackgroundJob, BackgroundMessage};
+use crate::worktree::{format_kept_worktree, format_worktree_checkout};
 
 /// Application state containing all screen states and shared data.
 pub struct AppState {
@@ -30,8 +32,14 @@ pub struct AppState {
     pub shared: SharedState,
     /// State for the PR List screen.
     pub pr_list: pr_list::State,
+    /// State for the PR detail screen.
+    pub pr_detail: pr_detail::State,
     /// State for the Authors from Teams screen.
     pub authors: authors::State,
+    /// State for the Repositories screen.
+    pub repositories: repositories::State,
+    /// State for the Sync history screen.
+    pub sync_history: sync_history::State,
     /// Currently active screen.
     pub current_screen: Screen,
 }
@@ -42,7 +50,10 @@ impl AppState {
         Self {
             shared,
             pr_list: pr_list::State::new(),
+            pr_detail: pr_detail::State::new(),
             authors: authors::State::new(),
+            repositories: repositories::State::new(),
+            sync_history: sync_history::State::new(),
             current_screen: Screen::PrList,
         }
     }
@@ -92,19 +103,14 @@ fn restore_terminal() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn review_pr_in_octo_mode(pr: &PullRequest) {
-    let home_dir = env::var_os("HOME").unwrap_or_else(|| "~".into());
-    let repo_path = PathBuf::from(home_dir)
-        .join("code")
-        .join(pr.repository_name());
-    let _ = Command::new("ghostty")
-        .arg("+new-window")
-        .arg(format!("--working-directory={}", repo_path.display()))
-        .arg("-e")
-        .arg("fish")
-        .arg("-c")
-        .arg(format!("pr_review {} {}", repo_path.display(), pr.number))
-        .spawn();
+async fn launch_review(
+    repo: &DatabaseRepository,
+    pr: &PullRequest,
+    launcher: &ReviewLauncher,
+) -> anyhow::Result<()> {
+    let local_path = repo.get_repository_local_path(&pr.repository).await?;
+    let context = LaunchContext::for_pull_request(pr, local_path);
+    spawn_review_launcher(launcher, &context)
 }
 
 /// Main TUI event loop.
@@ -135,6 +141,9 @@ async fn run_tui_inner(
                     spinner_tick = 0;
 
                     let summary = result?;
+                    for kept in &summary.kept_worktrees {
+                        app_state.pr_list.push_sync_log(format_kept_worktree(kept));
+                    }
                     app_state
                         .pr_list
                         .push_sync_log(format_sync_summary(&summary));
@@ -158,7 +167,27 @@ async fn run_tui_inner(
                         .dashboard
                         .section(PrOwnerFilter::Mine, status)
                         .len();
-                    app_state.pr_list.clamp_cursors(tracked_len, mine_len);
+                    let inbox_len = app_state
+                        .shared
+                        .dashboard
+                        .section(PrOwnerFilter::Inbox, status)
+                        .len();
+                    app_state
+                        .pr_list
+                        .clamp_cursors(tracked_len, mine_len, inbox_len);
+                    app_state.pr_detail.refresh(&app_state.shared.dashboard.prs);
+                    repositories::events::reload(
+                        &mut app_state.repositories,
+                        &app_state.shared,
+                        repo,
+                    )
+                    .await?;
+                    if app_state.current_screen == Screen::Repositories {
+                        app_state.repositories.notice = Some(format_sync_summary(&summary));
+                    }
+                    if app_state.current_screen == Screen::SyncHistory {
+                        sync_history::events::reload(&mut app_state.sync_history, repo).await?;
+                    }
                 }
                 BackgroundMessage::TeamsFetchFinished(result) => {
                     active_job = None;
@@ -177,6 +206,34 @@ async fn run_tui_inner(
                         }
                     }
                 }
+                BackgroundMessage::WorktreeCheckoutFinished(result) => {
+                    active_job = None;
+                    spinner_tick = 0;
+                    match result {
+                        Ok(checkout) => {
+                            app_state.shared.notice = Some(format_worktree_checkout(&checkout));
+                        }
+                        Err(e) => app_state.shared.error = Some(e.to_string()),
+                    }
+                }
+                BackgroundMessage::RepositoryAddFinished(result) => {
+                    active_job = None;
+                    spinner_tick = 0;
+                    match result {
+                        Ok(repository) => {
+                            repositories::events::reload(
+                                &mut app_state.repositories,
+                                &app_state.shared,
+                                repo,
+                            )
+                            .await?;
+                            app_state.repositories.select(&repository);
+                            app_state.repositories.notice =
+                                Some(format!("Repository '{repository}' added"));
+                        }
+                        Err(e) => app_state.repositories.error = Some(e.to_string()),
+                    }
+                }
             }
         }
 
@@ -191,9 +248,28 @@ async fn run_tui_inner(
                     spinner_tick,
                 );
             }
+            Screen::PrDetail => {
+                pr_detail::render::draw(frame, &app_state.pr_detail, &app_state.shared);
+            }
             Screen::AuthorsFromTeams => {
                 authors::render::draw(frame, &app_state.authors, active_job, spinner_tick);
             }
+            Screen::Repositories => {
+                repositories::render::draw(
+                    frame,
+                    &app_state.repositories,
+                    active_job,
+                    spinner_tick,
+                );
+            }
+            Screen::SyncHistory => {
+                sync_history::render::draw(
+                    frame,
+                    &app_state.sync_history,
+                    active_job,
+                    spinner_tick,
+                );
+            }
         })?;
 
         // Update spinner if there's an active job
@@ -207,6 +283,7 @@ async fn run_tui_inner(
                 match app_state.current_screen {
                     Screen::PrList => {
                         app_state.shared.error = None;
+                        app_state.shared.notice = None;
 
                         match pr_list::events::handle_event(
                             key,
@@ -227,8 +304,30 @@ async fn run_tui_inner(
                                     active_job = Some(BackgroundJob::TeamsFetch);
                                     spawn_teams_fetch(repo.clone(), tx.clone());
                                 }
+                                if screen == Screen::Repositories {
+                                    app_state.repositories = repositories::State::new();
+                                    repositories::events::reload(
+                                        &mut app_state.repositories,
+                                        &app_state.shared,
+                                        repo,
+                                    )
+                                    .await?;
+                                }
+                                if screen == Screen::SyncHistory {
+                                    app_state.sync_history = sync_history::State::new();
+                                    sync_history::events::reload(&mut app_state.sync_history, repo)
+                                        .await?;
+                                }
+                            }
+                            TuiAction::ShowPrDetail(pr) => {
+                                app_state.pr_detail.open(*pr);
+                                app_state.current_screen = Screen::PrDetail;
+                            }
+                            TuiAction::ReviewPr(pr, launcher) => {
+                                if let Err(err) = launch_review(repo, &pr, &launcher).await {
+                                    app_state.shared.error = Some(err.to_string());
+                                }
                             }
-                            TuiAction::ReviewPr(pr) => review_pr_in_octo_mode(&pr),
                             TuiAction::StartJob(job) => {
                                 active_job = Some(job);
                                 spinner_tick = 0;
@@ -236,6 +335,16 @@ async fn run_tui_inner(
                             TuiAction::Continue => {}
                         }
                     }
+                    Screen::PrDetail => {
+                        app_state.shared.error = None;
+                        if let TuiAction::SwitchScreen(screen) = pr_detail::events::handle_event(
+                            key,
+                            &mut app_state.pr_detail,
+                            &mut app_state.shared,
+                        ) {
+                            app_state.current_screen = screen;
+                        }
+                    }
                     Screen::AuthorsFromTeams => {
                         match authors::events::handle_event(key, &mut app_state.authors, repo)
                             .await?
@@ -244,7 +353,53 @@ async fn run_tui_inner(
                             TuiAction::SwitchScreen(screen) => {
                                 app_state.current_screen = screen;
                             }
-                            TuiAction::ReviewPr(_) => {}
+                            TuiAction::ShowPrDetail(_) => {}
+                            TuiAction::ReviewPr(..) => {}
+                            TuiAction::StartJob(_) => {}
+                            TuiAction::Continue => {}
+                        }
+                    }
+                    Screen::Repositories => {
+                        match repositories::events::handle_event(
+                            key,
+                            &mut app_state.repositories,
+                            &mut app_state.shared,
+                            &active_job,
+                            repo,
+                            &tx,
+                        )
+                        .await?
+                        {
+                            TuiAction::Quit => should_quit = true,
+                            TuiAction::SwitchScreen(screen) => {
+                                app_state.current_screen = screen;
+                            }
+                            TuiAction::StartJob(job) => {
+                                if matches!(job, BackgroundJob::FullSync) {
+                                    app_state.pr_list.clear_sync_logs();
+                                }
+                                active_job = Some(job);
+                                spinner_tick = 0;
+                            }
+                            TuiAction::ShowPrDetail(_) => {}
+                            TuiAction::ReviewPr(..) => {}
+                            TuiAction::Continue => {}
+                        }
+                    }
+                    Screen::SyncHistory => {
+                        match sync_history::events::handle_event(
+                            key,
+                            &mut app_state.sync_history,
+                            repo,
+                        )
+                        .await?
+                        {
+                            TuiAction::Quit => should_quit = true,
+                            TuiAction::SwitchScreen(screen) => {
+                                app_state.current_screen = screen;
+                            }
+                            TuiAction::ShowPrDetail(_) => {}
+                            TuiAction::ReviewPr(..) => {}
                             TuiAction::StartJob(_) => {}
                             TuiAction::Continue => {}
                         }
@@ -2,8 +2,11 @@ pub mod action;
 pub mod app;
 pub mod authors;
 pub mod navigation;
+pub mod pr_detail;
 pub mod pr_list;
+pub mod repositories;
 pub mod state;
+pub mod sync_history;
 pub mod tasks;
 pub mod widgets;
 
@@ -2,7 +2,10 @@
 #[derive(Clone, Copy, PartialEq)]
 pub enum Screen {
     PrList,
+    PrDetail,
     AuthorsFromTeams,
+    Repositories,
+    SyncHistory,
 }
 
 /// Which view mode for the PR list.
@@ -35,6 +38,7 @@ impl ViewMode {
 pub enum PrPane {
     Tracked,
     Mine,
+    Inbox,
 }
 
 /// Which pane is focused on the Authors screen.
@@ -0,0 +1,53 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+
+use crate::tui::action::TuiAction;
+use crate::tui::navigation::Screen;
+use crate::tui::pr_detail::State;
+use crate::tui::state::SharedState;
+
+/// Handle a key event for the PR detail screen.
+pub fn handle_event(key_event: KeyEvent, state: &mut State, shared: &mut SharedState) -> TuiAction {
+    if key_event.kind != KeyEventKind::Press {
+        return TuiAction::Continue;
+    }
+
+    match key_event.code {
+        KeyCode::Esc | KeyCode::Char('q') => TuiAction::SwitchScreen(Screen::PrList),
+
+        KeyCode::Up | KeyCode::Char('k') => {
+            state.move_up();
+            TuiAction::Continue
+        }
+
+        KeyCode::Down | KeyCode::Char('j') => {
+            state.move_down();
+            TuiAction::Continue
+        }
+
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            if let Some(check) = state.selected_check() {
+                match &check.details_url {
+                    Some(url) => {
+                        let _ = open::that(url);
+                    }
+                    None => {
+                        shared.error = Some(format!(
+                            "check '{}' has no details link",
+                            check.display_name()
+                        ))
+                    }
+                }
+            }
+            TuiAction::Continue
+        }
+
+        KeyCode::Char('o') => {
+            if let Some(pr) = &state.pr {
+                let _ = open::that(pr.url());
+            }
+            TuiAction::Continue
+        }
+
+        _ => TuiAction::Continue,
+    }
+}
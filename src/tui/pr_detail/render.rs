@@ -0,0 +1,234 @@
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, HighlightSpacing, List, ListItem, ListState, Paragraph};
+
+use crate::models::{PrCheck, PrComment, PrReviewThread, PullRequest};
+use crate::tui::pr_detail::{format_check_duration, State};
+use crate::tui::pr_list::state::clamp_cursor;
+use crate::tui::state::{truncate, SharedState};
+use crate::tui::widgets::{approval_badge, ci_label, ci_style, threads_badge};
+
+/// Draw the PR detail screen.
+pub fn draw(frame: &mut ratatui::Frame<'_>, state: &State, shared: &SharedState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4),      // header
+            Constraint::Min(1),         // checks
+            Constraint::Percentage(30), // review threads
+            Constraint::Percentage(30), // inline comments
+            Constraint::Length(2),      // footer
+        ])
+        .split(frame.area());
+
+    let Some(pr) = &state.pr else {
+        let empty = Paragraph::new("No pull request selected")
+            .block(Block::default().borders(Borders::ALL).title("Details"));
+        frame.render_widget(empty, chunks[1]);
+        return;
+    };
+
+    frame.render_widget(header(pr, shared), chunks[0]);
+
+    let items: Vec<ListItem<'_>> = pr.checks.iter().map(check_item).collect();
+    let required = pr.checks.iter().filter(|check| check.is_required).count();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(
+                    "Checks ({}, {} required)",
+                    pr.checks.len(),
+                    required
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Rgb(48, 56, 68))
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▸ ")
+        .highlight_spacing(HighlightSpacing::Always);
+
+    let mut list_state = ListState::default();
+    if !pr.checks.is_empty() {
+        list_state.select(Some(clamp_cursor(state.check_cursor, pr.checks.len())));
+    }
+    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+
+    let threads: Vec<ListItem<'_>> = state.threads().into_iter().map(thread_item).collect();
+    let threads = List::new(threads).block(
+        Block::default()
+            .title(format!(
+                "Review threads ({}, {} unresolved)",
+                pr.review_threads.len(),
+                pr.unresolved_thread_count()
+            ))
+            .borders(Borders::ALL),
+    );
+    frame.render_widget(threads, chunks[2]);
+
+    let by_file = pr.inline_comments_by_file();
+    let comment_count: usize = by_file.iter().map(|(_, comments)| comments.len()).sum();
+    let comments = List::new(inline_comment_items(&by_file)).block(
+        Block::default()
+            .title(format!(
+                "Inline comments ({comment_count} in {} files)",
+                by_file.len()
+            ))
+            .borders(Borders::ALL),
+    );
+    frame.render_widget(comments, chunks[3]);
+
+    let footer = Paragraph::new(
+        "j/k or arrows: move  |  enter: open check details  |  o: open PR  |  esc/q: back",
+    )
+    .block(Block::default().borders(Borders::TOP));
+    frame.render_widget(footer, chunks[4]);
+}
+
+fn header<'a>(pr: &'a PullRequest, shared: &SharedState) -> Paragraph<'a> {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!("#{} ", pr.number), Style::default().fg(Color::Blue)),
+            Span::styled(
+                truncate(&pr.title, 80),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                format!("{}  by {}  ci: ", pr.repository, pr.author),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(ci_label(pr.ci_status), ci_style(pr.ci_status)),
+            approval_badge(pr),
+            threads_badge(pr),
+        ]),
+    ];
+
+    if pr.comments_truncated {
+        lines[1].spans.push(Span::styled(
+            "  older comments not synced",
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
+    if let Some(error) = &shared.error {
+        lines[1].spans.push(Span::styled(
+            format!("  |  Error: {}", truncate(error, 60)),
+            Style::default().fg(Color::Red),
+        ));
+    }
+
+    Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("Details"))
+}
+
+fn check_item(check: &PrCheck) -> ListItem<'_> {
+    let outcome = check
+        .conclusion
+        .as_deref()
+        .unwrap_or(check.status.as_str())
+        .to_ascii_lowercase();
+
+    let mut spans = vec![
+        Span::styled(
+            format!("{:<16}", ci_label(check.ci_status)),
+            ci_style(check.ci_status).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            truncate(&check.display_name(), 48),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(format!("  {outcome}"), Style::default().fg(Color::Gray)),
+    ];
+    if check.is_required {
+        spans.push(Span::styled("  required", Style::default().fg(Color::Cyan)));
+    }
+    if let Some(duration) = format_check_duration(check.started_at, check.completed_at) {
+        spans.push(Span::styled(
+            format!("  {duration}"),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    ListItem::new(Line::from(spans))
+}
+
+fn thread_item(thread: &PrReviewThread) -> ListItem<'_> {
+    let (label, style) = if thread.is_resolved {
+        ("resolved", Style::default().fg(Color::DarkGray))
+    } else {
+        ("unresolved", Style::default().fg(Color::Yellow))
+    };
+    let location = match thread.line {
+        Some(line) => format!("{}:{line}", thread.path),
+        None => thread.path.clone(),
+    };
+    let first_line = thread.body.lines().next().unwrap_or_default();
+
+    let mut spans = vec![
+        Span::styled(format!("{label:<12}"), style.add_modifier(Modifier::BOLD)),
+        Span::styled(truncate(&location, 48), Style::default().fg(Color::White)),
+    ];
+    if thread.is_outdated {
+        spans.push(Span::styled(
+            "  outdated",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    spans.push(Span::styled(
+        format!("  {}: {}", thread.author, truncate(first_line, 60)),
+        Style::default().fg(Color::Gray),
+    ));
+    if thread.comment_count > 1 {
+        spans.push(Span::styled(
+            format!("  ({} comments)", thread.comment_count),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    ListItem::new(Line::from(spans))
+}
+
+fn inline_comment_items<'a>(by_file: &[(&'a str, Vec<&'a PrComment>)]) -> Vec<ListItem<'a>> {
+    let mut items = Vec::new();
+    for (path, comments) in by_file {
+        items.push(ListItem::new(Line::from(Span::styled(
+            truncate(path, 80),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ))));
+        for comment in comments {
+            let line = comment
+                .line
+                .map_or_else(|| "-".to_string(), |line| format!("L{line}"));
+            let first_line = comment.body.lines().next().unwrap_or_default();
+            let mut spans = vec![
+                Span::styled(format!("  {line:<7}"), Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!("{}: ", comment.author),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(truncate(first_line, 70), Style::default().fg(Color::Gray)),
+            ];
+            if comment.is_outdated {
+                spans.push(Span::styled(
+                    "  outdated",
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            if comment.is_minimized {
+                spans.push(Span::styled(
+                    "  hidden",
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            items.push(ListItem::new(Line::from(spans)));
+        }
+    }
+    items
+}
@@ -0,0 +1,213 @@
+use chrono::{DateTime, Utc};
+
+use crate::models::{PrCheck, PrReviewThread, PullRequest};
+use crate::tui::pr_list::state::clamp_cursor;
+
+/// State for the PR detail screen.
+pub struct State {
+    /// The pull request being shown, if any.
+    pub pr: Option<PullRequest>,
+    /// Cursor position in the check list.
+    pub check_cursor: usize,
+}
+
+impl State {
+    /// Create an empty detail state.
+    pub fn new() -> Self {
+        Self {
+            pr: None,
+            check_cursor: 0,
+        }
+    }
+
+    /// Show `pr`, starting at its first check.
+    pub fn open(&mut self, pr: PullRequest) {
+        self.pr = Some(pr);
+        self.check_cursor = 0;
+    }
+
+    /// Replace the shown PR with its reloaded copy after a sync.
+    pub fn refresh(&mut self, prs: &[PullRequest]) {
+        let Some(current) = &self.pr else {
+            return;
+        };
+        if let Some(fresh) = prs
+            .iter()
+            .find(|pr| pr.repository == current.repository && pr.number == current.number)
+        {
+            self.pr = Some(fresh.clone());
+        }
+        self.check_cursor = clamp_cursor(self.check_cursor, self.checks().len());
+    }
+
+    pub fn checks(&self) -> &[PrCheck] {
+        self.pr.as_ref().map_or(&[], |pr| pr.checks.as_slice())
+    }
+
+    /// Review threads of the shown PR, unresolved ones first.
+    pub fn threads(&self) -> Vec<&PrReviewThread> {
+        let mut threads: Vec<&PrReviewThread> = self
+            .pr
+            .as_ref()
+            .map(|pr| pr.review_threads.iter().collect())
+            .unwrap_or_default();
+        threads.sort_by_key(|thread| thread.is_resolved);
+        threads
+    }
+
+    pub fn move_up(&mut self) {
+        self.check_cursor = self.check_cursor.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        self.check_cursor = clamp_cursor(self.check_cursor.saturating_add(1), self.checks().len());
+    }
+
+    pub fn selected_check(&self) -> Option<&PrCheck> {
+        self.checks().get(self.check_cursor)
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Pure utility function: how long a check ran, e.g. "2m 05s".
+pub fn format_check_duration(
+    started_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+) -> Option<String> {
+    let seconds = (completed_at? - started_at?).num_seconds();
+    if seconds < 0 {
+        return None;
+    }
+
+    Some(if seconds < 60 {
+        format!("{seconds}s")
+    } else if seconds < 3600 {
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}h {:02}m", seconds / 3600, (seconds % 3600) / 60)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApprovalStatus, CiStatus, PrSource};
+
+    fn check(name: &str) -> PrCheck {
+        PrCheck {
+            workflow: String::new(),
+            name: name.to_string(),
+            status: "COMPLETED".to_string(),
+            conclusion: Some("SUCCESS".to_string()),
+            ci_status: CiStatus::Success,
+            is_required: true,
+            counts_towards_ci: true,
+            details_url: None,
+            started_at: None,
+            completed_at: None,
+        }
+    }
+
+    fn test_pr(checks: Vec<PrCheck>) -> PullRequest {
+        PullRequest {
+            number: 1,
+            title: "Test PR".to_string(),
+            repository: "owner/repo".to_string(),
+            author: "bob".to_string(),
+            head_sha: "abc123".to_string(),
+            draft: false,
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+            ci_status: CiStatus::Pending,
+            last_comment_at: DateTime::UNIX_EPOCH,
+            last_commit_at: DateTime::UNIX_EPOCH,
+            last_ci_status_update_at: DateTime::UNIX_EPOCH,
+            approval_status: ApprovalStatus::None,
+            last_review_status_update_at: DateTime::UNIX_EPOCH,
+            last_acknowledged_at: None,
+            requested_reviewers: Vec::new(),
+            requested_teams: Vec::new(),
+            user_has_reviewed: false,
+            team_review_requested: false,
+            comments: Vec::new(),
+            checks,
+            review_threads: Vec::new(),
+            last_thread_update_at: DateTime::UNIX_EPOCH,
+            comments_truncated: false,
+            source: PrSource::Tracked,
+        }
+    }
+
+    #[test]
+    fn check_cursor_stays_within_bounds() {
+        let mut state = State::new();
+        state.open(test_pr(vec![check("build"), check("lint")]));
+
+        state.move_up();
+        assert_eq!(state.check_cursor, 0);
+        state.move_down();
+        state.move_down();
+        assert_eq!(
+            state.selected_check().map(|c| c.name.as_str()),
+            Some("lint")
+        );
+    }
+
+    #[test]
+    fn refresh_replaces_pr_and_clamps_cursor() {
+        let mut state = State::new();
+        state.open(test_pr(vec![check("build"), check("lint")]));
+        state.move_down();
+
+        state.refresh(&[test_pr(vec![check("build")])]);
+
+        assert_eq!(state.checks().len(), 1);
+        assert_eq!(state.check_cursor, 0);
+    }
+
+    #[test]
+    fn threads_lists_unresolved_first() {
+        let thread = |id: &str, is_resolved| PrReviewThread {
+            id: id.to_string(),
+            path: "src/lib.rs".to_string(),
+            line: None,
+            is_resolved,
+            is_outdated: false,
+            author: "alice".to_string(),
+            body: String::new(),
+            comment_count: 1,
+        };
+        let mut pr = test_pr(Vec::new());
+        pr.review_threads = vec![thread("a", true), thread("b", false)];
+        let mut state = State::new();
+        state.open(pr);
+
+        let ids: Vec<&str> = state.threads().iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "a"]);
+    }
+
+    #[test]
+    fn format_check_duration_scales_units() {
+        let start = DateTime::UNIX_EPOCH;
+        let after = |seconds| Some(start + chrono::Duration::seconds(seconds));
+
+        assert_eq!(
+            format_check_duration(Some(start), after(42)).as_deref(),
+            Some("42s")
+        );
+        assert_eq!(
+            format_check_duration(Some(start), after(125)).as_deref(),
+            Some("2m 05s")
+        );
+        assert_eq!(
+            format_check_duration(Some(start), after(3720)).as_deref(),
+            Some("1h 02m")
+        );
+        assert_eq!(format_check_duration(Some(start), None), None);
+    }
+}
@@ -1,14 +1,17 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 
 use crate::db::DatabaseRepository;
-use crate::models::PullRequest;
+use crate::models::{PullRequest, ReviewLauncher};
 use crate::pr_repository::{selected_pr_index, PrOwnerFilter, PrStatusFilter};
 use crate::tui::action::TuiAction;
 use crate::tui::navigation::Screen;
 use crate::tui::pr_list::state::clamp_cursor;
-use crate::tui::pr_list::State;
+use crate::tui::pr_list::{LauncherMenu, State};
 use crate::tui::state::SharedState;
-use crate::tui::tasks::{spawn_full_sync, BackgroundJob, BackgroundMessage};
+use crate::tui::tasks::{
+    spawn_full_sync, spawn_repository_sync, spawn_worktree_checkout, BackgroundJob,
+    BackgroundMessage,
+};
 
 use chrono::Utc;
 use tokio::sync::mpsc;
@@ -23,12 +26,20 @@ fn indices_for<'a>(state: &State, shared: &'a SharedState, owner: PrOwnerFilter)
     )
 }
 
+fn clamp_pane_cursors(state: &mut State, shared: &SharedState) {
+    let tracked_len = indices_for(state, shared, PrOwnerFilter::Tracked).len();
+    let mine_len = indices_for(state, shared, PrOwnerFilter::Mine).len();
+    let inbox_len = indices_for(state, shared, PrOwnerFilter::Inbox).len();
+    state.clamp_cursors(tracked_len, mine_len, inbox_len);
+}
+
 fn focused_indices<'a>(state: &State, shared: &'a SharedState) -> &'a [usize] {
     match state.focus {
         crate::tui::navigation::PrPane::Tracked => {
             indices_for(state, shared, PrOwnerFilter::Tracked)
         }
         crate::tui::navigation::PrPane::Mine => indices_for(state, shared, PrOwnerFilter::Mine),
+        crate::tui::navigation::PrPane::Inbox => indices_for(state, shared, PrOwnerFilter::Inbox),
     }
 }
 
@@ -37,6 +48,7 @@ fn selected_index_for_focus(state: &State, shared: &SharedState) -> Option<usize
     let cursor = match state.focus {
         crate::tui::navigation::PrPane::Tracked => state.tracked_cursor,
         crate::tui::navigation::PrPane::Mine => state.mine_cursor,
+        crate::tui::navigation::PrPane::Inbox => state.inbox_cursor,
     };
     selected_pr_index(indices, cursor)
 }
@@ -59,6 +71,64 @@ fn review_pr_url_for_event(
     selected_index_for_focus(state, shared).map(|pr_index| shared.dashboard.prs[pr_index].clone())
 }
 
+/// Launch directly when there is a single launcher, otherwise open the picker.
+fn open_review_launcher(
+    state: &mut State,
+    shared: &mut SharedState,
+    pr: PullRequest,
+    mut launchers: Vec<ReviewLauncher>,
+) -> TuiAction {
+    match launchers.len() {
+        0 => {
+            shared.error = Some(
+                "no review launchers configured, add one with 'prt launchers add'".to_string(),
+            );
+            TuiAction::Continue
+        }
+        1 => TuiAction::ReviewPr(Box::new(pr), launchers.remove(0)),
+        _ => {
+            state.launcher_menu = Some(LauncherMenu::new(pr, launchers));
+            TuiAction::Continue
+        }
+    }
+}
+
+/// Handle a key event while the launcher picker is open.
+fn handle_launcher_menu_event(key_event: KeyEvent, state: &mut State) -> TuiAction {
+    if key_event.kind != KeyEventKind::Press {
+        return TuiAction::Continue;
+    }
+
+    let Some(menu) = state.launcher_menu.as_mut() else {
+        return TuiAction::Continue;
+    };
+
+    match key_event.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            state.launcher_menu = None;
+            TuiAction::Continue
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            menu.move_up();
+            TuiAction::Continue
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            menu.move_down();
+            TuiAction::Continue
+        }
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            let Some(launcher) = menu.selected().cloned() else {
+                return TuiAction::Continue;
+            };
+            let Some(menu) = state.launcher_menu.take() else {
+                return TuiAction::Continue;
+            };
+            TuiAction::ReviewPr(Box::new(menu.pr), launcher)
+        }
+        _ => TuiAction::Continue,
+    }
+}
+
 /// Handle a key event for the PR List screen.
 pub async fn handle_event(
     key_event: KeyEvent,
@@ -68,8 +138,13 @@ pub async fn handle_event(
     repo: &DatabaseRepository,
     tx: &mpsc::UnboundedSender<BackgroundMessage>,
 ) -> anyhow::Result<TuiAction> {
+    if state.launcher_menu.is_some() {
+        return Ok(handle_launcher_menu_event(key_event, state));
+    }
+
     if let Some(pr) = review_pr_url_for_event(key_event, state, shared) {
-        return Ok(TuiAction::ReviewPr(Box::new(pr)));
+        let launchers = repo.get_review_launchers().await?;
+        return Ok(open_review_launcher(state, shared, pr, launchers));
     }
 
     if key_event.kind != KeyEventKind::Press {
@@ -83,9 +158,7 @@ pub async fn handle_event(
 
         KeyCode::Tab => {
             state.toggle_focus();
-            let tracked_len = indices_for(state, shared, PrOwnerFilter::Tracked).len();
-            let mine_len = indices_for(state, shared, PrOwnerFilter::Mine).len();
-            state.clamp_cursors(tracked_len, mine_len);
+            clamp_pane_cursors(state, shared);
             Ok(TuiAction::Continue)
         }
 
@@ -115,6 +188,13 @@ pub async fn handle_event(
             Ok(TuiAction::Continue)
         }
 
+        KeyCode::Char('d') => match selected_index_for_focus(state, shared) {
+            Some(pr_index) => Ok(TuiAction::ShowPrDetail(Box::new(
+                shared.dashboard.prs[pr_index].clone(),
+            ))),
+            None => Ok(TuiAction::Continue),
+        },
+
         KeyCode::Char('a') => {
             if let Some(pr_index) = selected_index_for_focus(state, shared) {
                 let mut pr = shared.dashboard.prs[pr_index].clone();
@@ -122,9 +202,7 @@ pub async fn handle_event(
                 repo.save_pr(&pr).await?;
                 shared.dashboard = repo.get_pr_dashboard(&shared.username).await?;
 
-                let tracked_len = indices_for(state, shared, PrOwnerFilter::Tracked).len();
-                let mine_len = indices_for(state, shared, PrOwnerFilter::Mine).len();
-                state.clamp_cursors(tracked_len, mine_len);
+                clamp_pane_cursors(state, shared);
             }
             Ok(TuiAction::Continue)
         }
@@ -144,6 +222,46 @@ pub async fn handle_event(
             Ok(TuiAction::StartJob(BackgroundJob::FullSync))
         }
 
+        KeyCode::Char('S') => {
+            if active_job.is_some() {
+                return Ok(TuiAction::Continue);
+            }
+            let Some(pr_index) = selected_index_for_focus(state, shared) else {
+                return Ok(TuiAction::Continue);
+            };
+
+            let repository = shared.dashboard.prs[pr_index].repository.clone();
+            let tracked = repo.get_tracked_repositories().await?;
+            if !tracked
+                .iter()
+                .any(|tracked_repo| tracked_repo.repository.eq_ignore_ascii_case(&repository))
+            {
+                shared.error = Some(format!(
+                    "'{repository}' is not tracked; its inbox PRs sync with 's'"
+                ));
+                return Ok(TuiAction::Continue);
+            }
+
+            state.clear_sync_logs();
+            spawn_repository_sync(repo.clone(), repository, tx.clone());
+            Ok(TuiAction::StartJob(BackgroundJob::FullSync))
+        }
+
+        KeyCode::Char('w') => {
+            if active_job.is_some() {
+                return Ok(TuiAction::Continue);
+            }
+
+            match selected_index_for_focus(state, shared) {
+                Some(pr_index) => {
+                    let pr = shared.dashboard.prs[pr_index].clone();
+                    spawn_worktree_checkout(repo.clone(), pr, tx.clone());
+                    Ok(TuiAction::StartJob(BackgroundJob::WorktreeCheckout))
+                }
+                None => Ok(TuiAction::Continue),
+            }
+        }
+
         KeyCode::Char('t') => {
             if active_job.is_none() {
                 Ok(TuiAction::SwitchScreen(Screen::AuthorsFromTeams))
@@ -152,6 +270,13 @@ pub async fn handle_event(
             }
         }
 
+        // Ctrl+R without a selected PR must not open the Repositories screen.
+        KeyCode::Char('r') if !key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            Ok(TuiAction::SwitchScreen(Screen::Repositories))
+        }
+
+        KeyCode::Char('H') => Ok(TuiAction::SwitchScreen(Screen::SyncHistory)),
+
         _ => Ok(TuiAction::Continue),
     }
 }
@@ -159,7 +284,7 @@ pub async fn handle_event(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{ApprovalStatus, CiStatus, PullRequest};
+    use crate::models::{ApprovalStatus, CiStatus, PrSource, PullRequest};
     use chrono::DateTime;
 
     fn test_pr(number: i64, author: &str) -> PullRequest {
@@ -180,8 +305,15 @@ mod tests {
             last_review_status_update_at: DateTime::UNIX_EPOCH,
             last_acknowledged_at: None,
             requested_reviewers: Vec::new(),
+            requested_teams: Vec::new(),
             user_has_reviewed: false,
+            team_review_requested: false,
             comments: Vec::new(),
+            checks: Vec::new(),
+            review_threads: Vec::new(),
+            last_thread_update_at: DateTime::UNIX_EPOCH,
+            comments_truncated: false,
+            source: PrSource::Tracked,
         }
     }
 
@@ -213,6 +345,116 @@ mod tests {
         assert_eq!(review_pr_url_for_event(key_event, &state, &shared), None);
     }
 
+    fn launcher(name: &str) -> ReviewLauncher {
+        ReviewLauncher {
+            name: name.to_string(),
+            command: "true".to_string(),
+            args: Vec::new(),
+        }
+    }
+
+    fn shared_with_pr() -> SharedState {
+        SharedState::new(
+            crate::pr_repository::build_pr_dashboard(vec![test_pr(42, "bob")], "alice"),
+            "alice".to_string(),
+        )
+    }
+
+    #[test]
+    fn open_review_launcher_reports_missing_launchers() {
+        let mut state = State::new();
+        let mut shared = shared_with_pr();
+
+        let action = open_review_launcher(&mut state, &mut shared, test_pr(42, "bob"), vec![]);
+
+        assert!(matches!(action, TuiAction::Continue));
+        assert!(shared.error.is_some());
+        assert!(state.launcher_menu.is_none());
+    }
+
+    #[test]
+    fn open_review_launcher_launches_single_launcher_directly() {
+        let mut state = State::new();
+        let mut shared = shared_with_pr();
+
+        let action = open_review_launcher(
+            &mut state,
+            &mut shared,
+            test_pr(42, "bob"),
+            vec![launcher("editor")],
+        );
+
+        match action {
+            TuiAction::ReviewPr(pr, launcher) => {
+                assert_eq!(pr.number, 42);
+                assert_eq!(launcher.name, "editor");
+            }
+            _ => panic!("expected ReviewPr action"),
+        }
+        assert!(state.launcher_menu.is_none());
+    }
+
+    #[test]
+    fn open_review_launcher_opens_menu_for_multiple_launchers() {
+        let mut state = State::new();
+        let mut shared = shared_with_pr();
+
+        let action = open_review_launcher(
+            &mut state,
+            &mut shared,
+            test_pr(42, "bob"),
+            vec![launcher("editor"), launcher("terminal")],
+        );
+
+        assert!(matches!(action, TuiAction::Continue));
+        assert_eq!(
+            state
+                .launcher_menu
+                .as_ref()
+                .map(|menu| menu.launchers.len()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn launcher_menu_enter_launches_selected_launcher() {
+        let mut state = State::new();
+        state.launcher_menu = Some(LauncherMenu::new(
+            test_pr(42, "bob"),
+            vec![launcher("editor"), launcher("terminal")],
+        ));
+
+        handle_launcher_menu_event(
+            KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE),
+            &mut state,
+        );
+        let action = handle_launcher_menu_event(
+            KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+            &mut state,
+        );
+
+        match action {
+            TuiAction::ReviewPr(_, launcher) => assert_eq!(launcher.name, "terminal"),
+            _ => panic!("expected ReviewPr action"),
+        }
+        assert!(state.launcher_menu.is_none());
+    }
+
+    #[test]
+    fn launcher_menu_esc_closes_without_launching() {
+        let mut state = State::new();
+        state.launcher_menu = Some(LauncherMenu::new(
+            test_pr(42, "bob"),
+            vec![launcher("editor"), launcher("terminal")],
+        ));
+
+        let action =
+            handle_launcher_menu_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE), &mut state);
+
+        assert!(matches!(action, TuiAction::Continue));
+        assert!(state.launcher_menu.is_none());
+    }
+
     #[test]
     fn review_pr_url_for_event_returns_none_without_selection() {
         let state = State::new();
@@ -1,17 +1,19 @@
-use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span, Text};
-use ratatui::widgets::{Block, Borders, HighlightSpacing, List, ListItem, ListState, Paragraph};
+use ratatui::widgets::{
+    Block, Borders, Clear, HighlightSpacing, List, ListItem, ListState, Paragraph,
+};
 
 use crate::models::PullRequest;
 use crate::pr_repository::{selected_pr_index, PrOwnerFilter, PrStatusFilter};
 use crate::tui::navigation::PrPane;
 use crate::tui::pr_list::state::clamp_cursor;
-use crate::tui::pr_list::State;
+use crate::tui::pr_list::{LauncherMenu, State};
 use crate::tui::state::{title_case, truncate, SharedState};
 use crate::tui::tasks::{background_job_label, BackgroundJob};
 use crate::tui::widgets::{
-    approval_badge, ci_label, ci_style, involved_badge, review_badge, spinner_frame,
+    approval_badge, ci_label, ci_style, involved_badge, review_badge, spinner_frame, threads_badge,
 };
 
 /// Draw the PR List screen.
@@ -28,9 +30,11 @@ pub fn draw(
     };
     let tracked = shared.dashboard.section(PrOwnerFilter::Tracked, status);
     let mine = shared.dashboard.section(PrOwnerFilter::Mine, status);
+    let inbox = shared.dashboard.section(PrOwnerFilter::Inbox, status);
     let selected = match state.focus {
         PrPane::Tracked => selected_pr_index(tracked, state.tracked_cursor),
         PrPane::Mine => selected_pr_index(mine, state.mine_cursor),
+        PrPane::Inbox => selected_pr_index(inbox, state.inbox_cursor),
     }
     .and_then(|index| shared.dashboard.prs.get(index));
 
@@ -46,6 +50,7 @@ pub fn draw(
     let focus_label = match state.focus {
         PrPane::Tracked => "tracked",
         PrPane::Mine => "mine",
+        PrPane::Inbox => "inbox",
     };
 
     let header = Paragraph::new(Line::from(vec![
@@ -66,6 +71,11 @@ pub fn draw(
             Style::default().fg(Color::White),
         ),
         Span::raw("  |  "),
+        Span::styled(
+            format!("inbox: {}", inbox.len()),
+            Style::default().fg(Color::White),
+        ),
+        Span::raw("  |  "),
         Span::styled(
             format!("view: {}", state.view_label()),
             Style::default().fg(Color::LightCyan),
@@ -83,12 +93,16 @@ pub fn draw(
             },
             Style::default().fg(Color::Gray),
         ),
-        match &shared.error {
-            Some(error) => Span::styled(
+        match (&shared.error, &shared.notice) {
+            (Some(error), _) => Span::styled(
                 format!("  |  Error: {}", truncate(error, 60)),
                 Style::default().fg(Color::Red),
             ),
-            None => Span::raw(""),
+            (None, Some(notice)) => Span::styled(
+                format!("  |  {}", truncate(notice, 80)),
+                Style::default().fg(Color::Green),
+            ),
+            (None, None) => Span::raw(""),
         },
     ]))
     .block(Block::default().borders(Borders::ALL).title("Overview"));
@@ -101,6 +115,10 @@ pub fn draw(
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
             .split(chunks[1]);
+        let right_panes = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(panes[1]);
 
         draw_pr_pane(
             frame,
@@ -113,13 +131,22 @@ pub fn draw(
         );
         draw_pr_pane(
             frame,
-            panes[1],
+            right_panes[0],
             "My PRs",
             mine,
             state.mine_cursor,
             state.focus == PrPane::Mine,
             shared,
         );
+        draw_pr_pane(
+            frame,
+            right_panes[1],
+            "Review inbox",
+            inbox,
+            state.inbox_cursor,
+            state.focus == PrPane::Inbox,
+            shared,
+        );
     }
 
     let spinner = match active_job {
@@ -132,11 +159,75 @@ pub fn draw(
     };
 
     let footer = Paragraph::new(format!(
-        "tab: switch pane  |  j/k or arrows: move  |  enter/space: open PR  |  ctrl+r: octo review  |  a: acknowledge  |  v: toggle view  |  s: sync now  |  t: authors from teams  |  q: quit{}",
+        "tab: switch pane  |  j/k or arrows: move  |  enter/space: open PR  |  d: details  |  ctrl+r: review  |  w: worktree  |  a: acknowledge  |  v: toggle view  |  s: sync now  |  S: sync PR's repo  |  t: authors from teams  |  r: repositories  |  H: sync history  |  q: quit{}",
         spinner
     ))
     .block(Block::default().borders(Borders::TOP));
     frame.render_widget(footer, chunks[2]);
+
+    if let Some(menu) = &state.launcher_menu {
+        draw_launcher_menu(frame, menu);
+    }
+}
+
+fn draw_launcher_menu(frame: &mut ratatui::Frame<'_>, menu: &LauncherMenu) {
+    let height = (menu.launchers.len() as u16).saturating_add(2);
+    let area = centered_rect(frame.area(), 60, height);
+
+    let items: Vec<ListItem<'_>> = menu
+        .launchers
+        .iter()
+        .map(|launcher| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{} ", launcher.name),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    truncate(
+                        &format!("{} {}", launcher.command, launcher.args.join(" ")),
+                        40,
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(
+                    "Review #{} with (enter: launch, esc: cancel)",
+                    menu.pr.number
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Rgb(48, 56, 68))
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▸ ")
+        .highlight_spacing(HighlightSpacing::Always);
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(clamp_cursor(menu.cursor, menu.launchers.len())));
+
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
+fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
 }
 
 fn draw_pr_pane(
@@ -247,6 +338,7 @@ fn build_list_item<'a>(index: usize, pr: &'a PullRequest, username: &str) -> Lis
                 Span::raw("")
             },
             approval_badge(pr),
+            threads_badge(pr),
             involved_badge(pr, username),
             review_badge(pr, username),
         ]),
@@ -1,3 +1,4 @@
+use crate::models::{PullRequest, ReviewLauncher};
 use crate::tui::navigation::{PrPane, ViewMode};
 
 const MAX_SYNC_LOG_LINES: usize = 256;
@@ -10,6 +11,38 @@ pub fn clamp_cursor(cursor: usize, len: usize) -> usize {
     }
 }
 
+/// Launcher picker shown when more than one review launcher is configured.
+pub struct LauncherMenu {
+    /// The pull request the chosen launcher will be started for.
+    pub pr: PullRequest,
+    /// Launchers to choose from, in display order.
+    pub launchers: Vec<ReviewLauncher>,
+    /// Cursor position in the launcher list.
+    pub cursor: usize,
+}
+
+impl LauncherMenu {
+    pub fn new(pr: PullRequest, launchers: Vec<ReviewLauncher>) -> Self {
+        Self {
+            pr,
+            launchers,
+            cursor: 0,
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        self.cursor = clamp_cursor(self.cursor.saturating_add(1), self.launchers.len());
+    }
+
+    pub fn selected(&self) -> Option<&ReviewLauncher> {
+        self.launchers.get(self.cursor)
+    }
+}
+
 /// State for the PR List screen.
 pub struct State {
     /// Which pane is currently focused.
@@ -18,10 +51,14 @@ pub struct State {
     pub tracked_cursor: usize,
     /// Cursor position in the authored-by-me pane.
     pub mine_cursor: usize,
+    /// Cursor position in the review inbox pane.
+    pub inbox_cursor: usize,
     /// Current view mode (Active or Acknowledged).
     pub view_mode: ViewMode,
     /// Recent sync log lines shown while a sync is running.
     pub sync_logs: Vec<String>,
+    /// Open launcher picker, if any.
+    pub launcher_menu: Option<LauncherMenu>,
 }
 
 impl State {
@@ -31,8 +68,10 @@ impl State {
             focus: PrPane::Tracked,
             tracked_cursor: 0,
             mine_cursor: 0,
+            inbox_cursor: 0,
             view_mode: ViewMode::Active,
             sync_logs: Vec::new(),
+            launcher_menu: None,
         }
     }
 
@@ -52,27 +91,31 @@ impl State {
         match pane {
             PrPane::Tracked => &mut self.tracked_cursor,
             PrPane::Mine => &mut self.mine_cursor,
+            PrPane::Inbox => &mut self.inbox_cursor,
         }
     }
 
-    pub fn clamp_cursors(&mut self, tracked_len: usize, mine_len: usize) {
+    pub fn clamp_cursors(&mut self, tracked_len: usize, mine_len: usize, inbox_len: usize) {
         self.tracked_cursor = clamp_cursor(self.tracked_cursor, tracked_len);
         self.mine_cursor = clamp_cursor(self.mine_cursor, mine_len);
+        self.inbox_cursor = clamp_cursor(self.inbox_cursor, inbox_len);
     }
 
     pub fn toggle_focus(&mut self) {
         self.focus = match self.focus {
             PrPane::Tracked => PrPane::Mine,
-            PrPane::Mine => PrPane::Tracked,
+            PrPane::Mine => PrPane::Inbox,
+            PrPane::Inbox => PrPane::Tracked,
         };
     }
 
     /// Toggle between Active and Acknowledged view modes.
-    /// Resets both cursors to 0 when toggling.
+    /// Resets all cursors to 0 when toggling.
     pub fn toggle_view(&mut self) {
         self.view_mode = self.view_mode.toggle();
         self.tracked_cursor = 0;
         self.mine_cursor = 0;
+        self.inbox_cursor = 0;
     }
 
     /// Return the label for the current view mode.
@@ -90,6 +133,38 @@ impl Default for State {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{ApprovalStatus, CiStatus, PrSource};
+    use chrono::DateTime;
+
+    fn test_pr() -> PullRequest {
+        PullRequest {
+            number: 1,
+            title: "Test PR".to_string(),
+            repository: "owner/repo".to_string(),
+            author: "bob".to_string(),
+            head_sha: "abc123".to_string(),
+            draft: false,
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+            ci_status: CiStatus::Pending,
+            last_comment_at: DateTime::UNIX_EPOCH,
+            last_commit_at: DateTime::UNIX_EPOCH,
+            last_ci_status_update_at: DateTime::UNIX_EPOCH,
+            approval_status: ApprovalStatus::None,
+            last_review_status_update_at: DateTime::UNIX_EPOCH,
+            last_acknowledged_at: None,
+            requested_reviewers: Vec::new(),
+            requested_teams: Vec::new(),
+            user_has_reviewed: false,
+            team_review_requested: false,
+            comments: Vec::new(),
+            checks: Vec::new(),
+            review_threads: Vec::new(),
+            last_thread_update_at: DateTime::UNIX_EPOCH,
+            comments_truncated: false,
+            source: PrSource::Tracked,
+        }
+    }
 
     #[test]
     fn new_starts_with_tracked_focus() {
@@ -102,6 +177,7 @@ mod tests {
         let state = State::new();
         assert_eq!(state.tracked_cursor, 0);
         assert_eq!(state.mine_cursor, 0);
+        assert_eq!(state.inbox_cursor, 0);
     }
 
     #[test]
@@ -131,13 +207,25 @@ mod tests {
     }
 
     #[test]
-    fn toggle_view_resets_both_cursors() {
+    fn toggle_focus_cycles_through_inbox_back_to_tracked() {
+        let mut state = State::new();
+        state.toggle_focus();
+        state.toggle_focus();
+        assert!(matches!(state.focus, PrPane::Inbox));
+        state.toggle_focus();
+        assert!(matches!(state.focus, PrPane::Tracked));
+    }
+
+    #[test]
+    fn toggle_view_resets_all_cursors() {
         let mut state = State::new();
         state.tracked_cursor = 2;
         state.mine_cursor = 3;
+        state.inbox_cursor = 4;
         state.toggle_view();
         assert_eq!(state.tracked_cursor, 0);
         assert_eq!(state.mine_cursor, 0);
+        assert_eq!(state.inbox_cursor, 0);
     }
 
     #[test]
@@ -145,11 +233,30 @@ mod tests {
         let mut state = State::new();
         state.tracked_cursor = 10;
         state.mine_cursor = 4;
+        state.inbox_cursor = 5;
 
-        state.clamp_cursors(2, 0);
+        state.clamp_cursors(2, 0, 9);
 
         assert_eq!(state.tracked_cursor, 1);
         assert_eq!(state.mine_cursor, 0);
+        assert_eq!(state.inbox_cursor, 5);
+    }
+
+    #[test]
+    fn launcher_menu_cursor_stays_within_bounds() {
+        let launcher = |name: &str| ReviewLauncher {
+            name: name.to_string(),
+            command: "true".to_string(),
+            args: Vec::new(),
+        };
+        let mut menu = LauncherMenu::new(test_pr(), vec![launcher("a"), launcher("b")]);
+
+        menu.move_up();
+        assert_eq!(menu.cursor, 0);
+        menu.move_down();
+        menu.move_down();
+        assert_eq!(menu.cursor, 1);
+        assert_eq!(menu.selected().map(|l| l.name.as_str()), Some("b"));
     }
 
     #[test]
@@ -0,0 +1,155 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use tokio::sync::mpsc;
+
+use crate::db::DatabaseRepository;
+use crate::tui::action::TuiAction;
+use crate::tui::navigation::Screen;
+use crate::tui::repositories::State;
+use crate::tui::state::SharedState;
+use crate::tui::tasks::{
+    spawn_repository_add, spawn_repository_sync, BackgroundJob, BackgroundMessage,
+};
+
+/// Reload the listed repositories from the database.
+pub async fn reload(
+    state: &mut State,
+    shared: &SharedState,
+    repo: &DatabaseRepository,
+) -> anyhow::Result<()> {
+    let tracked = repo.get_tracked_repositories().await?;
+    state.load(tracked, &shared.dashboard.prs);
+    Ok(())
+}
+
+/// Handle a key event while the add prompt is open.
+fn handle_add_input_event(
+    key_code: KeyCode,
+    state: &mut State,
+    active_job: &Option<BackgroundJob>,
+    repo: &DatabaseRepository,
+    tx: &mpsc::UnboundedSender<BackgroundMessage>,
+) -> TuiAction {
+    let Some(input) = state.add_input.as_mut() else {
+        return TuiAction::Continue;
+    };
+
+    match key_code {
+        KeyCode::Esc => state.add_input = None,
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        KeyCode::Char(c) => input.push(c),
+        KeyCode::Enter => {
+            let reference = input.trim().to_string();
+            if reference.is_empty() {
+                return TuiAction::Continue;
+            }
+            if active_job.is_some() {
+                state.error = Some("wait for the running job to finish".to_string());
+                return TuiAction::Continue;
+            }
+            state.add_input = None;
+            spawn_repository_add(repo.clone(), reference, tx.clone());
+            return TuiAction::StartJob(BackgroundJob::RepositoryAdd);
+        }
+        _ => {}
+    }
+    TuiAction::Continue
+}
+
+/// Handle a key event for the Repositories screen.
+pub async fn handle_event(
+    key_event: KeyEvent,
+    state: &mut State,
+    shared: &mut SharedState,
+    active_job: &Option<BackgroundJob>,
+    repo: &DatabaseRepository,
+    tx: &mpsc::UnboundedSender<BackgroundMessage>,
+) -> anyhow::Result<TuiAction> {
+    if key_event.kind != KeyEventKind::Press {
+        return Ok(TuiAction::Continue);
+    }
+
+    state.error = None;
+    state.notice = None;
+
+    // Removing a repository also deletes its stored PRs, so it waits for `y`;
+    // any other key cancels.
+    if let Some(repository) = state.confirm_remove.take() {
+        if key_event.code == KeyCode::Char('y') {
+            let deleted_prs = repo.delete_tracked_repository(&repository).await?;
+            shared.dashboard = repo.get_pr_dashboard(&shared.username).await?;
+            reload(state, shared, repo).await?;
+            state.notice = Some(format!(
+                "Stopped tracking '{repository}' and deleted {deleted_prs} stored PR(s)"
+            ));
+        }
+        return Ok(TuiAction::Continue);
+    }
+
+    if state.add_input.is_some() {
+        return Ok(handle_add_input_event(
+            key_event.code,
+            state,
+            active_job,
+            repo,
+            tx,
+        ));
+    }
+
+    match key_event.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            return Ok(TuiAction::SwitchScreen(Screen::PrList));
+        }
+
+        KeyCode::Up | KeyCode::Char('k') => {
+            state.cursor = state.cursor.saturating_sub(1);
+        }
+
+        KeyCode::Down | KeyCode::Char('j') => {
+            state.cursor = (state.cursor + 1).min(state.rows.len().saturating_sub(1));
+        }
+
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            if let Some(row) = state.selected() {
+                let _ = open::that(format!("https://github.com/{}", row.repository));
+            }
+        }
+
+        KeyCode::Char('a') => {
+            state.add_input = Some(String::new());
+        }
+
+        KeyCode::Char('x') => {
+            if let Some(row) = state.selected() {
+                state.confirm_remove = Some(row.repository.clone());
+            }
+        }
+
+        KeyCode::Char('r') => {
+            if let Some(row) = state.selected() {
+                let repository = row.repository.clone();
+                repo.reset_tracked_repository_last_synced_at(&repository)
+                    .await?;
+                reload(state, shared, repo).await?;
+                state.notice = Some(format!(
+                    "'{repository}' will be fully refetched on the next sync"
+                ));
+            }
+        }
+
+        KeyCode::Char('s') => {
+            if active_job.is_some() {
+                return Ok(TuiAction::Continue);
+            }
+            if let Some(row) = state.selected() {
+                spawn_repository_sync(repo.clone(), row.repository.clone(), tx.clone());
+                return Ok(TuiAction::StartJob(BackgroundJob::FullSync));
+            }
+        }
+
+        _ => {}
+    }
+
+    Ok(TuiAction::Continue)
+}
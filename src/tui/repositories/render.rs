@@ -0,0 +1,177 @@
+use chrono::Utc;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+
+use crate::tui::repositories::{format_last_synced, RepositoryRow, State};
+use crate::tui::state::truncate;
+use crate::tui::tasks::{background_job_label, BackgroundJob};
+use crate::tui::widgets::spinner_frame;
+
+/// Draw the Repositories screen.
+pub fn draw(
+    frame: &mut ratatui::Frame<'_>,
+    state: &State,
+    active_job: Option<BackgroundJob>,
+    spinner_tick: usize,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // header
+            Constraint::Min(1),    // repositories
+            Constraint::Length(3), // add prompt or remove confirmation
+            Constraint::Length(2), // footer
+        ])
+        .split(frame.area());
+
+    // Header
+    let header = Paragraph::new(Line::from(vec![
+        Span::styled(
+            "PR Tracker",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  |  "),
+        Span::styled("Repositories", Style::default().fg(Color::LightCyan)),
+        match (&state.error, &state.notice) {
+            (Some(error), _) => Span::styled(
+                format!("  |  Error: {}", truncate(error, 80)),
+                Style::default().fg(Color::Red),
+            ),
+            (None, Some(notice)) => Span::styled(
+                format!("  |  {}", truncate(notice, 80)),
+                Style::default().fg(Color::Green),
+            ),
+            (None, None) => Span::raw(""),
+        },
+    ]))
+    .block(Block::default().borders(Borders::ALL).title("Overview"));
+    frame.render_widget(header, chunks[0]);
+
+    // Repositories
+    let now = Utc::now();
+    let items: Vec<ListItem<'_>> = state
+        .rows
+        .iter()
+        .map(|row| build_list_item(row, now))
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!("Tracked repositories ({})", state.rows.len()))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Rgb(48, 56, 68))
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▸ ");
+    let mut list_state = ListState::default();
+    if !state.rows.is_empty() {
+        list_state.select(Some(state.cursor.min(state.rows.len() - 1)));
+    }
+    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+
+    // Add prompt or remove confirmation
+    match (&state.add_input, &state.confirm_remove) {
+        (_, Some(repository)) => {
+            let confirm = Paragraph::new(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(
+                    format!(
+                        "Stop tracking '{repository}' and delete its stored PRs, \
+                         comments, checks and review threads?"
+                    ),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Remove repository"),
+            );
+            frame.render_widget(confirm, chunks[2]);
+        }
+        (Some(input), None) => {
+            let prompt = Paragraph::new(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(input.as_str(), Style::default().fg(Color::White)),
+                Span::styled("█", Style::default().fg(Color::Yellow)), // cursor
+            ]))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Add repository (owner/repo or URL)"),
+            );
+            frame.render_widget(prompt, chunks[2]);
+        }
+        (None, None) => frame.render_widget(Block::default(), chunks[2]),
+    }
+
+    // Footer
+    let spinner = match active_job {
+        Some(job) => format!(
+            "  |  {} {}",
+            background_job_label(job),
+            spinner_frame(spinner_tick)
+        ),
+        None => String::new(),
+    };
+    let keys = if state.confirm_remove.is_some() {
+        "y: remove  |  any other key: cancel"
+    } else if state.add_input.is_some() {
+        "enter: add  |  esc: cancel"
+    } else {
+        "j/k: move  |  enter/space: open  |  a: add  |  x: remove  |  r: reset sync  |  s: sync repo  |  esc/q: back"
+    };
+    let footer =
+        Paragraph::new(format!("{keys}{spinner}")).block(Block::default().borders(Borders::TOP));
+    frame.render_widget(footer, chunks[3]);
+}
+
+fn build_list_item(row: &RepositoryRow, now: chrono::DateTime<Utc>) -> ListItem<'_> {
+    let mut details = vec![
+        Span::styled("synced: ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format_last_synced(row.last_synced_at, now),
+            Style::default().fg(Color::White),
+        ),
+        Span::raw("  "),
+        Span::styled("open PRs: ", Style::default().fg(Color::DarkGray)),
+        Span::styled(row.open_prs.to_string(), Style::default().fg(Color::White)),
+    ];
+    if let Some(account) = &row.account {
+        details.push(Span::raw("  "));
+        details.push(Span::styled(
+            "account: ",
+            Style::default().fg(Color::DarkGray),
+        ));
+        details.push(Span::styled(
+            account.as_str(),
+            Style::default().fg(Color::White),
+        ));
+    }
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            row.repository.as_str(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(details),
+    ];
+    if let Some(error) = &row.last_sync_error {
+        lines.push(Line::from(Span::styled(
+            format!("last sync failed: {}", truncate(error, 100)),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    lines.push(Line::raw(""));
+    ListItem::new(lines)
+}
@@ -0,0 +1,259 @@
+use chrono::{DateTime, Utc};
+
+use crate::models::{PrSource, PullRequest, TrackedRepository};
+
+/// One tracked repository as listed on the Repositories screen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepositoryRow {
+    pub repository: String,
+    pub account: Option<String>,
+    pub last_synced_at: Option<DateTime<Utc>>,
+    /// Open tracked PRs stored for the repository.
+    pub open_prs: usize,
+    pub last_sync_error: Option<String>,
+}
+
+/// State for the Repositories screen.
+pub struct State {
+    /// Tracked repositories, sorted by name.
+    pub rows: Vec<RepositoryRow>,
+    /// Cursor position in the list.
+    pub cursor: usize,
+    /// Text typed into the add prompt, `None` while the prompt is closed.
+    pub add_input: Option<String>,
+    /// Repository waiting for the user to confirm that it should be removed.
+    pub confirm_remove: Option<String>,
+    /// Error message of the last action.
+    pub error: Option<String>,
+    /// Outcome of the last action.
+    pub notice: Option<String>,
+}
+
+impl State {
+    /// Create an empty Repositories screen state.
+    pub fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            cursor: 0,
+            add_input: None,
+            confirm_remove: None,
+            error: None,
+            notice: None,
+        }
+    }
+
+    /// Replace the listed repositories, keeping the cursor in bounds.
+    pub fn load(&mut self, tracked: Vec<TrackedRepository>, prs: &[PullRequest]) {
+        self.rows = build_repository_rows(tracked, prs);
+        self.clamp_cursor();
+    }
+
+    /// Ensure the cursor doesn't exceed list bounds.
+    pub fn clamp_cursor(&mut self) {
+        if self.rows.is_empty() {
+            self.cursor = 0;
+        } else if self.cursor >= self.rows.len() {
+            self.cursor = self.rows.len() - 1;
+        }
+    }
+
+    /// The repository under the cursor.
+    pub fn selected(&self) -> Option<&RepositoryRow> {
+        self.rows.get(self.cursor)
+    }
+
+    /// Move the cursor onto `repository`, e.g. after adding it.
+    pub fn select(&mut self, repository: &str) {
+        if let Some(index) = self
+            .rows
+            .iter()
+            .position(|row| row.repository.eq_ignore_ascii_case(repository))
+        {
+            self.cursor = index;
+        }
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Rows for `tracked`, sorted by name, with the number of open tracked PRs
+/// stored for each.
+pub fn build_repository_rows(
+    tracked: Vec<TrackedRepository>,
+    prs: &[PullRequest],
+) -> Vec<RepositoryRow> {
+    let mut rows: Vec<RepositoryRow> = tracked
+        .into_iter()
+        .map(|tracked_repo| RepositoryRow {
+            open_prs: prs
+                .iter()
+                .filter(|pr| {
+                    pr.source == PrSource::Tracked
+                        && pr.repository.eq_ignore_ascii_case(&tracked_repo.repository)
+                })
+                .count(),
+            repository: tracked_repo.repository,
+            account: tracked_repo.account,
+            last_synced_at: tracked_repo.last_synced_at,
+            last_sync_error: tracked_repo.last_sync_error,
+        })
+        .collect();
+    rows.sort_by_key(|row| row.repository.to_lowercase());
+    rows
+}
+
+/// How long ago `last_synced_at` was, e.g. `5m ago`, or `never`.
+pub fn format_last_synced(last_synced_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> String {
+    let Some(last_synced_at) = last_synced_at else {
+        return "never".to_string();
+    };
+    let elapsed = now.signed_duration_since(last_synced_at);
+    if elapsed.num_minutes() < 1 {
+        "just now".to_string()
+    } else if elapsed.num_hours() < 1 {
+        format!("{}m ago", elapsed.num_minutes())
+    } else if elapsed.num_days() < 1 {
+        format!("{}h ago", elapsed.num_hours())
+    } else {
+        format!("{}d ago", elapsed.num_days())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ApprovalStatus, CiPolicy, CiStatus};
+    use chrono::TimeZone;
+
+    fn tracked(repository: &str) -> TrackedRepository {
+        TrackedRepository {
+            repository: repository.to_string(),
+            last_synced_at: None,
+            account: None,
+            ci_policy: CiPolicy::RequiredOnly,
+            node_id: None,
+            last_sync_error: None,
+        }
+    }
+
+    fn pr(repository: &str, source: PrSource) -> PullRequest {
+        PullRequest {
+            number: 1,
+            title: "Test PR".to_string(),
+            repository: repository.to_string(),
+            author: "bob".to_string(),
+            head_sha: "abc123".to_string(),
+            draft: false,
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+            ci_status: CiStatus::Pending,
+            last_comment_at: DateTime::UNIX_EPOCH,
+            last_commit_at: DateTime::UNIX_EPOCH,
+            last_ci_status_update_at: DateTime::UNIX_EPOCH,
+            approval_status: ApprovalStatus::None,
+            last_review_status_update_at: DateTime::UNIX_EPOCH,
+            last_acknowledged_at: None,
+            requested_reviewers: Vec::new(),
+            requested_teams: Vec::new(),
+            user_has_reviewed: false,
+            team_review_requested: false,
+            comments: Vec::new(),
+            checks: Vec::new(),
+            review_threads: Vec::new(),
+            last_thread_update_at: DateTime::UNIX_EPOCH,
+            comments_truncated: false,
+            source,
+        }
+    }
+
+    // ── build_repository_rows tests ────────────────────────────────
+
+    #[test]
+    fn build_repository_rows_sorts_by_name_ignoring_case() {
+        let rows = build_repository_rows(
+            vec![
+                tracked("octo/widgets"),
+                tracked("Acme/api"),
+                tracked("beta/app"),
+            ],
+            &[],
+        );
+
+        let names: Vec<&str> = rows.iter().map(|row| row.repository.as_str()).collect();
+        assert_eq!(names, vec!["Acme/api", "beta/app", "octo/widgets"]);
+    }
+
+    #[test]
+    fn build_repository_rows_counts_only_tracked_prs_of_the_repository() {
+        let prs = vec![
+            pr("octo/widgets", PrSource::Tracked),
+            pr("Octo/Widgets", PrSource::Tracked),
+            pr("octo/widgets", PrSource::ReviewInbox),
+            pr("octo/gadgets", PrSource::Tracked),
+        ];
+
+        let rows = build_repository_rows(vec![tracked("octo/widgets")], &prs);
+
+        assert_eq!(rows[0].open_prs, 2);
+    }
+
+    // ── State tests ────────────────────────────────────────────────
+
+    #[test]
+    fn load_clamps_cursor_to_new_rows() {
+        let mut state = State::new();
+        state.cursor = 4;
+
+        state.load(vec![tracked("octo/widgets"), tracked("octo/gadgets")], &[]);
+
+        assert_eq!(state.cursor, 1);
+        state.load(Vec::new(), &[]);
+        assert_eq!(state.cursor, 0);
+    }
+
+    #[test]
+    fn select_moves_cursor_to_repository() {
+        let mut state = State::new();
+        state.load(
+            vec![
+                tracked("octo/gadgets"),
+                tracked("octo/tools"),
+                tracked("octo/widgets"),
+            ],
+            &[],
+        );
+
+        state.select("Octo/Tools");
+
+        assert_eq!(
+            state.selected().map(|row| row.repository.as_str()),
+            Some("octo/tools")
+        );
+    }
+
+    // ── format_last_synced tests ───────────────────────────────────
+
+    #[test]
+    fn format_last_synced_uses_largest_unit() {
+        let now = Utc.with_ymd_and_hms(2025, 6, 15, 12, 0, 0).unwrap();
+
+        assert_eq!(format_last_synced(None, now), "never");
+        assert_eq!(format_last_synced(Some(now), now), "just now");
+        assert_eq!(
+            format_last_synced(Some(now - chrono::Duration::minutes(5)), now),
+            "5m ago"
+        );
+        assert_eq!(
+            format_last_synced(Some(now - chrono::Duration::hours(3)), now),
+            "3h ago"
+        );
+        assert_eq!(
+            format_last_synced(Some(now - chrono::Duration::days(2)), now),
+            "2d ago"
+        );
+    }
+}
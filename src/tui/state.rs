@@ -5,6 +5,8 @@ pub struct SharedState {
     pub dashboard: PrDashboard,
     pub username: String,
     pub error: Option<String>,
+    /// One-off status message, e.g. the outcome of a worktree checkout.
+    pub notice: Option<String>,
 }
 
 impl SharedState {
@@ -13,6 +15,7 @@ impl SharedState {
             dashboard,
             username,
             error: None,
+            notice: None,
         }
     }
 }
@@ -0,0 +1,60 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+
+use crate::db::DatabaseRepository;
+use crate::tui::action::TuiAction;
+use crate::tui::navigation::Screen;
+use crate::tui::sync_history::{State, SYNC_HISTORY_LIMIT};
+
+/// Reload the listed runs from the database.
+pub async fn reload(state: &mut State, repo: &DatabaseRepository) -> anyhow::Result<()> {
+    let runs = repo.get_sync_runs(SYNC_HISTORY_LIMIT).await?;
+    state.load(runs);
+    Ok(())
+}
+
+/// Handle a key event for the Sync history screen.
+pub async fn handle_event(
+    key_event: KeyEvent,
+    state: &mut State,
+    repo: &DatabaseRepository,
+) -> anyhow::Result<TuiAction> {
+    if key_event.kind != KeyEventKind::Press {
+        return Ok(TuiAction::Continue);
+    }
+
+    state.error = None;
+
+    match key_event.code {
+        KeyCode::Esc | KeyCode::Char('q') if state.show_detail => {
+            state.toggle_detail();
+        }
+
+        KeyCode::Esc | KeyCode::Char('q') => {
+            return Ok(TuiAction::SwitchScreen(Screen::PrList));
+        }
+
+        KeyCode::Up | KeyCode::Char('k') if state.show_detail => {
+            state.detail_scroll = state.detail_scroll.saturating_sub(1);
+        }
+
+        KeyCode::Down | KeyCode::Char('j') if state.show_detail => {
+            state.detail_scroll = state.detail_scroll.saturating_add(1);
+        }
+
+        KeyCode::Up | KeyCode::Char('k') => {
+            state.cursor = state.cursor.saturating_sub(1);
+        }
+
+        KeyCode::Down | KeyCode::Char('j') => {
+            state.cursor = (state.cursor + 1).min(state.runs.len().saturating_sub(1));
+        }
+
+        KeyCode::Enter | KeyCode::Char(' ') => state.toggle_detail(),
+
+        KeyCode::Char('r') => reload(state, repo).await?,
+
+        _ => {}
+    }
+
+    Ok(TuiAction::Continue)
+}
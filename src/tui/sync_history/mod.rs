@@ -0,0 +1,5 @@
+pub mod state;
+pub use state::*;
+
+pub mod events;
+pub mod render;
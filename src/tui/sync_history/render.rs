@@ -0,0 +1,125 @@
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+
+use crate::models::SyncRun;
+use crate::sync::format_sync_run;
+use crate::tui::state::truncate;
+use crate::tui::sync_history::{sync_run_detail_lines, State};
+use crate::tui::tasks::{background_job_label, BackgroundJob};
+use crate::tui::widgets::spinner_frame;
+
+/// Draw the Sync history screen.
+pub fn draw(
+    frame: &mut ratatui::Frame<'_>,
+    state: &State,
+    active_job: Option<BackgroundJob>,
+    spinner_tick: usize,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // header
+            Constraint::Min(1),    // runs or detail
+            Constraint::Length(2), // footer
+        ])
+        .split(frame.area());
+
+    // Header
+    let header = Paragraph::new(Line::from(vec![
+        Span::styled(
+            "PR Tracker",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  |  "),
+        Span::styled("Sync history", Style::default().fg(Color::LightCyan)),
+        match &state.error {
+            Some(error) => Span::styled(
+                format!("  |  Error: {}", truncate(error, 80)),
+                Style::default().fg(Color::Red),
+            ),
+            None => Span::raw(""),
+        },
+    ]))
+    .block(Block::default().borders(Borders::ALL).title("Overview"));
+    frame.render_widget(header, chunks[0]);
+
+    match state.selected().filter(|_| state.show_detail) {
+        Some(run) => {
+            let lines: Vec<Line<'_>> = sync_run_detail_lines(run)
+                .into_iter()
+                .map(Line::raw)
+                .collect();
+            let detail = Paragraph::new(lines)
+                .block(
+                    Block::default()
+                        .title(format!("Sync run #{}", run.id))
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Color::Cyan)),
+                )
+                .scroll((state.detail_scroll, 0));
+            frame.render_widget(detail, chunks[1]);
+        }
+        None => {
+            let items: Vec<ListItem<'_>> = state.runs.iter().map(build_list_item).collect();
+            let list = List::new(items)
+                .block(
+                    Block::default()
+                        .title(format!("Sync runs ({})", state.runs.len()))
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Color::Cyan)),
+                )
+                .highlight_style(
+                    Style::default()
+                        .bg(Color::Rgb(48, 56, 68))
+                        .add_modifier(Modifier::BOLD),
+                )
+                .highlight_symbol("▸ ");
+            let mut list_state = ListState::default();
+            if !state.runs.is_empty() {
+                list_state.select(Some(state.cursor.min(state.runs.len() - 1)));
+            }
+            frame.render_stateful_widget(list, chunks[1], &mut list_state);
+        }
+    }
+
+    // Footer
+    let spinner = match active_job {
+        Some(job) => format!(
+            "  |  {} {}",
+            background_job_label(job),
+            spinner_frame(spinner_tick)
+        ),
+        None => String::new(),
+    };
+    let keys = if state.show_detail {
+        "j/k: scroll  |  enter/space/esc/q: back to runs"
+    } else {
+        "j/k: move  |  enter/space: show log  |  r: refresh  |  esc/q: back"
+    };
+    let footer =
+        Paragraph::new(format!("{keys}{spinner}")).block(Block::default().borders(Borders::TOP));
+    frame.render_widget(footer, chunks[2]);
+}
+
+fn build_list_item(run: &SyncRun) -> ListItem<'_> {
+    let color = if run.error.is_some()
+        || run
+            .repositories
+            .iter()
+            .any(|repository| repository.error.is_some())
+    {
+        Color::Red
+    } else if run.finished_at.is_none() {
+        Color::Yellow
+    } else {
+        Color::White
+    };
+    ListItem::new(Line::from(Span::styled(
+        format_sync_run(run),
+        Style::default().fg(color),
+    )))
+}
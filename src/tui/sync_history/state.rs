@@ -0,0 +1,173 @@
+use crate::models::SyncRun;
+use crate::sync::{format_sync_run, format_sync_run_repository};
+
+/// How many recorded runs the Sync history screen lists.
+pub const SYNC_HISTORY_LIMIT: usize = 50;
+
+/// State for the Sync history screen.
+pub struct State {
+    /// Recorded runs, newest first.
+    pub runs: Vec<SyncRun>,
+    /// Cursor position in the list.
+    pub cursor: usize,
+    /// Whether the selected run's repositories and log are shown.
+    pub show_detail: bool,
+    /// Scroll offset of the detail view.
+    pub detail_scroll: u16,
+    /// Error message of the last action.
+    pub error: Option<String>,
+}
+
+impl State {
+    /// Create an empty Sync history screen state.
+    pub fn new() -> Self {
+        Self {
+            runs: Vec::new(),
+            cursor: 0,
+            show_detail: false,
+            detail_scroll: 0,
+            error: None,
+        }
+    }
+
+    /// Replace the listed runs, keeping the cursor in bounds.
+    pub fn load(&mut self, runs: Vec<SyncRun>) {
+        self.runs = runs;
+        self.clamp_cursor();
+    }
+
+    /// Ensure the cursor doesn't exceed list bounds.
+    pub fn clamp_cursor(&mut self) {
+        if self.runs.is_empty() {
+            self.cursor = 0;
+            self.show_detail = false;
+        } else if self.cursor >= self.runs.len() {
+            self.cursor = self.runs.len() - 1;
+        }
+    }
+
+    /// The run under the cursor.
+    pub fn selected(&self) -> Option<&SyncRun> {
+        self.runs.get(self.cursor)
+    }
+
+    /// Open or close the detail view of the selected run.
+    pub fn toggle_detail(&mut self) {
+        self.show_detail = !self.show_detail && self.selected().is_some();
+        self.detail_scroll = 0;
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Lines of the detail view: the run itself, its error, its repositories
+/// and its log.
+pub fn sync_run_detail_lines(run: &SyncRun) -> Vec<String> {
+    let mut lines = vec![format_sync_run(run)];
+    if let Some(error) = &run.error {
+        lines.push(format!("Error: {error}"));
+    }
+    if !run.repositories.is_empty() {
+        lines.push(String::new());
+        lines.push("Repositories:".to_string());
+        lines.extend(
+            run.repositories
+                .iter()
+                .map(|repository| format!("  {}", format_sync_run_repository(repository))),
+        );
+    }
+    if !run.log.is_empty() {
+        lines.push(String::new());
+        lines.push("Log:".to_string());
+        lines.extend(run.log.iter().map(|line| format!("  {line}")));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SyncRunRepository, SyncTrigger};
+    use chrono::{TimeZone, Utc};
+
+    fn run(id: i64) -> SyncRun {
+        let started_at = Utc.with_ymd_and_hms(2025, 6, 15, 12, 0, 0).unwrap();
+        SyncRun {
+            id,
+            trigger: SyncTrigger::Cli,
+            scope: "all".to_string(),
+            dry_run: false,
+            started_at,
+            finished_at: Some(started_at + chrono::Duration::seconds(2)),
+            new_prs: 0,
+            updated_data_prs: 0,
+            updated_attention_prs: 0,
+            deleted_prs: 0,
+            graphql_requests: 3,
+            graphql_cost: 3,
+            rate_limit_remaining: None,
+            rate_limit_reset_at: None,
+            error: None,
+            log: Vec::new(),
+            repositories: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn load_clamps_cursor_and_closes_detail_without_runs() {
+        let mut state = State::new();
+        state.cursor = 4;
+
+        state.load(vec![run(2), run(1)]);
+        assert_eq!(state.cursor, 1);
+
+        state.toggle_detail();
+        assert!(state.show_detail);
+        state.load(Vec::new());
+        assert_eq!(state.cursor, 0);
+        assert!(!state.show_detail);
+    }
+
+    #[test]
+    fn toggle_detail_needs_a_selected_run() {
+        let mut state = State::new();
+
+        state.toggle_detail();
+
+        assert!(!state.show_detail);
+    }
+
+    #[test]
+    fn sync_run_detail_lines_list_repositories_and_log() {
+        let mut run = run(7);
+        run.repositories.push(SyncRunRepository {
+            repository: "acme/api".to_string(),
+            duration_ms: 1_300,
+            new_prs: 1,
+            updated_prs: 2,
+            deleted_prs: 0,
+            error: None,
+        });
+        run.log
+            .push("[sync] syncing repository: acme/api".to_string());
+
+        let lines = sync_run_detail_lines(&run);
+
+        assert!(lines[0].starts_with("#7 "));
+        assert_eq!(
+            lines[1..],
+            [
+                "".to_string(),
+                "Repositories:".to_string(),
+                "  acme/api 1.3s new=1 updated=2 deleted=0".to_string(),
+                "".to_string(),
+                "Log:".to_string(),
+                "  [sync] syncing repository: acme/api".to_string(),
+            ]
+        );
+    }
+}
@@ -1,22 +1,30 @@
 use tokio::sync::mpsc;
 
+use crate::credentials::github_client_for_user;
 use crate::db::DatabaseRepository;
-use crate::github::GitHubClient;
+use crate::models::{PullRequest, SyncTrigger};
 use crate::pr_repository::{partition_team_authors, TeamAuthorBuckets};
-use crate::sync::{sync_all_tracked_with_progress, SyncProgress, SyncRunSummary};
+use crate::service::resolve_repository_to_track;
+use crate::sync::{load_sync_accounts, record_sync_run, SyncOptions, SyncProgress, SyncRunSummary};
+use crate::worktree::{checkout_pull_request, WorktreeCheckout};
 
 /// Background job types that can be active.
 #[derive(Clone, Copy)]
 pub enum BackgroundJob {
     FullSync,
     TeamsFetch,
+    WorktreeCheckout,
+    RepositoryAdd,
 }
 
 /// Messages sent from background tasks to the main loop.
 pub enum BackgroundMessage {
     SyncProgress(SyncProgress),
-    FullSyncFinished(anyhow::Result<SyncRunSummary>),
+    FullSyncFinished(anyhow::Result<Box<SyncRunSummary>>),
     TeamsFetchFinished(anyhow::Result<TeamsPayload>),
+    WorktreeCheckoutFinished(anyhow::Result<WorktreeCheckout>),
+    /// Canonical name of the repository that was added.
+    RepositoryAddFinished(anyhow::Result<String>),
 }
 
 /// Payload returned from team fetch operations.
@@ -26,8 +34,21 @@ pub type TeamsPayload = TeamAuthorBuckets;
 pub fn spawn_full_sync(repo: DatabaseRepository, tx: mpsc::UnboundedSender<BackgroundMessage>) {
     tokio::spawn(async move {
         let progress_tx = tx.clone();
-        let result = run_full_sync(repo, progress_tx).await;
-        let _ = tx.send(BackgroundMessage::FullSyncFinished(result));
+        let result = run_sync(repo, SyncOptions::default(), progress_tx).await;
+        let _ = tx.send(BackgroundMessage::FullSyncFinished(result.map(Box::new)));
+    });
+}
+
+/// Spawn a sync of a single tracked repository in the background.
+pub fn spawn_repository_sync(
+    repo: DatabaseRepository,
+    repository: String,
+    tx: mpsc::UnboundedSender<BackgroundMessage>,
+) {
+    tokio::spawn(async move {
+        let progress_tx = tx.clone();
+        let result = run_sync(repo, SyncOptions::repository(repository), progress_tx).await;
+        let _ = tx.send(BackgroundMessage::FullSyncFinished(result.map(Box::new)));
     });
 }
 
@@ -39,18 +60,42 @@ pub fn spawn_teams_fetch(repo: DatabaseRepository, tx: mpsc::UnboundedSender<Bac
     });
 }
 
-/// Run a full sync operation.
-async fn run_full_sync(
+/// Spawn a worktree checkout for a single PR in the background.
+pub fn spawn_worktree_checkout(
+    repo: DatabaseRepository,
+    pr: PullRequest,
+    tx: mpsc::UnboundedSender<BackgroundMessage>,
+) {
+    tokio::spawn(async move {
+        let result = checkout_pull_request(&repo, &pr).await;
+        let _ = tx.send(BackgroundMessage::WorktreeCheckoutFinished(result));
+    });
+}
+
+/// Spawn validating and tracking a repository in the background.
+pub fn spawn_repository_add(
+    repo: DatabaseRepository,
+    reference: String,
+    tx: mpsc::UnboundedSender<BackgroundMessage>,
+) {
+    tokio::spawn(async move {
+        let result = run_repository_add(repo, reference).await;
+        let _ = tx.send(BackgroundMessage::RepositoryAddFinished(result));
+    });
+}
+
+/// Run a sync of the repositories in `options`.
+async fn run_sync(
     repo: DatabaseRepository,
+    options: SyncOptions,
     tx: mpsc::UnboundedSender<BackgroundMessage>,
 ) -> anyhow::Result<SyncRunSummary> {
-    let user = repo.get_user().await?.ok_or_else(|| {
-        anyhow::anyhow!("no authenticated user found, run 'cli auth <token>' first")
-    })?;
-    let username = user.username.clone();
-    let github = GitHubClient::new(user.access_token)?;
+    let accounts = load_sync_accounts(&repo).await?;
+    if accounts.accounts.is_empty() {
+        anyhow::bail!("no authenticated user found, run 'prt auth <token>' first");
+    }
 
-    sync_all_tracked_with_progress(&repo, &github, &username, |progress| {
+    record_sync_run(&repo, &accounts, &options, SyncTrigger::Tui, |progress| {
         let _ = tx.send(BackgroundMessage::SyncProgress(progress));
     })
     .await
@@ -61,7 +106,7 @@ async fn run_teams_fetch(repo: DatabaseRepository) -> anyhow::Result<TeamsPayloa
     let user = repo.get_user().await?.ok_or_else(|| {
         anyhow::anyhow!("no authenticated user found, run 'prt auth <token>' first")
     })?;
-    let github = GitHubClient::new(user.access_token.clone())?;
+    let github = github_client_for_user(&user)?;
 
     let teams = github.fetch_user_teams().await?;
 
@@ -83,11 +128,27 @@ async fn run_teams_fetch(repo: DatabaseRepository) -> anyhow::Result<TeamsPayloa
     ))
 }
 
+/// Track a repository with the active account after checking it exists.
+async fn run_repository_add(repo: DatabaseRepository, reference: String) -> anyhow::Result<String> {
+    let user = repo.get_user().await?.ok_or_else(|| {
+        anyhow::anyhow!("no authenticated user found, run 'prt auth <token>' first")
+    })?;
+    let github = github_client_for_user(&user)?;
+
+    let tracked = repo.get_tracked_repositories().await?;
+    let found = resolve_repository_to_track(&github, &reference, &tracked).await?;
+    repo.save_tracked_repository(&found.full_name, None, found.node_id.as_deref())
+        .await?;
+    Ok(found.full_name)
+}
+
 /// Get a human-readable label for a background job.
 pub fn background_job_label(job: BackgroundJob) -> &'static str {
     match job {
         BackgroundJob::FullSync => "sync",
         BackgroundJob::TeamsFetch => "fetching teams",
+        BackgroundJob::WorktreeCheckout => "checking out worktree",
+        BackgroundJob::RepositoryAdd => "adding repository",
     }
 }
 
@@ -107,4 +168,20 @@ mod tests {
             "fetching teams"
         );
     }
+
+    #[test]
+    fn background_job_label_worktree_checkout() {
+        assert_eq!(
+            background_job_label(BackgroundJob::WorktreeCheckout),
+            "checking out worktree"
+        );
+    }
+
+    #[test]
+    fn background_job_label_repository_add() {
+        assert_eq!(
+            background_job_label(BackgroundJob::RepositoryAdd),
+            "adding repository"
+        );
+    }
 }
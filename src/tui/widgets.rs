@@ -1,14 +1,24 @@
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::Span;
 
-use crate::models::{ApprovalStatus, CiStatus, PullRequest};
+use crate::models::{ApprovalStatus, CiStatus, Involvement, PullRequest};
 
 /// Style for CI status badges.
 pub fn ci_style(status: CiStatus) -> Style {
     match status {
         CiStatus::Pending => Style::default().fg(Color::Yellow),
+        CiStatus::Expected => Style::default().fg(Color::LightYellow),
         CiStatus::Success => Style::default().fg(Color::Green),
+        CiStatus::Neutral => Style::default().fg(Color::Gray),
+        CiStatus::Skipped => Style::default().fg(Color::DarkGray),
         CiStatus::Failure => Style::default().fg(Color::Red),
+        CiStatus::Cancelled => Style::default().fg(Color::Magenta),
+        CiStatus::Stale => Style::default().fg(Color::LightMagenta),
+        CiStatus::ActionRequired => Style::default().fg(Color::LightBlue),
+        CiStatus::NoChecks => Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::ITALIC),
+        CiStatus::OptionalFailing => Style::default().fg(Color::LightRed),
     }
 }
 
@@ -16,8 +26,16 @@ pub fn ci_style(status: CiStatus) -> Style {
 pub fn ci_label(status: CiStatus) -> &'static str {
     match status {
         CiStatus::Pending => "pending",
+        CiStatus::Expected => "expected",
         CiStatus::Success => "success",
+        CiStatus::Neutral => "neutral",
+        CiStatus::Skipped => "skipped",
         CiStatus::Failure => "failure",
+        CiStatus::Cancelled => "cancelled",
+        CiStatus::Stale => "stale",
+        CiStatus::ActionRequired => "action required",
+        CiStatus::NoChecks => "no checks",
+        CiStatus::OptionalFailing => "optional failing",
     }
 }
 
@@ -32,17 +50,31 @@ pub fn approval_badge(pr: &PullRequest) -> Span<'static> {
     }
 }
 
+/// Badge counting a PR's unresolved review threads.
+pub fn threads_badge(pr: &PullRequest) -> Span<'static> {
+    match pr.unresolved_thread_count() {
+        0 => Span::raw(""),
+        1 => Span::styled("  1 unresolved thread", Style::default().fg(Color::Yellow)),
+        count => Span::styled(
+            format!("  {count} unresolved threads"),
+            Style::default().fg(Color::Yellow),
+        ),
+    }
+}
+
 /// Badge showing if the user is involved in a PR.
 pub fn involved_badge<'a>(pr: &PullRequest, username: &str) -> Span<'a> {
-    if pr.user_is_involved(username) {
-        Span::styled(
+    match pr.involvement(username) {
+        Some(Involvement::Author | Involvement::ReviewRequested) => Span::styled(
             "  involved",
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
-        )
-    } else {
-        Span::raw("")
+        ),
+        Some(Involvement::TeamReviewRequested) => {
+            Span::styled("  team involved", Style::default().fg(Color::Cyan))
+        }
+        None => Span::raw(""),
     }
 }
 
@@ -52,7 +84,12 @@ pub fn review_badge<'a>(pr: &PullRequest, username: &str) -> Span<'a> {
         return Span::raw("");
     }
 
-    if pr.user_is_involved(username) {
+    if pr.involvement(username) == Some(Involvement::TeamReviewRequested) {
+        Span::styled(
+            "  team review requested",
+            Style::default().fg(Color::LightYellow),
+        )
+    } else if pr.user_is_involved(username) {
         Span::styled("  review requested", Style::default().fg(Color::Yellow))
     } else if pr.user_has_reviewed {
         Span::styled("  reviewed", Style::default().fg(Color::Green))
@@ -70,7 +107,7 @@ pub fn spinner_frame(tick: usize) -> char {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{ApprovalStatus, CiStatus, PullRequest};
+    use crate::models::{ApprovalStatus, CiStatus, PrReviewThread, PrSource, PullRequest};
     use chrono::DateTime;
 
     fn test_pr() -> PullRequest {
@@ -91,8 +128,15 @@ mod tests {
             last_review_status_update_at: DateTime::UNIX_EPOCH,
             last_acknowledged_at: None,
             requested_reviewers: Vec::new(),
+            requested_teams: Vec::new(),
             user_has_reviewed: false,
+            team_review_requested: false,
             comments: Vec::new(),
+            checks: Vec::new(),
+            review_threads: Vec::new(),
+            last_thread_update_at: DateTime::UNIX_EPOCH,
+            comments_truncated: false,
+            source: PrSource::Tracked,
         }
     }
 
@@ -128,6 +172,35 @@ mod tests {
         assert_eq!(badge.content, "  changes requested");
     }
 
+    // ── threads_badge tests ────────────────────────────────────────
+
+    fn thread(is_resolved: bool) -> PrReviewThread {
+        PrReviewThread {
+            id: "t".to_string(),
+            path: "src/lib.rs".to_string(),
+            line: Some(1),
+            is_resolved,
+            is_outdated: false,
+            author: "bob".to_string(),
+            body: "nit".to_string(),
+            comment_count: 1,
+        }
+    }
+
+    #[test]
+    fn threads_badge_counts_unresolved_threads() {
+        let mut pr = test_pr();
+        pr.review_threads = vec![thread(false), thread(true), thread(false)];
+        assert_eq!(threads_badge(&pr).content, "  2 unresolved threads");
+    }
+
+    #[test]
+    fn threads_badge_empty_when_all_resolved() {
+        let mut pr = test_pr();
+        pr.review_threads = vec![thread(true)];
+        assert_eq!(threads_badge(&pr).content, "");
+    }
+
     // ── involved_badge tests ───────────────────────────────────────
 
     #[test]
@@ -158,6 +231,16 @@ mod tests {
         assert_eq!(badge.content, "  involved");
     }
 
+    #[test]
+    fn badges_mark_team_review_requests_separately() {
+        let mut pr = test_pr();
+        pr.requested_teams = vec!["acme/platform".to_string()];
+        pr.team_review_requested = true;
+
+        assert_eq!(involved_badge(&pr, "bob").content, "  team involved");
+        assert_eq!(review_badge(&pr, "bob").content, "  team review requested");
+    }
+
     // ── review_badge tests ─────────────────────────────────────────
 
     #[test]
@@ -227,6 +310,15 @@ mod tests {
         assert_eq!(style.fg, Some(Color::Red));
     }
 
+    #[test]
+    fn ci_style_distinguishes_optional_failures_and_missing_checks() {
+        assert_eq!(
+            ci_style(CiStatus::OptionalFailing).fg,
+            Some(Color::LightRed)
+        );
+        assert_eq!(ci_style(CiStatus::NoChecks).fg, Some(Color::DarkGray));
+    }
+
     // ── ci_label tests ─────────────────────────────────────────────
 
     #[test]
@@ -244,6 +336,11 @@ mod tests {
         assert_eq!(ci_label(CiStatus::Failure), "failure");
     }
 
+    #[test]
+    fn ci_label_action_required() {
+        assert_eq!(ci_label(CiStatus::ActionRequired), "action required");
+    }
+
     // ── spinner_frame tests ─────────────────────────────────────────
 
     #[test]
@@ -0,0 +1,471 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::db::DatabaseRepository;
+use crate::launcher::expand_home;
+use crate::models::{PrWorktree, PullRequest};
+
+/// Outcome of checking out a pull request into its worktree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorktreeStatus {
+    Created,
+    Updated,
+    UpToDate,
+    /// The worktree could not be moved to the PR head, e.g. because it has
+    /// local changes or the branch was force-pushed.
+    Behind {
+        local_sha: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeCheckout {
+    pub repository: String,
+    pub pr_number: i64,
+    pub path: PathBuf,
+    pub head_sha: String,
+    pub status: WorktreeStatus,
+}
+
+/// A closed PR's worktree that sync left in place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeptWorktree {
+    pub repository: String,
+    pub pr_number: i64,
+    pub path: String,
+    pub reason: String,
+}
+
+/// Outcome of removing the worktrees of closed PRs.
+#[derive(Debug, Default)]
+pub struct WorktreeCleanup {
+    pub removed: usize,
+    pub kept: Vec<KeptWorktree>,
+}
+
+pub fn format_kept_worktree(kept: &KeptWorktree) -> String {
+    format!(
+        "Kept worktree for {}#{} at {}: {}",
+        kept.repository, kept.pr_number, kept.path, kept.reason
+    )
+}
+
+pub fn format_worktree_checkout(checkout: &WorktreeCheckout) -> String {
+    let target = format!("{}#{}", checkout.repository, checkout.pr_number);
+    let path = checkout.path.display();
+    match &checkout.status {
+        WorktreeStatus::Created => format!("Created worktree for {target} at {path}"),
+        WorktreeStatus::Updated => format!("Updated worktree for {target} at {path}"),
+        WorktreeStatus::UpToDate => format!("Worktree for {target} is up to date at {path}"),
+        WorktreeStatus::Behind { local_sha } => format!(
+            "Worktree for {target} at {path} is behind: local {} vs head {}",
+            short_sha(local_sha),
+            short_sha(&checkout.head_sha)
+        ),
+    }
+}
+
+/// Parse an `owner/repo#number` pull request reference.
+pub fn parse_pr_target(target: &str) -> anyhow::Result<(String, i64)> {
+    let (repository, number) = target
+        .rsplit_once('#')
+        .ok_or_else(|| anyhow::anyhow!("expected 'owner/repo#number', got '{target}'"))?;
+
+    if repository
+        .split_once('/')
+        .is_none_or(|(owner, name)| owner.is_empty() || name.is_empty() || name.contains('/'))
+    {
+        anyhow::bail!("expected 'owner/repo#number', got '{target}'");
+    }
+
+    let number = number
+        .parse::<i64>()
+        .map_err(|_| anyhow::anyhow!("invalid pull request number in '{target}'"))?;
+
+    Ok((repository.to_string(), number))
+}
+
+/// Worktrees live next to the clone: `<clone>-worktrees/pr-<number>`.
+pub fn worktree_path_for(local_path: &Path, pr_number: i64) -> PathBuf {
+    let clone_name = local_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "repo".to_string());
+    let parent = local_path.parent().unwrap_or(local_path);
+
+    parent
+        .join(format!("{clone_name}-worktrees"))
+        .join(format!("pr-{pr_number}"))
+}
+
+fn pr_branch_name(pr_number: i64) -> String {
+    format!("pr/{pr_number}")
+}
+
+fn pr_remote_ref(pr_number: i64) -> String {
+    format!("refs/remotes/origin/pr/{pr_number}")
+}
+
+/// Create or fast-forward the worktree for `pr` and record it in the database.
+pub async fn checkout_pull_request(
+    repository: &DatabaseRepository,
+    pr: &PullRequest,
+) -> anyhow::Result<WorktreeCheckout> {
+    let local_path = repository
+        .get_repository_local_path(&pr.repository)
+        .await?
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "no local clone configured for '{}', run 'prt repositories set-path {} <path>' first",
+                pr.repository,
+                pr.repository
+            )
+        })?;
+    let clone_path = PathBuf::from(expand_home(&local_path));
+    let pr_number = pr.number;
+    let head_sha = pr.head_sha.clone();
+
+    let (path, status) = tokio::task::spawn_blocking(move || {
+        checkout_worktree_blocking(&clone_path, pr_number, &head_sha)
+    })
+    .await??;
+
+    repository
+        .save_pr_worktree(&PrWorktree {
+            repository: pr.repository.clone(),
+            pr_number: pr.number,
+            path: path.display().to_string(),
+            head_sha: pr.head_sha.clone(),
+        })
+        .await?;
+
+    Ok(WorktreeCheckout {
+        repository: pr.repository.clone(),
+        pr_number: pr.number,
+        path,
+        head_sha: pr.head_sha.clone(),
+        status,
+    })
+}
+
+fn checkout_worktree_blocking(
+    clone_path: &Path,
+    pr_number: i64,
+    head_sha: &str,
+) -> anyhow::Result<(PathBuf, WorktreeStatus)> {
+    if !clone_path.is_dir() {
+        anyhow::bail!("local clone not found at {}", clone_path.display());
+    }
+
+    let remote_ref = pr_remote_ref(pr_number);
+    run_git(
+        clone_path,
+        &[
+            "fetch",
+            "origin",
+            &format!("+refs/pull/{pr_number}/head:{remote_ref}"),
+        ],
+    )?;
+
+    let path = worktree_path_for(clone_path, pr_number);
+    let path_arg = path.display().to_string();
+
+    if !path.exists() {
+        run_git(
+            clone_path,
+            &[
+                "worktree",
+                "add",
+                "-B",
+                &pr_branch_name(pr_number),
+                &path_arg,
+                &remote_ref,
+            ],
+        )?;
+        return Ok((path.clone(), status_against_head(&path, head_sha, true)?));
+    }
+
+    let local_sha = run_git(&path, &["rev-parse", "HEAD"])?;
+    if local_sha == head_sha {
+        return Ok((path, WorktreeStatus::UpToDate));
+    }
+
+    let is_clean = run_git(&path, &["status", "--porcelain"])?.is_empty();
+    let fast_forwardable =
+        run_git(&path, &["merge-base", "--is-ancestor", "HEAD", &remote_ref]).is_ok();
+    if is_clean && fast_forwardable {
+        run_git(&path, &["merge", "--ff-only", &remote_ref])?;
+    }
+
+    Ok((path.clone(), status_against_head(&path, head_sha, false)?))
+}
+
+fn status_against_head(
+    path: &Path,
+    head_sha: &str,
+    created: bool,
+) -> anyhow::Result<WorktreeStatus> {
+    let local_sha = run_git(path, &["rev-parse", "HEAD"])?;
+    if !head_sha.is_empty() && local_sha != head_sha {
+        return Ok(WorktreeStatus::Behind { local_sha });
+    }
+
+    Ok(if created {
+        WorktreeStatus::Created
+    } else {
+        WorktreeStatus::Updated
+    })
+}
+
+/// Report whether an existing worktree still matches the PR head.
+pub async fn worktree_status(worktree: &PrWorktree, head_sha: &str) -> WorktreeStatus {
+    let path = PathBuf::from(&worktree.path);
+    let head_sha = head_sha.to_string();
+    tokio::task::spawn_blocking(move || match run_git(&path, &["rev-parse", "HEAD"]) {
+        Ok(local_sha) if local_sha == head_sha => WorktreeStatus::UpToDate,
+        Ok(local_sha) => WorktreeStatus::Behind { local_sha },
+        Err(_) => WorktreeStatus::Behind {
+            local_sha: String::new(),
+        },
+    })
+    .await
+    .unwrap_or(WorktreeStatus::Behind {
+        local_sha: String::new(),
+    })
+}
+
+/// Remove the worktrees of PRs that sync found closed or merged.
+///
+/// Worktrees with uncommitted changes, untracked files or commits beyond the
+/// PR head are kept, as are those git refuses to remove; they keep their
+/// database row, so the next sync checks them again.
+pub async fn cleanup_closed_pr_worktrees(
+    repository: &DatabaseRepository,
+    repo_name: &str,
+    closed_pr_numbers: &[i64],
+) -> anyhow::Result<WorktreeCleanup> {
+    let mut cleanup = WorktreeCleanup::default();
+    if closed_pr_numbers.is_empty() {
+        return Ok(cleanup);
+    }
+
+    let worktrees: Vec<PrWorktree> = repository
+        .get_pr_worktrees_for_repository(repo_name)
+        .await?
+        .into_iter()
+        .filter(|worktree| closed_pr_numbers.contains(&worktree.pr_number))
+        .collect();
+    if worktrees.is_empty() {
+        return Ok(cleanup);
+    }
+
+    let Some(local_path) = repository.get_repository_local_path(repo_name).await? else {
+        return Ok(cleanup);
+    };
+    let clone_path = PathBuf::from(expand_home(&local_path));
+
+    for worktree in worktrees {
+        let clone_path = clone_path.clone();
+        let path = PathBuf::from(&worktree.path);
+        let pr_number = worktree.pr_number;
+        let result = tokio::task::spawn_blocking(move || {
+            remove_worktree_blocking(&clone_path, &path, pr_number)
+        })
+        .await?;
+
+        match result {
+            Ok(()) => {
+                repository
+                    .delete_pr_worktree(&worktree.repository, worktree.pr_number)
+                    .await?;
+                cleanup.removed += 1;
+            }
+            Err(err) => cleanup.kept.push(KeptWorktree {
+                repository: worktree.repository,
+                pr_number: worktree.pr_number,
+                path: worktree.path,
+                reason: err.to_string(),
+            }),
+        }
+    }
+
+    Ok(cleanup)
+}
+
+fn remove_worktree_blocking(clone_path: &Path, path: &Path, pr_number: i64) -> anyhow::Result<()> {
+    if path.exists() {
+        ensure_no_local_work(path, pr_number)?;
+        run_git(
+            clone_path,
+            &["worktree", "remove", &path.display().to_string()],
+        )?;
+    } else {
+        run_git(clone_path, &["worktree", "prune"])?;
+    }
+
+    // The branch and fetched ref are only bookkeeping; ignore failures.
+    let _ = run_git(clone_path, &["branch", "-D", &pr_branch_name(pr_number)]);
+    let _ = run_git(clone_path, &["update-ref", "-d", &pr_remote_ref(pr_number)]);
+    Ok(())
+}
+
+/// Fail when removing the worktree at `path` would lose work: uncommitted
+/// changes, untracked files, or commits the fetched PR head doesn't have.
+fn ensure_no_local_work(path: &Path, pr_number: i64) -> anyhow::Result<()> {
+    if !run_git(path, &["status", "--porcelain"])?.is_empty() {
+        anyhow::bail!("it has uncommitted changes or untracked files");
+    }
+    if run_git(
+        path,
+        &[
+            "merge-base",
+            "--is-ancestor",
+            "HEAD",
+            &pr_remote_ref(pr_number),
+        ],
+    )
+    .is_err()
+    {
+        anyhow::bail!("it has commits that are not in the PR head");
+    }
+    Ok(())
+}
+
+fn run_git(dir: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .map_err(|err| anyhow::anyhow!("failed to run git: {err}"))?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn short_sha(sha: &str) -> &str {
+    sha.get(..7).unwrap_or(sha)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pr_target_accepts_owner_repo_number() {
+        assert_eq!(
+            parse_pr_target("octo/widgets#42").expect("valid target"),
+            ("octo/widgets".to_string(), 42)
+        );
+    }
+
+    #[test]
+    fn parse_pr_target_rejects_malformed_targets() {
+        assert!(parse_pr_target("octo/widgets").is_err());
+        assert!(parse_pr_target("widgets#42").is_err());
+        assert!(parse_pr_target("octo/widgets#abc").is_err());
+        assert!(parse_pr_target("/widgets#1").is_err());
+    }
+
+    #[test]
+    fn worktree_path_for_uses_sibling_directory() {
+        assert_eq!(
+            worktree_path_for(Path::new("/home/me/code/widgets"), 42),
+            PathBuf::from("/home/me/code/widgets-worktrees/pr-42")
+        );
+    }
+
+    #[test]
+    fn format_worktree_checkout_reports_behind_with_short_shas() {
+        let checkout = WorktreeCheckout {
+            repository: "octo/widgets".to_string(),
+            pr_number: 42,
+            path: PathBuf::from("/tmp/pr-42"),
+            head_sha: "0123456789abcdef".to_string(),
+            status: WorktreeStatus::Behind {
+                local_sha: "fedcba9876543210".to_string(),
+            },
+        };
+
+        assert_eq!(
+            format_worktree_checkout(&checkout),
+            "Worktree for octo/widgets#42 at /tmp/pr-42 is behind: local fedcba9 vs head 0123456"
+        );
+    }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .output()
+            .expect("git runs")
+            .status;
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    /// A clone with the worktree of PR 42 checked out from its fetched ref.
+    fn clone_with_pr_worktree(name: &str) -> (PathBuf, PathBuf) {
+        let root = std::env::temp_dir().join(format!("prt-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let clone_path = root.join("widgets");
+        std::fs::create_dir_all(&clone_path).unwrap();
+        git(&clone_path, &["init", "-q"]);
+        git(
+            &clone_path,
+            &["commit", "-q", "--allow-empty", "-m", "init"],
+        );
+        git(&clone_path, &["update-ref", &pr_remote_ref(42), "HEAD"]);
+        let path = worktree_path_for(&clone_path, 42);
+        git(
+            &clone_path,
+            &[
+                "worktree",
+                "add",
+                "-q",
+                "-B",
+                &pr_branch_name(42),
+                &path.display().to_string(),
+                &pr_remote_ref(42),
+            ],
+        );
+        (root, path)
+    }
+
+    #[test]
+    fn remove_worktree_removes_clean_worktrees() {
+        let (root, path) = clone_with_pr_worktree("clean");
+
+        remove_worktree_blocking(&root.join("widgets"), &path, 42).expect("removed");
+
+        assert!(!path.exists());
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn remove_worktree_keeps_worktrees_with_local_work() {
+        let (root, path) = clone_with_pr_worktree("dirty");
+        let clone_path = root.join("widgets");
+
+        std::fs::write(path.join("notes.txt"), "wip").unwrap();
+        let err = remove_worktree_blocking(&clone_path, &path, 42).unwrap_err();
+        assert!(err.to_string().contains("untracked"));
+        assert!(path.join("notes.txt").exists());
+
+        git(&path, &["add", "notes.txt"]);
+        git(&path, &["commit", "-q", "-m", "wip"]);
+        let err = remove_worktree_blocking(&clone_path, &path, 42).unwrap_err();
+        assert!(err.to_string().contains("not in the PR head"));
+        assert!(path.join("notes.txt").exists());
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+}